@@ -0,0 +1,161 @@
+//! RPC interface for the NFT pallet, the `nft_*` methods.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{DeserializeOwned, RpcResult, Serialize},
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_nft_runtime_api::{
+    AttributeNamespace, CollectionDetails, NftApi as NftRuntimeApi, NftAttribute, NftHolding, NftItemDetails, NftOwner,
+};
+
+#[rpc(client, server)]
+pub trait NftApi<BlockHash, AccountId> {
+    /// The NFTs held by an account, at most `limit` of them after `start_after`.
+    #[method(name = "nft_holdings")]
+    fn holdings(
+        &self,
+        account: AccountId,
+        start_after: Option<(H256, u32)>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NftHolding>>;
+
+    /// The details of an NFT including its merge tree.
+    #[method(name = "nft_itemDetails")]
+    fn item_details(
+        &self,
+        collection_id: H256,
+        item_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NftItemDetails<AccountId>>>;
+
+    /// The details of a collection.
+    #[method(name = "nft_collectionDetails")]
+    fn collection_details(
+        &self,
+        collection_id: H256,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<CollectionDetails<AccountId>>>;
+
+    /// The owners of an NFT, at most `limit` of them after `start_after`.
+    #[method(name = "nft_owners")]
+    fn owners(
+        &self,
+        collection_id: H256,
+        item_id: u32,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NftOwner<AccountId>>>;
+
+    /// The attributes of a collection, or of an NFT if `item_id` is given, only those in `namespace` if given.
+    #[method(name = "nft_attributes")]
+    fn attributes(
+        &self,
+        collection_id: H256,
+        item_id: Option<u32>,
+        namespace: Option<AttributeNamespace<AccountId>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NftAttribute<AccountId>>>;
+}
+
+/// Provides the `nft_*` RPC methods.
+pub struct Nft<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Nft<C, Block> {
+    /// Create a new instance with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), "Unable to query the NFTs.", Some(format!("{:?}", e)))
+}
+
+impl<C, Block, AccountId> NftApiServer<<Block as BlockT>::Hash, AccountId> for Nft<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: NftRuntimeApi<Block, AccountId>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn holdings(
+        &self,
+        account: AccountId,
+        start_after: Option<(H256, u32)>,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<NftHolding>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().holdings(at_hash, account, start_after, limit).map_err(runtime_error)
+    }
+
+    fn item_details(
+        &self,
+        collection_id: H256,
+        item_id: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<NftItemDetails<AccountId>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().item_details(at_hash, collection_id, item_id).map_err(runtime_error)
+    }
+
+    fn collection_details(
+        &self,
+        collection_id: H256,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<CollectionDetails<AccountId>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().collection_details(at_hash, collection_id).map_err(runtime_error)
+    }
+
+    fn owners(
+        &self,
+        collection_id: H256,
+        item_id: u32,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<NftOwner<AccountId>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().owners(at_hash, collection_id, item_id, start_after, limit).map_err(runtime_error)
+    }
+
+    fn attributes(
+        &self,
+        collection_id: H256,
+        item_id: Option<u32>,
+        namespace: Option<AttributeNamespace<AccountId>>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<NftAttribute<AccountId>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().attributes(at_hash, collection_id, item_id, namespace).map_err(runtime_error)
+    }
+}
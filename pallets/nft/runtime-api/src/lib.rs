@@ -0,0 +1,28 @@
+//! Runtime API definition for the NFT pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+pub use pallet_nft::api::{CollectionDetails, NftAttribute, NftHolding, NftItemDetails, NftOwner, SubNft, MAX_PAGE_SIZE};
+pub use pallet_nft::AttributeNamespace;
+
+sp_api::decl_runtime_apis! {
+    /// Query the NFTs, their owners and collections.
+    pub trait NftApi<AccountId> where
+        AccountId: Codec,
+    {
+        /// The NFTs held by `account`, at most `limit` of them after `start_after`.
+        fn holdings(account: AccountId, start_after: Option<(H256, u32)>, limit: u32) -> Vec<NftHolding>;
+        /// The details of an NFT including its merge tree.
+        fn item_details(collection_id: H256, item_id: u32) -> Option<NftItemDetails<AccountId>>;
+        /// The details of a collection.
+        fn collection_details(collection_id: H256) -> Option<CollectionDetails<AccountId>>;
+        /// The owners of an NFT, at most `limit` of them after `start_after`.
+        fn owners(collection_id: H256, item_id: u32, start_after: Option<AccountId>, limit: u32) -> Vec<NftOwner<AccountId>>;
+        /// The attributes of a collection, or of an NFT if `item_id` is given, only those in `namespace` if given.
+        fn attributes(collection_id: H256, item_id: Option<u32>, namespace: Option<AttributeNamespace<AccountId>>) -> Vec<NftAttribute<AccountId>>;
+    }
+}
@@ -0,0 +1,82 @@
+//! Types returned by the NFT runtime API, readable by front ends without the storage layout.
+
+use crate::{AttributeNamespace, Share};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// The maximum number of entries in a page of holdings or owners.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// An NFT held by an account and the held share.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct NftHolding {
+    pub collection_id: H256,
+    pub item_id: u32,
+    pub share: Share,
+}
+
+/// A sub NFT of a merged NFT.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SubNft {
+    pub collection_id: H256,
+    pub item_id: u32,
+    pub metadata: Vec<u8>,
+}
+
+/// The details of an NFT and its place in the merge tree.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct NftItemDetails<AccountId> {
+    pub collection_id: H256,
+    pub item_id: u32,
+    pub metadata: Vec<u8>,
+    pub share_supply: Share,
+    pub merged_nft: Option<(H256, u32)>, // the merged NFT of a sub NFT, or of the merged NFT itself
+    pub sub_nfts: Vec<SubNft>, // the sub NFTs of a merged NFT, the merged NFT first
+    pub royalty: Option<(AccountId, u16)>, // (recipient, bps), falls back to the collection royalty
+    pub metadata_locked: bool, // by the NFT or its collection
+}
+
+/// The details of a collection.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CollectionDetails<AccountId> {
+    pub collection_id: H256,
+    pub max_items: u32,
+    pub minted_items: u32,
+    pub metadata: Vec<u8>,
+    pub owner: Option<AccountId>,
+    pub frozen: bool,
+    pub royalty: Option<(AccountId, u16)>, // (recipient, bps)
+    pub metadata_locked: bool,
+}
+
+/// An owner of an NFT and the owned share.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct NftOwner<AccountId> {
+    pub account: AccountId,
+    pub share: Share,
+}
+
+/// An attribute of a collection or an NFT.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct NftAttribute<AccountId> {
+    pub namespace: AttributeNamespace<AccountId>,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
@@ -3,19 +3,38 @@
 use super::*;
 
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
 use frame_support::{BoundedVec,  pallet_prelude::Get};
-use sp_std::vec;
+use frame_support::traits::{Currency, ReservableCurrency};
+use sp_std::{vec, vec::Vec};
 use sp_core::hashing::blake2_256;
 use sp_core::H256;
 use frame_support::pallet_prelude::*;
-type MaxSubNftsLength = ConstU32<10>;
-type MaxNftOwners = ConstU32<10>;
-type MaxMetadataLength = ConstU32<256>;
-type MaxCollectionsLength = ConstU32<100>;
-type MaxNftsLength = ConstU32<10000>;
 type NftItem = (H256, u32);
-type NftItemWithShare = (H256, u32, u8);
+
+fn collection_roles<T: Config>(owner: T::AccountId) -> CollectionRoles<T::AccountId> {
+    CollectionRoles {
+        owner,
+        issuer: None,
+        admin: None,
+        freezer: None,
+    }
+}
+
+fn owned_nft<T: Config>(owner: T::AccountId) -> NftItem {
+    let nft_item = (H256::zero(), 0u32);
+    let nft_info = NftInfo {
+        merged_nft: None,
+        sub_nfts: BoundedVec::default(),
+        metadata: vec![0; 32].try_into().unwrap(),
+    };
+    NFTDetails::<T>::insert(nft_item, nft_info);
+
+    OwnedNFTs::<T>::insert(&owner, nft_item, T::DefaultShareSupply::get());
+    ShareSupply::<T>::insert(nft_item, T::DefaultShareSupply::get());
+    NFTOwners::<T>::insert(nft_item, &owner, ());
+    nft_item
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -24,8 +43,9 @@ mod benchmarks {
     #[benchmark]
     fn create_collection() {
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
         let max_items = 100u32;
-        let metadata: BoundedVec<u8, MaxMetadataLength> = vec![0; 32].try_into().unwrap();
+        let metadata: BoundedVec<u8, T::MaxMetadataLength> = vec![0; 32].try_into().unwrap();
 
         #[extrinsic_call]
         create_collection(RawOrigin::Signed(caller), max_items, metadata);
@@ -33,21 +53,37 @@ mod benchmarks {
         assert!(NFTCollectionIds::<T>::get().is_some());
     }
 
+    #[benchmark]
+    fn create_collection_with_salt() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let max_items = 100u32;
+        let metadata: BoundedVec<u8, T::MaxMetadataLength> = vec![0; 32].try_into().unwrap();
+        let salt = [1u8; 32];
+
+        #[extrinsic_call]
+        create_collection_with_salt(RawOrigin::Signed(caller.clone()), max_items, metadata, salt);
+
+        assert!(NFTCollections::<T>::contains_key(Pallet::<T>::salted_collection_id(&caller, salt)));
+    }
+
     #[benchmark]
     fn mint_nft() {
         let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
         let max_items = 100u32;
-        let collection_metadata: BoundedVec<u8, MaxMetadataLength> = vec![0; 32].try_into().unwrap();
+        let collection_metadata: BoundedVec<u8, T::MaxMetadataLength> = vec![0; 32].try_into().unwrap();
         let collection_id = H256::from_slice(&blake2_256(&collection_metadata));
 
         NFTCollections::<T>::insert(&collection_id, (max_items, 0, collection_metadata.clone()));
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
 
-        let nft_metadata: BoundedVec<u8, MaxMetadataLength> = vec![1; 32].try_into().unwrap();
+        let nft_metadata: BoundedVec<u8, T::MaxMetadataLength> = vec![1; 32].try_into().unwrap();
 
         #[extrinsic_call]
         mint_nft(RawOrigin::Signed(caller.clone()), collection_id, nft_metadata);
 
-        assert!(OwnedNFTs::<T>::contains_key(&caller));
+        assert!(OwnedNFTs::<T>::contains_key(&caller, (collection_id, 0u32)));
     }
 
     #[benchmark]
@@ -56,7 +92,7 @@ mod benchmarks {
         let receiver: T::AccountId = whitelisted_caller();
         let collection_id = H256::zero();
         let nft_item = (collection_id, 0u32);
-        let share = 100u8;
+        let share = T::DefaultShareSupply::get();
 
         // Setup: Mint an NFT for the caller
         let nft_info = NftInfo {
@@ -66,25 +102,21 @@ mod benchmarks {
         };
         NFTDetails::<T>::insert(nft_item, nft_info);
         
-        let mut owned_nfts = BoundedVec::<NftItemWithShare, MaxNftsLength>::default();
-        owned_nfts.try_push((collection_id, 0u32, 100u8)).unwrap();
-        OwnedNFTs::<T>::insert(&caller, owned_nfts);
-
-        let mut nft_owners = BoundedVec::<T::AccountId, MaxNftOwners>::default();
-        nft_owners.try_push(caller.clone()).unwrap();
-        NFTOwners::<T>::insert(nft_item, nft_owners);
+        OwnedNFTs::<T>::insert(&caller, nft_item, T::DefaultShareSupply::get());
+        ShareSupply::<T>::insert(nft_item, T::DefaultShareSupply::get());
+        NFTOwners::<T>::insert(nft_item, &caller, ());
 
         #[extrinsic_call]
         transfer_nft(RawOrigin::Signed(caller), receiver.clone(), nft_item, share);
 
-        assert!(OwnedNFTs::<T>::contains_key(&receiver));
+        assert!(OwnedNFTs::<T>::contains_key(&receiver, nft_item));
     }
 
     #[benchmark]
     fn merge_nfts() {
         let caller: T::AccountId = whitelisted_caller();
         let collection_id = H256::zero();
-        let nft_items: BoundedVec<NftItem, MaxSubNftsLength> = vec![(collection_id, 0u32), (collection_id, 1u32)].try_into().unwrap();
+        let nft_items: BoundedVec<NftItem, T::MaxSubNftsLength> = vec![(collection_id, 0u32), (collection_id, 1u32)].try_into().unwrap();
 
         // Setup: Create two NFTs for the caller
         for &nft_item in nft_items.iter() {
@@ -95,9 +127,9 @@ mod benchmarks {
             };
             NFTDetails::<T>::insert(nft_item, nft_info);
             
-            let mut nft_owners = BoundedVec::<T::AccountId, MaxNftOwners>::default();
-            nft_owners.try_push(caller.clone()).unwrap();
-            NFTOwners::<T>::insert(nft_item, nft_owners);
+            OwnedNFTs::<T>::insert(&caller, nft_item, T::DefaultShareSupply::get());
+            ShareSupply::<T>::insert(nft_item, T::DefaultShareSupply::get());
+            NFTOwners::<T>::insert(nft_item, &caller, ());
         }
 
         #[extrinsic_call]
@@ -111,7 +143,7 @@ mod benchmarks {
         let caller: T::AccountId = whitelisted_caller();
         let collection_id = H256::zero();
         let nft_item = (collection_id, 0u32);
-        let sub_nfts: BoundedVec<NftItem, MaxSubNftsLength> = vec![(collection_id, 0u32), (collection_id, 1u32), (collection_id, 2u32)].try_into().unwrap();
+        let sub_nfts: BoundedVec<NftItem, T::MaxSubNftsLength> = vec![(collection_id, 0u32), (collection_id, 1u32), (collection_id, 2u32)].try_into().unwrap();
 
         // Setup: Create a merged NFT
         let nft_info = NftInfo {
@@ -121,13 +153,390 @@ mod benchmarks {
         };
         NFTDetails::<T>::insert(nft_item, nft_info);
 
-        let mut nft_owners = BoundedVec::<T::AccountId, MaxNftOwners>::default();
-        nft_owners.try_push(caller.clone()).unwrap();
-        NFTOwners::<T>::insert(nft_item, nft_owners);
+        OwnedNFTs::<T>::insert(&caller, nft_item, T::DefaultShareSupply::get());
+        ShareSupply::<T>::insert(nft_item, T::DefaultShareSupply::get());
+        NFTOwners::<T>::insert(nft_item, &caller, ());
 
         #[extrinsic_call]
         split_nft(RawOrigin::Signed(caller), nft_item);
 
         assert!(NFTDetails::<T>::get(nft_item).unwrap().merged_nft.is_none());
     }
+
+    #[benchmark]
+    fn set_collection_team() {
+        let caller: T::AccountId = whitelisted_caller();
+        let team: T::AccountId = account("team", 0, 0);
+        let collection_id = H256::zero();
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        set_collection_team(RawOrigin::Signed(caller), collection_id, Some(team.clone()), Some(team.clone()), Some(team.clone()));
+
+        assert_eq!(NFTCollectionRoles::<T>::get(&collection_id).unwrap().issuer, Some(team));
+    }
+
+    #[benchmark]
+    fn transfer_collection_ownership() {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+        let collection_id = H256::zero();
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        transfer_collection_ownership(RawOrigin::Signed(caller), collection_id, new_owner.clone());
+
+        assert_eq!(NFTCollectionRoles::<T>::get(&collection_id).unwrap().owner, new_owner);
+    }
+
+    #[benchmark]
+    fn set_collection_max_items() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection_id = H256::zero();
+        let metadata: BoundedVec<u8, T::MaxMetadataLength> = vec![0; 32].try_into().unwrap();
+        NFTCollections::<T>::insert(&collection_id, (100u32, 0u32, metadata));
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        set_collection_max_items(RawOrigin::Signed(caller), collection_id, 200u32);
+
+        assert_eq!(NFTCollections::<T>::get(&collection_id).unwrap().0, 200u32);
+    }
+
+    #[benchmark]
+    fn freeze_collection() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection_id = H256::zero();
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        freeze_collection(RawOrigin::Signed(caller), collection_id);
+
+        assert!(FrozenCollections::<T>::contains_key(&collection_id));
+    }
+
+    #[benchmark]
+    fn thaw_collection() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection_id = H256::zero();
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+        FrozenCollections::<T>::insert(&collection_id, ());
+
+        #[extrinsic_call]
+        thaw_collection(RawOrigin::Signed(caller), collection_id);
+
+        assert!(!FrozenCollections::<T>::contains_key(&collection_id));
+    }
+
+    #[benchmark]
+    fn burn_nft(s: Linear<2, { T::MaxSubNftsLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection_id = H256::zero();
+        let nft_item = (collection_id, 0u32);
+        let sub_nfts: BoundedVec<NftItem, T::MaxSubNftsLength> = (0..s).map(|i| (collection_id, i)).collect::<Vec<_>>().try_into().unwrap();
+
+        // Setup: Create a merged NFT with `s` sub NFTs owned by the caller
+        for &sub_nft_item in sub_nfts.iter() {
+            let nft_info = NftInfo {
+                merged_nft: Some(nft_item),
+                sub_nfts: if sub_nft_item == nft_item { sub_nfts.clone() } else { BoundedVec::default() },
+                metadata: vec![0; 32].try_into().unwrap(),
+            };
+            NFTDetails::<T>::insert(sub_nft_item, nft_info);
+            OwnedNFTs::<T>::insert(&caller, sub_nft_item, T::DefaultShareSupply::get());
+            ShareSupply::<T>::insert(sub_nft_item, T::DefaultShareSupply::get());
+            NFTOwners::<T>::insert(sub_nft_item, &caller, ());
+        }
+
+        #[extrinsic_call]
+        burn_nft(RawOrigin::Signed(caller.clone()), nft_item, T::DefaultShareSupply::get());
+
+        assert!(!NFTDetails::<T>::contains_key(nft_item));
+        assert_eq!(OwnedNFTs::<T>::iter_prefix(&caller).count(), 0);
+    }
+
+    #[benchmark]
+    fn approve_transfer() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let nft_item = owned_nft::<T>(caller.clone());
+
+        #[extrinsic_call]
+        approve_transfer(RawOrigin::Signed(caller.clone()), nft_item, delegate.clone(), None);
+
+        assert_eq!(NFTApprovals::<T>::get(nft_item, &caller), Some((delegate, None)));
+    }
+
+    #[benchmark]
+    fn cancel_approval() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTApprovals::<T>::insert(nft_item, &caller, (delegate, None::<BlockNumberFor<T>>));
+
+        #[extrinsic_call]
+        cancel_approval(RawOrigin::Signed(caller.clone()), nft_item);
+
+        assert!(!NFTApprovals::<T>::contains_key(nft_item, &caller));
+    }
+
+    #[benchmark]
+    fn approve_operator() {
+        let caller: T::AccountId = whitelisted_caller();
+        let operator: T::AccountId = account("operator", 0, 0);
+
+        #[extrinsic_call]
+        approve_operator(RawOrigin::Signed(caller.clone()), operator.clone(), None);
+
+        assert!(OperatorApprovals::<T>::contains_key(&caller, &operator));
+    }
+
+    #[benchmark]
+    fn cancel_operator() {
+        let caller: T::AccountId = whitelisted_caller();
+        let operator: T::AccountId = account("operator", 0, 0);
+        OperatorApprovals::<T>::insert(&caller, &operator, None::<BlockNumberFor<T>>);
+
+        #[extrinsic_call]
+        cancel_operator(RawOrigin::Signed(caller.clone()), operator.clone());
+
+        assert!(!OperatorApprovals::<T>::contains_key(&caller, &operator));
+    }
+
+    #[benchmark]
+    fn transfer_from() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let receiver: T::AccountId = account("receiver", 0, 0);
+        let nft_item = owned_nft::<T>(owner.clone());
+        NFTApprovals::<T>::insert(nft_item, &owner, (caller.clone(), None::<BlockNumberFor<T>>));
+
+        #[extrinsic_call]
+        transfer_from(RawOrigin::Signed(caller), owner.clone(), receiver.clone(), nft_item, T::DefaultShareSupply::get());
+
+        assert!(OwnedNFTs::<T>::contains_key(&receiver, nft_item));
+        assert!(!NFTApprovals::<T>::contains_key(nft_item, &owner));
+    }
+
+    #[benchmark]
+    fn fractionalize() {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item = owned_nft::<T>(caller.clone());
+        let share_supply: Share = 1_000_000;
+
+        #[extrinsic_call]
+        fractionalize(RawOrigin::Signed(caller.clone()), nft_item, share_supply);
+
+        assert_eq!(OwnedNFTs::<T>::get(&caller, nft_item), Some(share_supply));
+    }
+
+    #[benchmark]
+    fn unify() {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item = owned_nft::<T>(caller.clone());
+        OwnedNFTs::<T>::insert(&caller, nft_item, 1_000_000);
+        ShareSupply::<T>::insert(nft_item, 1_000_000);
+
+        #[extrinsic_call]
+        unify(RawOrigin::Signed(caller.clone()), nft_item);
+
+        assert_eq!(ShareSupply::<T>::get(nft_item), Some(T::DefaultShareSupply::get()));
+    }
+
+    #[benchmark]
+    fn set_collection_royalty() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection_id = H256::zero();
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+        let royalty = RoyaltyInfo { recipient: caller.clone(), bps: 500 };
+
+        #[extrinsic_call]
+        set_collection_royalty(RawOrigin::Signed(caller), collection_id, Some(royalty.clone()));
+
+        assert_eq!(CollectionRoyalties::<T>::get(&collection_id), Some(royalty));
+    }
+
+    #[benchmark]
+    fn set_nft_royalty() {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+        let royalty = RoyaltyInfo { recipient: caller.clone(), bps: 500 };
+
+        #[extrinsic_call]
+        set_nft_royalty(RawOrigin::Signed(caller), nft_item, Some(royalty.clone()));
+
+        assert_eq!(NFTRoyalties::<T>::get(nft_item), Some(royalty));
+    }
+
+    #[benchmark]
+    fn set_collection_metadata() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let collection_id = H256::zero();
+        NFTCollections::<T>::insert(&collection_id, (100u32, 0u32, BoundedVec::default()));
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+        let metadata: BoundedVec<u8, T::MaxMetadataLength> = vec![1; 32].try_into().unwrap();
+
+        #[extrinsic_call]
+        set_collection_metadata(RawOrigin::Signed(caller), collection_id, metadata.clone());
+
+        assert_eq!(NFTCollections::<T>::get(&collection_id).map(|collection| collection.2), Some(metadata));
+    }
+
+    #[benchmark]
+    fn clear_collection_metadata() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection_id = H256::zero();
+        NFTCollections::<T>::insert(&collection_id, (100u32, 0u32, BoundedVec::try_from(vec![1; 32]).unwrap()));
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        clear_collection_metadata(RawOrigin::Signed(caller), collection_id);
+
+        assert_eq!(NFTCollections::<T>::get(&collection_id).map(|collection| collection.2), Some(BoundedVec::default()));
+    }
+
+    #[benchmark]
+    fn lock_collection_metadata() {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection_id = H256::zero();
+        NFTCollectionRoles::<T>::insert(&collection_id, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        lock_collection_metadata(RawOrigin::Signed(caller), collection_id);
+
+        assert!(LockedCollectionMetadata::<T>::contains_key(&collection_id));
+    }
+
+    #[benchmark]
+    fn set_nft_metadata() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+        let metadata: BoundedVec<u8, T::MaxMetadataLength> = vec![1; 32].try_into().unwrap();
+
+        #[extrinsic_call]
+        set_nft_metadata(RawOrigin::Signed(caller), nft_item, metadata.clone());
+
+        assert_eq!(NFTDetails::<T>::get(nft_item).map(|nft_info| nft_info.metadata), Some(metadata));
+    }
+
+    #[benchmark]
+    fn clear_nft_metadata() {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        clear_nft_metadata(RawOrigin::Signed(caller), nft_item);
+
+        assert_eq!(NFTDetails::<T>::get(nft_item).map(|nft_info| nft_info.metadata), Some(BoundedVec::default()));
+    }
+
+    #[benchmark]
+    fn lock_nft_metadata() {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        lock_nft_metadata(RawOrigin::Signed(caller), nft_item);
+
+        assert!(LockedNFTMetadata::<T>::contains_key(nft_item));
+    }
+
+    #[benchmark]
+    fn set_attribute() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+        let key: AttributeKeyOf<T> = vec![0; T::MaxAttributeKeyLength::get() as usize].try_into().unwrap();
+        let value: AttributeValueOf<T> = vec![1; T::MaxAttributeValueLength::get() as usize].try_into().unwrap();
+
+        #[extrinsic_call]
+        set_attribute(RawOrigin::Signed(caller), nft_item.0, Some(nft_item.1), AttributeNamespace::CollectionOwner, key.clone(), value);
+
+        assert!(Attributes::<T>::contains_key((nft_item.0, Some(nft_item.1), AttributeNamespace::<T::AccountId>::CollectionOwner, key)));
+    }
+
+    #[benchmark]
+    fn clear_attribute() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+        let key: AttributeKeyOf<T> = vec![0; T::MaxAttributeKeyLength::get() as usize].try_into().unwrap();
+        let value: AttributeValueOf<T> = vec![1; T::MaxAttributeValueLength::get() as usize].try_into().unwrap();
+        Pallet::<T>::set_attribute(RawOrigin::Signed(caller.clone()).into(),
+                                   nft_item.0,
+                                   Some(nft_item.1),
+                                   AttributeNamespace::CollectionOwner,
+                                   key.clone(),
+                                   value).unwrap();
+
+        #[extrinsic_call]
+        clear_attribute(RawOrigin::Signed(caller), nft_item.0, Some(nft_item.1), AttributeNamespace::CollectionOwner, key.clone());
+
+        assert!(!Attributes::<T>::contains_key((nft_item.0, Some(nft_item.1), AttributeNamespace::<T::AccountId>::CollectionOwner, key)));
+    }
+
+    #[benchmark]
+    fn approve_attributes() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+
+        #[extrinsic_call]
+        approve_attributes(RawOrigin::Signed(caller), nft_item.0, Some(nft_item.1), delegate.clone());
+
+        assert!(AttributeApprovals::<T>::contains_key((nft_item.0, Some(nft_item.1)), delegate));
+    }
+
+    #[benchmark]
+    fn cancel_attributes_approval() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let nft_item = owned_nft::<T>(caller.clone());
+        NFTCollectionRoles::<T>::insert(&nft_item.0, collection_roles::<T>(caller.clone()));
+        AttributeApprovals::<T>::insert((nft_item.0, Some(nft_item.1)), &delegate, ());
+
+        #[extrinsic_call]
+        cancel_attributes_approval(RawOrigin::Signed(caller), nft_item.0, Some(nft_item.1), delegate.clone());
+
+        assert!(!AttributeApprovals::<T>::contains_key((nft_item.0, Some(nft_item.1)), delegate));
+    }
+
+    #[benchmark]
+    fn force_set_deposits() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let depositor: T::AccountId = account("depositor", 0, 0);
+        T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::from(1_000_000u32));
+        T::Currency::make_free_balance_be(&depositor, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item = owned_nft::<T>(owner.clone());
+        let target = (nft_item.0, Some(nft_item.1));
+        T::Currency::reserve(&owner, BalanceOf::<T>::from(2u32)).unwrap();
+        Deposits::<T>::insert(target, (owner.clone(), BalanceOf::<T>::from(1u32)));
+        MetadataDeposits::<T>::insert(target, (owner, BalanceOf::<T>::from(1u32)));
+
+        #[extrinsic_call]
+        force_set_deposits(RawOrigin::Root, nft_item.0, Some(nft_item.1), depositor.clone(), BalanceOf::<T>::from(10u32), BalanceOf::<T>::from(10u32));
+
+        assert_eq!(Deposits::<T>::get(target), Some((depositor, BalanceOf::<T>::from(10u32))));
+    }
+
+    #[benchmark]
+    fn force_set_collection_team() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let collection_id = H256::zero();
+        NFTCollections::<T>::insert(&collection_id, (100u32, 0u32, BoundedVec::default()));
+
+        #[extrinsic_call]
+        force_set_collection_team(RawOrigin::Root, collection_id, owner.clone(), Some(owner.clone()), None, None);
+
+        assert_eq!(NFTCollectionRoles::<T>::get(&collection_id).map(|roles| roles.owner), Some(owner));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,144 @@
+//! Implementations of the `nonfungibles_v2` traits, the interface of the pallet for other pallets.
+//!
+//! The traits see an NFT as a whole: an NFT is owned by the account holding its whole share
+//! supply, and is transferred and burned with its whole share supply. The NFT shares are exposed
+//! by the traits in [`crate::shares`].
+
+use crate::{
+    AttributeNamespace, Config, Error, NFTCollectionIds, NFTCollectionRoles, NFTCollections,
+    NFTDetails, OwnedNFTs, Pallet,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    ensure,
+    traits::tokens::nonfungibles_v2::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
+    BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The configuration of a collection created through [`Create`].
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CollectionConfig {
+    /// The maximum number of NFTs of the collection.
+    pub max_items: u32,
+}
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type ItemId = u32;
+    type CollectionId = H256;
+
+    fn owner(collection: &H256, item: &u32) -> Option<T::AccountId> {
+        Self::whole_share_owner((*collection, *item))
+    }
+
+    fn collection_owner(collection: &H256) -> Option<T::AccountId> {
+        NFTCollectionRoles::<T>::get(collection).map(|roles| roles.owner)
+    }
+
+    fn attribute(collection: &H256, item: &u32, key: &[u8]) -> Option<Vec<u8>> {
+        Self::attribute(*collection, Some(*item), &AttributeNamespace::CollectionOwner, key)
+    }
+
+    fn custom_attribute(account: &T::AccountId, collection: &H256, item: &u32, key: &[u8]) -> Option<Vec<u8>> {
+        Self::attribute(*collection, Some(*item), &AttributeNamespace::Account(account.clone()), key)
+    }
+
+    fn collection_attribute(collection: &H256, key: &[u8]) -> Option<Vec<u8>> {
+        Self::attribute(*collection, None, &AttributeNamespace::CollectionOwner, key)
+    }
+
+    fn can_transfer(collection: &H256, item: &u32) -> bool {
+        let nft_item = (*collection, *item);
+        Self::whole_share_owner(nft_item)
+            .map_or(false, |owner| Self::ensure_can_transfer(&owner, nft_item, Self::share_supply(nft_item)).is_ok())
+    }
+}
+
+impl<T: Config> InspectEnumerable<T::AccountId> for Pallet<T> {
+    type CollectionsIterator = sp_std::vec::IntoIter<H256>;
+    type ItemsIterator = sp_std::vec::IntoIter<u32>;
+    type OwnedIterator = sp_std::vec::IntoIter<(H256, u32)>;
+    type OwnedInCollectionIterator = sp_std::vec::IntoIter<u32>;
+
+    fn collections() -> Self::CollectionsIterator {
+        NFTCollectionIds::<T>::get().map(BoundedVec::into_inner).unwrap_or_default().into_iter()
+    }
+
+    fn items(collection: &H256) -> Self::ItemsIterator {
+        let minted = NFTCollections::<T>::get(collection).map_or(0, |(_, cur_item_index, _)| cur_item_index);
+        (0..minted)
+            .filter(|item_id| NFTDetails::<T>::contains_key((*collection, *item_id)))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn owned(who: &T::AccountId) -> Self::OwnedIterator {
+        OwnedNFTs::<T>::iter_key_prefix(who)
+            .filter(|nft_item| Self::owns_whole_share(who, *nft_item))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn owned_in_collection(collection: &H256, who: &T::AccountId) -> Self::OwnedInCollectionIterator {
+        Self::owned(who)
+            .filter(|(collection_id, _)| collection_id == collection)
+            .map(|(_, item_id)| item_id)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<T: Config> Create<T::AccountId, CollectionConfig> for Pallet<T> {
+    /// Create a collection owned by `admin`, the collection deposit is reserved from `who`.
+    fn create_collection(who: &T::AccountId, admin: &T::AccountId, config: &CollectionConfig) -> Result<H256, DispatchError> {
+        let collection_id = Self::next_collection_id(who);
+        Self::do_create_collection(who, admin.clone(), collection_id, config.max_items, BoundedVec::default())?;
+        Ok(collection_id)
+    }
+}
+
+impl<T: Config> Mutate<T::AccountId, ()> for Pallet<T> {
+    /// Mint the next NFT of a collection to `who`, `item` must be the next item id.
+    ///
+    /// The deposits are reserved from the collection owner if `deposit_collection_owner` is set,
+    /// otherwise from `who`.
+    fn mint_into(collection: &H256, item: &u32, who: &T::AccountId, _config: &(), deposit_collection_owner: bool) -> DispatchResult {
+        let (_, cur_item_index, _) = NFTCollections::<T>::get(collection).ok_or(Error::<T>::CollectionNotFound)?;
+        ensure!(*item == cur_item_index, Error::<T>::WrongItemId);
+
+        let depositor = if deposit_collection_owner {
+            NFTCollectionRoles::<T>::get(collection)
+                .map(|roles| roles.owner)
+                .ok_or(Error::<T>::CollectionNotFound)?
+        } else {
+            who.clone()
+        };
+        Self::do_mint(&depositor, who.clone(), *collection, BoundedVec::default()).map(|_| ())
+    }
+
+    /// Burn an NFT with its whole share supply.
+    fn burn(collection: &H256, item: &u32, maybe_check_owner: Option<&T::AccountId>) -> DispatchResult {
+        let nft_item = (*collection, *item);
+        ensure!(NFTDetails::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
+        let owner = Self::whole_share_owner(nft_item).ok_or(Error::<T>::NFTNotEnoughShare)?;
+        if let Some(check_owner) = maybe_check_owner {
+            ensure!(*check_owner == owner, Error::<T>::NotOwner);
+        }
+
+        Self::do_burn(owner, nft_item, Self::share_supply(nft_item))
+    }
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+    /// Transfer an NFT with its whole share supply.
+    fn transfer(collection: &H256, item: &u32, destination: &T::AccountId) -> DispatchResult {
+        let nft_item = (*collection, *item);
+        ensure!(NFTDetails::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
+        let owner = Self::whole_share_owner(nft_item).ok_or(Error::<T>::NFTNotEnoughShare)?;
+
+        Self::do_transfer(owner, destination.clone(), nft_item, Self::share_supply(nft_item))
+    }
+}
@@ -6,6 +6,15 @@ pub use pallet::*;
 pub mod weights;
 pub use weights::*;
 
+pub mod migrations;
+
+pub mod api;
+
+mod impl_nonfungibles;
+pub use impl_nonfungibles::CollectionConfig;
+
+pub mod shares;
+
 #[cfg(test)]
 mod mock;
 
@@ -15,6 +24,31 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+use frame_support::weights::Weight;
+use sp_core::H256;
+
+/// Handler of the NFT share movements and changes, used by other pallets to keep the state
+/// derived from the NFT ownership up to date.
+pub trait OnNftTransfer<AccountId> {
+    /// The `share` of an NFT moved from `from` to `to`.
+    fn on_transfer(from: &AccountId, to: &AccountId, nft_item: (H256, u32), share: Share);
+    /// The `share` of an NFT held by `owner` was burned, and the NFT has shares left.
+    fn on_burn(owner: &AccountId, nft_item: (H256, u32), share: Share);
+    /// An NFT was merged, split, destroyed, or its share supply changed.
+    fn on_mutate(nft_item: (H256, u32));
+    /// The most weight a call of any of the handlers takes, charged by the extrinsics calling them.
+    fn max_weight() -> Weight;
+}
+
+impl<AccountId> OnNftTransfer<AccountId> for () {
+    fn on_transfer(_from: &AccountId, _to: &AccountId, _nft_item: (H256, u32), _share: Share) {}
+    fn on_burn(_owner: &AccountId, _nft_item: (H256, u32), _share: Share) {}
+    fn on_mutate(_nft_item: (H256, u32)) {}
+    fn max_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
         use super::*;
@@ -22,27 +56,79 @@ pub mod pallet {
         use sp_core::hashing::blake2_256;
         use sp_core::H256;
         use frame_support::pallet_prelude::*;
-        type MaxSubNftsLength = ConstU32<10>;
-        type MaxNftOwners = ConstU32<10>;
-        type MaxMetadataLength = ConstU32<256>;
-        type MaxCollectionsLength = ConstU32<100>;
-        type MaxNftsLength = ConstU32<10000>;
+        use frame_support::traits::{Currency, ReservableCurrency};
+        use frame_support::sp_runtime::traits::{Saturating, Zero};
+        use scale_info::TypeInfo;
+        use sp_std::vec::Vec;
+        #[cfg(any(feature = "try-runtime", test))]
+        use frame_support::sp_runtime::TryRuntimeError;
         type NftItem = (H256, u32);
-        type NftItemWithShare = (H256, u32, u8);
+        /// The amount of fungible shares of an NFT.
+        pub type Share = u128;
+        /// The maximum royalty in basis points, 100% of the price.
+        pub const MAX_ROYALTY_BPS: u16 = 10_000;
+        pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+        pub type AttributeKeyOf<T> = BoundedVec<u8, <T as Config>::MaxAttributeKeyLength>;
+        pub type AttributeValueOf<T> = BoundedVec<u8, <T as Config>::MaxAttributeValueLength>;
 
         #[pallet::config]
         pub trait Config: frame_system::Config {
             type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+            /// The maximum number of sub NFTs of a merged NFT.
+            #[pallet::constant]
+            type MaxSubNftsLength: Get<u32>;
+            /// The total share supply of a newly minted or unified NFT.
+            #[pallet::constant]
+            type DefaultShareSupply: Get<Share>;
+            /// The maximum length of the collection and NFT metadata.
+            #[pallet::constant]
+            type MaxMetadataLength: Get<u32>;
+            /// The maximum number of collections.
+            #[pallet::constant]
+            type MaxCollectionsLength: Get<u32>;
+            /// The handler of the NFT share movements and changes.
+            type OnNftTransfer: OnNftTransfer<Self::AccountId>;
+            /// The currency of the deposits.
+            type Currency: ReservableCurrency<Self::AccountId>;
+            /// The deposit reserved for a collection.
+            #[pallet::constant]
+            type CollectionDeposit: Get<BalanceOf<Self>>;
+            /// The deposit reserved for an NFT.
+            #[pallet::constant]
+            type ItemDeposit: Get<BalanceOf<Self>>;
+            /// The deposit reserved per byte of the collection and NFT metadata.
+            #[pallet::constant]
+            type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+            /// The base deposit reserved for an attribute.
+            #[pallet::constant]
+            type AttributeDepositBase: Get<BalanceOf<Self>>;
+            /// The deposit reserved per byte of an attribute key and value.
+            #[pallet::constant]
+            type DepositPerByte: Get<BalanceOf<Self>>;
+            /// The maximum length of an attribute key.
+            #[pallet::constant]
+            type MaxAttributeKeyLength: Get<u32>;
+            /// The maximum length of an attribute value.
+            #[pallet::constant]
+            type MaxAttributeValueLength: Get<u32>;
 		    /// Weights required by the dispatchables
 		    type WeightInfo: WeightInfo;
         }
 
+        /// The in-code storage version.
+        const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
         #[pallet::pallet]
+        #[pallet::storage_version(STORAGE_VERSION)]
         pub struct Pallet<T>(_);
 
         /// The collection id array.
         #[pallet::storage]
-        pub type NFTCollectionIds<T: Config> = StorageValue<_, BoundedVec<H256, MaxCollectionsLength>>;
+        pub type NFTCollectionIds<T: Config> = StorageValue<_, BoundedVec<H256, T::MaxCollectionsLength>>;
+
+        /// The number of created collections, part of the seed of the next collection id.
+        #[pallet::storage]
+        pub type CollectionNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
 
         /// The detail of a collection.
         #[pallet::storage]
@@ -50,23 +136,176 @@ pub mod pallet {
             _,
             Blake2_128Concat,
             H256, // collection
-            (u32, u32, BoundedVec<u8, MaxMetadataLength>), // (max_items, cur_item_index, collecton_metadata)
+            (u32, u32, BoundedVec<u8, T::MaxMetadataLength>), // (max_items, cur_item_index, collecton_metadata)
         >;
 
-        /// The NFTs owned by an account. 
+        #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+        pub struct CollectionRoles<AccountId> {
+            pub owner: AccountId, // collection owner, can set the team and hand over the ownership
+            pub issuer: Option<AccountId>, // can mint NFTs, the owner if not set
+            pub admin: Option<AccountId>, // can change collection settings, the owner if not set
+            pub freezer: Option<AccountId>, // can freeze and thaw the collection, the owner if not set
+        }
+
+        impl<AccountId: PartialEq> CollectionRoles<AccountId> {
+            pub fn is_owner(&self, who: &AccountId) -> bool {
+                self.owner == *who
+            }
+
+            pub fn is_issuer(&self, who: &AccountId) -> bool {
+                self.issuer.as_ref().unwrap_or(&self.owner) == who
+            }
+
+            pub fn is_admin(&self, who: &AccountId) -> bool {
+                self.admin.as_ref().unwrap_or(&self.owner) == who
+            }
+
+            pub fn is_freezer(&self, who: &AccountId) -> bool {
+                self.freezer.as_ref().unwrap_or(&self.owner) == who
+            }
+        }
+
+        #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+        pub struct RoyaltyInfo<AccountId> {
+            pub recipient: AccountId, // receives the royalty of secondary sales
+            pub bps: u16, // royalty in basis points of the sale price
+        }
+
+        /// The owner and the roles of a collection.
         #[pallet::storage]
-        pub type OwnedNFTs<T: Config> = StorageMap<
+        pub type NFTCollectionRoles<T: Config> = StorageMap<
             _,
             Blake2_128Concat,
-            T::AccountId,
-            BoundedVec<NftItemWithShare, MaxNftsLength>, // (collection, item_id, share)
+            H256, // collection
+            CollectionRoles<T::AccountId>,
+        >;
+
+        /// The frozen collections, NFTs of a frozen collection can not be minted or transferred.
+        #[pallet::storage]
+        pub type FrozenCollections<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            H256, // collection
+            (),
+        >;
+
+        /// The collections with locked metadata, the metadata of a collection and its NFTs can not
+        /// be changed once locked.
+        #[pallet::storage]
+        pub type LockedCollectionMetadata<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            H256, // collection
+            (),
+        >;
+
+        /// The NFTs with locked metadata, the metadata of an NFT can not be changed once locked.
+        #[pallet::storage]
+        pub type LockedNFTMetadata<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            NftItem,
+            (),
+        >;
+
+        /// The creator royalty of a collection, applies to NFTs without their own royalty.
+        #[pallet::storage]
+        pub type CollectionRoyalties<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            H256, // collection
+            RoyaltyInfo<T::AccountId>,
+        >;
+
+        /// The creator royalty of an NFT, overrides the royalty of its collection.
+        #[pallet::storage]
+        pub type NFTRoyalties<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            NftItem,
+            RoyaltyInfo<T::AccountId>,
+        >;
+
+        /// The deposits of collections and NFTs, the deposit of a collection has no item id.
+        #[pallet::storage]
+        pub type Deposits<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            (H256, Option<u32>), // (collection, item)
+            (T::AccountId, BalanceOf<T>), // (depositor, deposit)
         >;
 
+        /// The metadata deposits of collections and NFTs, reserved from the account which set the
+        /// metadata.
+        #[pallet::storage]
+        pub type MetadataDeposits<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            (H256, Option<u32>), // (collection, item)
+            (T::AccountId, BalanceOf<T>), // (depositor, deposit)
+        >;
+
+        /// The namespace of an attribute, which decides who can set it.
         #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-        pub struct NftInfo {
+        #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+        pub enum AttributeNamespace<AccountId> {
+            CollectionOwner, // set by the collection admin, locked with the metadata
+            ItemOwner, // set by the owner of the whole share of an NFT
+            Account(AccountId), // set by the account once approved
+        }
+
+        /// The attributes of collections and NFTs, the attributes of a collection have no item id.
+        #[pallet::storage]
+        pub type Attributes<T: Config> = StorageNMap<
+            _,
+            (
+                NMapKey<Blake2_128Concat, H256>, // collection
+                NMapKey<Blake2_128Concat, Option<u32>>, // item
+                NMapKey<Blake2_128Concat, AttributeNamespace<T::AccountId>>,
+                NMapKey<Blake2_128Concat, AttributeKeyOf<T>>,
+            ),
+            (AttributeValueOf<T>, T::AccountId, BalanceOf<T>), // (value, depositor, deposit)
+        >;
+
+        /// The accounts approved to set attributes in their own namespace of a collection or an NFT.
+        #[pallet::storage]
+        pub type AttributeApprovals<T: Config> = StorageDoubleMap<
+            _,
+            Blake2_128Concat,
+            (H256, Option<u32>), // (collection, item)
+            Blake2_128Concat,
+            T::AccountId, // delegate
+            (),
+        >;
+
+        /// The NFTs owned by an account and the owned share.
+        #[pallet::storage]
+        pub type OwnedNFTs<T: Config> = StorageDoubleMap<
+            _,
+            Blake2_128Concat,
+            T::AccountId,
+            Blake2_128Concat,
+            NftItem,
+            Share,
+        >;
+
+        /// The total share supply of an NFT.
+        #[pallet::storage]
+        pub type ShareSupply<T: Config> = StorageMap<
+            _,
+            Blake2_128Concat,
+            NftItem,
+            Share,
+        >;
+
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
+        pub struct NftInfo<T: Config> {
             pub merged_nft: Option<NftItem>, // for sub nft, record merged nft
-            pub sub_nfts: BoundedVec<NftItem, MaxSubNftsLength>, // for merged nft, record sub nfts
-            pub metadata: BoundedVec<u8, MaxMetadataLength>, // nft metadata
+            pub sub_nfts: BoundedVec<NftItem, T::MaxSubNftsLength>, // for merged nft, record sub nfts
+            pub metadata: BoundedVec<u8, T::MaxMetadataLength>, // nft metadata
         }
 
         /// The details of an NFT.
@@ -75,16 +314,40 @@ pub mod pallet {
             _,
             Blake2_128Concat,
             NftItem,
-            NftInfo
+            NftInfo<T>
+        >;
+
+        /// The owners of an NFT, the reverse index of `OwnedNFTs`.
+        #[pallet::storage]
+        pub type NFTOwners<T: Config> = StorageDoubleMap<
+            _,
+            Blake2_128Concat,
+            NftItem,
+            Blake2_128Concat,
+            T::AccountId, // owner
+            (),
         >;
 
-        /// The owner of an NFT.
+        /// The transfer approvals of NFTs, the delegate can transfer the NFT share of the owner.
         #[pallet::storage]
-        pub type NFTOwners<T: Config> = StorageMap<
+        pub type NFTApprovals<T: Config> = StorageDoubleMap<
             _,
             Blake2_128Concat,
             NftItem,
-            BoundedVec<T::AccountId, MaxNftOwners>, // nft owners
+            Blake2_128Concat,
+            T::AccountId, // owner
+            (T::AccountId, Option<BlockNumberFor<T>>), // (delegate, deadline)
+        >;
+
+        /// The operators of an account, an operator can transfer all NFTs of the account.
+        #[pallet::storage]
+        pub type OperatorApprovals<T: Config> = StorageDoubleMap<
+            _,
+            Blake2_128Concat,
+            T::AccountId, // owner
+            Blake2_128Concat,
+            T::AccountId, // operator
+            Option<BlockNumberFor<T>>, // deadline
         >;
 
         #[pallet::event]
@@ -97,9 +360,59 @@ pub mod pallet {
             /// An NFT was transfered.
             NFTTransferred(T::AccountId, T::AccountId, NftItem),
             /// An NFT was merged.
-            NFTMerged(T::AccountId, NftItem, BoundedVec::<NftItem, MaxSubNftsLength>),
+            NFTMerged(T::AccountId, NftItem, BoundedVec::<NftItem, T::MaxSubNftsLength>),
             /// An NFT was splited.
-            NFTSplited(T::AccountId, NftItem, BoundedVec::<NftItem, MaxSubNftsLength>),
+            NFTSplited(T::AccountId, NftItem, BoundedVec::<NftItem, T::MaxSubNftsLength>),
+            /// The team of a collection was changed.
+            CollectionTeamChanged(H256, Option<T::AccountId>, Option<T::AccountId>, Option<T::AccountId>), // collection, issuer, admin, freezer
+            /// The owner of a collection was changed.
+            CollectionOwnerChanged(H256, T::AccountId), // collection, new owner
+            /// The max items of a collection was changed.
+            CollectionMaxItemsSet(H256, u32), // collection, max_items
+            /// A collection was frozen.
+            CollectionFrozen(H256),
+            /// A collection was thawed.
+            CollectionThawed(H256),
+            /// An NFT or a part of its share was burned.
+            NFTBurned(T::AccountId, NftItem, Share), // account, nft, burned share
+            /// A delegate was approved to transfer the NFT share of an owner.
+            TransferApproved(T::AccountId, NftItem, T::AccountId, Option<BlockNumberFor<T>>), // owner, nft, delegate, deadline
+            /// A transfer approval was cancelled.
+            ApprovalCancelled(T::AccountId, NftItem, T::AccountId), // owner, nft, delegate
+            /// An operator was approved to transfer all NFTs of an owner.
+            OperatorApproved(T::AccountId, T::AccountId, Option<BlockNumberFor<T>>), // owner, operator, deadline
+            /// An operator approval was cancelled.
+            OperatorCancelled(T::AccountId, T::AccountId), // owner, operator
+            /// An NFT was fractionalized into a new share supply.
+            NFTFractionalized(T::AccountId, NftItem, Share), // owner, nft, share supply
+            /// A fractionalized NFT was unified.
+            NFTUnified(T::AccountId, NftItem), // owner, nft
+            /// The royalty of a collection was set or cleared.
+            CollectionRoyaltySet(H256, Option<RoyaltyInfo<T::AccountId>>),
+            /// The royalty of an NFT was set or cleared.
+            NFTRoyaltySet(NftItem, Option<RoyaltyInfo<T::AccountId>>),
+            /// The metadata of a collection was set.
+            CollectionMetadataSet(H256, BoundedVec<u8, T::MaxMetadataLength>),
+            /// The metadata of a collection was cleared.
+            CollectionMetadataCleared(H256),
+            /// The metadata of a collection and its NFTs was locked.
+            CollectionMetadataLocked(H256),
+            /// The metadata of an NFT was set.
+            NFTMetadataSet(NftItem, BoundedVec<u8, T::MaxMetadataLength>),
+            /// The metadata of an NFT was cleared.
+            NFTMetadataCleared(NftItem),
+            /// The metadata of an NFT was locked.
+            NFTMetadataLocked(NftItem),
+            /// An attribute of a collection or an NFT was set.
+            AttributeSet(H256, Option<u32>, AttributeNamespace<T::AccountId>, AttributeKeyOf<T>, AttributeValueOf<T>), // collection, item, namespace, key, value
+            /// An attribute of a collection or an NFT was cleared.
+            AttributeCleared(H256, Option<u32>, AttributeNamespace<T::AccountId>, AttributeKeyOf<T>), // collection, item, namespace, key
+            /// An account was approved to set attributes of a collection or an NFT.
+            AttributesApproved(H256, Option<u32>, T::AccountId), // collection, item, delegate
+            /// An attributes approval was cancelled.
+            AttributesApprovalCancelled(H256, Option<u32>, T::AccountId), // collection, item, delegate
+            /// The deposits of a collection or an NFT were set by root.
+            DepositsForceSet(H256, Option<u32>, T::AccountId, BalanceOf<T>, BalanceOf<T>), // collection, item, depositor, deposit, metadata deposit
         }
 
         #[pallet::error]
@@ -132,13 +445,51 @@ pub mod pallet {
             NFTNotEnoughShare,
             /// The NFT can not be merged or splited.
             NFTCanNotMergeOrSplit,
+            /// The signed account does not have the required collection role.
+            NoPermission,
+            /// The collection is frozen.
+            CollectionIsFrozen,
+            /// The max items is less than the number of minted NFTs.
+            MaxItemsTooSmall,
+            /// The merged NFT can only be burned as a whole.
+            NFTPartialBurnNotAllowed,
+            /// The signed account is not approved to transfer the NFT.
+            NotApproved,
+            /// The approval has expired.
+            ApprovalExpired,
+            /// The share supply of an NFT can not be zero.
+            ZeroShareSupply,
+            /// The royalty exceeds 10_000 basis points.
+            RoyaltyTooHigh,
+            /// The metadata is locked.
+            MetadataIsLocked,
+            /// The namespace does not apply to the attribute.
+            WrongNamespace,
+            /// The attribute is not found.
+            AttributeNotFound,
+            /// The balance is not enough for the deposit.
+            InsufficientBalance,
+            /// The item id is not the next item id of the collection.
+            WrongItemId,
+            /// The share can not be zero.
+            ZeroShare,
+        }
+
+        #[pallet::hooks]
+        impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+            #[cfg(feature = "try-runtime")]
+            fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+                Self::do_try_state()
+            }
         }
 
         #[pallet::call]
         impl<T: Config> Pallet<T> {
-            /// Create an NFT collection.
+            /// Create an NFT collection, the collection id is derived from the creator, the
+            /// collection nonce and the block number.
             ///
-            /// The origin must be signed.
+            /// The origin must be signed. The deposits of the collection and its metadata are
+            /// reserved from the signed account.
             ///
             /// Parameters:
             /// - `max_items`: The maximum NFT number of the collection.
@@ -147,30 +498,17 @@ pub mod pallet {
             /// Emits `NFTCollectionCreated` event when successful.
             #[pallet::call_index(0)]
             #[pallet::weight(T::WeightInfo::create_collection())]
-            pub fn create_collection(origin: OriginFor<T>, max_items: u32, metadata: BoundedVec<u8, MaxMetadataLength>) -> DispatchResult {
+            pub fn create_collection(origin: OriginFor<T>, max_items: u32, metadata: BoundedVec<u8, T::MaxMetadataLength>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
-                let collection_id_array = blake2_256(&metadata);
-                let collection_id = H256::from_slice(&collection_id_array);
-
-                ensure!(!NFTCollections::<T>::contains_key(&collection_id), Error::<T>::CollectionAlreadyExists);
-
-                NFTCollections::<T>::insert(&collection_id, (max_items, 0, metadata));
-                NFTCollectionIds::<T>::mutate(|col| {
-                    if col.is_none() {
-                        *col = Some(BoundedVec::<H256, MaxCollectionsLength>::default());
-                    }
-                    if let Some(col_value) = col {
-                        col_value.try_push(collection_id).unwrap_or_default();
-                    }
-                });
+                let collection_id = Self::next_collection_id(&sender);
 
-                Self::deposit_event(Event::NFTCollectionCreated(sender, collection_id, max_items));
-                Ok(())
+                Self::do_create_collection(&sender, sender.clone(), collection_id, max_items, metadata)
             }
 
             /// Mint an NFT.
             ///
-            /// The origin must be signed.
+            /// The origin must be signed by the issuer of the collection. The deposits of the NFT and
+            /// its metadata are reserved from the signed account and returned when the NFT is burned.
             ///
             /// Parameters:
             /// - `collection_id`: The collection id of an NFT.
@@ -179,43 +517,13 @@ pub mod pallet {
             /// Emits `NFTMinted` event when successful.
             #[pallet::call_index(1)]
             #[pallet::weight(T::WeightInfo::mint_nft())]
-            pub fn mint_nft(origin: OriginFor<T>, collection_id: H256, metadata: BoundedVec<u8, MaxMetadataLength>) -> DispatchResult {
+            pub fn mint_nft(origin: OriginFor<T>, collection_id: H256, metadata: BoundedVec<u8, T::MaxMetadataLength>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
 
-                ensure!(NFTCollections::<T>::contains_key(&collection_id), Error::<T>::CollectionNotFound);
-
-                let (max_items, cur_item_index, collection_metadata) = NFTCollections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
-                ensure!(cur_item_index < max_items, Error::<T>::NFTExceeds);
-
-                let nft_item = (collection_id, cur_item_index);
-                let nft_item_with_share = (collection_id, cur_item_index, 100);
-                OwnedNFTs::<T>::mutate(&sender, |nfts| {
-                    if nfts.is_none() {
-                        *nfts = Some(BoundedVec::<NftItemWithShare, MaxNftsLength>::default());
-                    }
-                    if let Some(nfts_value) = nfts {
-                        nfts_value.try_push(nft_item_with_share).unwrap_or_default();
-                    }
-                });
-
-                let nft_info = NftInfo {
-                    merged_nft: None,
-                    sub_nfts: BoundedVec::default(),
-                    metadata: metadata,
-                };
-                NFTDetails::<T>::insert(nft_item, nft_info);
-                NFTOwners::<T>::mutate(&nft_item, |accounts| {
-                    if accounts.is_none() {
-                        *accounts = Some(BoundedVec::<T::AccountId, MaxNftOwners>::default());
-                    }
-                    if let Some(accounts_value) = accounts {
-                        accounts_value.try_push(sender.clone()).unwrap_or_default();
-                    }
-                });
-                NFTCollections::<T>::insert(&collection_id, (max_items, cur_item_index + 1, collection_metadata));
+                let roles = NFTCollectionRoles::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_issuer(&sender), Error::<T>::NoPermission);
 
-                Self::deposit_event(Event::NFTMinted(sender, nft_item));
-                Ok(())
+                Self::do_mint(&sender, sender.clone(), collection_id, metadata).map(|_| ())
             }
 
             /// Transfer an NFT.
@@ -229,77 +537,11 @@ pub mod pallet {
             ///
             /// Emits `NFTTransferred` event when successful.
             #[pallet::call_index(2)]
-            #[pallet::weight(T::WeightInfo::transfer_nft())]
-            pub fn transfer_nft(origin: OriginFor<T>, to: T::AccountId, nft_item: NftItem, share: u8) -> DispatchResult {
+            #[pallet::weight(T::WeightInfo::transfer_nft().saturating_add(Self::hook_weight(T::MaxSubNftsLength::get())))]
+            pub fn transfer_nft(origin: OriginFor<T>, to: T::AccountId, nft_item: NftItem, share: Share) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
 
-                // Retrieve NFT details and ensure the NFT exists
-                let nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
-                if let Some(merged_nft) = nft_details.merged_nft {
-                    ensure!(merged_nft == nft_item, Error::<T>::NFTIsFrozen);
-
-                    let nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
-                    for (index, sub_nft_item) in nft_details.sub_nfts.iter().enumerate() {
-                        if index == 0 {
-                            continue;
-                        }
-                        // update OwnedNFTs
-                        let mut sender_owned_nfts = OwnedNFTs::<T>::get(&sender).ok_or(Error::<T>::NFTNotFound)?;
-                        sender_owned_nfts.retain(|nft_item| *nft_item != (sub_nft_item.0, sub_nft_item.1, 100));
-                        OwnedNFTs::<T>::insert(&sender, sender_owned_nfts);
-                        let mut receiver_owned_nfts = OwnedNFTs::<T>::get(&to).unwrap_or_default();
-                        receiver_owned_nfts.try_push((sub_nft_item.0, sub_nft_item.1, 100)).unwrap_or_default();
-                        OwnedNFTs::<T>::insert(&to, receiver_owned_nfts);
-
-                        // update NFTOwners
-                        let mut nft_owners = NFTOwners::<T>::get(sub_nft_item).ok_or(Error::<T>::NFTNotFound)?;
-                        nft_owners.try_push(to.clone()).unwrap_or_default();
-                        nft_owners.retain(|owner| *owner != sender);
-                        NFTOwners::<T>::insert(&sub_nft_item, nft_owners);
-                    }
-                }
-
-                // Retrieve sender's owned NFTs
-                let mut sender_owned_nfts = OwnedNFTs::<T>::get(&sender).ok_or(Error::<T>::NFTNotFound)?;
-                let sender_nft_item_with_share_index = sender_owned_nfts.iter()
-                    .position(|&nft| nft.0 == nft_item.0 && nft.1 == nft_item.1)
-                    .ok_or(Error::<T>::NotOwner)?;
-                let sender_nft_item_with_share = sender_owned_nfts[sender_nft_item_with_share_index];
-                ensure!(sender_nft_item_with_share.2 >= share, Error::<T>::NFTNotEnoughShare);
-
-                let mut remove_sender_nft = false;
-                // Update sender's owned NFTs
-                if sender_nft_item_with_share.2 == share {
-                    sender_owned_nfts.remove(sender_nft_item_with_share_index);
-                    remove_sender_nft = true;
-                } else {
-                    sender_owned_nfts[sender_nft_item_with_share_index].2 -= share;
-                }
-                OwnedNFTs::<T>::insert(&sender, sender_owned_nfts);
-
-                // Retrieve receiver's owned NFTs
-                let mut receiver_owned_nfts = OwnedNFTs::<T>::get(&to).unwrap_or_default();
-                let receiver_nft_item_with_share = receiver_owned_nfts.iter_mut()
-                    .find(|nft| nft.0 == nft_item.0 && nft.1 == nft_item.1);
-            
-                // Update receiver's owned NFTs
-                if let Some(nft_item_with_share) = receiver_nft_item_with_share {
-                    nft_item_with_share.2 += share;
-                } else {
-                    receiver_owned_nfts.try_push((nft_item.0, nft_item.1, share)).unwrap_or_default();
-                }
-                OwnedNFTs::<T>::insert(&to, receiver_owned_nfts);
-            
-                // Update NFT owners
-                let mut nft_owners = NFTOwners::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
-                if !nft_owners.contains(&to) {
-                    nft_owners.try_push(to.clone()).unwrap_or_default();
-                }
-                if remove_sender_nft {
-                    nft_owners.retain(|owner| *owner != sender);
-                }
-                NFTOwners::<T>::insert(&nft_item, nft_owners);
-                Ok(())
+                Self::do_transfer(sender, to, nft_item, share)
             }
 
             /// Merge NFTs.
@@ -311,36 +553,25 @@ pub mod pallet {
             ///
             /// Emits `NFTMerged` event when successful.
             #[pallet::call_index(3)]
-            #[pallet::weight(T::WeightInfo::merge_nfts())]
-            pub fn merge_nfts(origin: OriginFor<T>, nft_items: BoundedVec::<NftItem, MaxSubNftsLength>) -> DispatchResult {
+            #[pallet::weight(T::WeightInfo::merge_nfts().saturating_add(Self::hook_weight(nft_items.len() as u32)))]
+            pub fn merge_nfts(origin: OriginFor<T>, nft_items: BoundedVec::<NftItem, T::MaxSubNftsLength>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
-                let mut sub_nfts: BoundedVec::<NftItem, MaxSubNftsLength> = BoundedVec::<NftItem, MaxSubNftsLength>::default();
+                let sub_nfts: BoundedVec::<NftItem, T::MaxSubNftsLength> = nft_items.clone();
                 let mut merged_nft: NftItem = NftItem::default();
 
                 ensure!(nft_items.len() > 1, Error::<T>::NFTNoSubNfts);
 
-                let sender_owned_nfts = OwnedNFTs::<T>::get(&sender).ok_or(Error::<T>::NFTNotFound)?;
                 for (index, nft_item) in nft_items.iter().enumerate() {
                     let mut nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
                     ensure!(nft_details.merged_nft.is_none(), Error::<T>::NFTAlreadyMerged);
-                    ensure!(
-                        sender_owned_nfts.iter().any(|&nft| nft.0 == nft_item.0 && nft.1 == nft_item.1),
-                        Error::<T>::NotOwner
-                    );
-                    ensure!(
-                        sender_owned_nfts.iter().any(|&nft| nft.0 == nft_item.0 && nft.1 == nft_item.1 && nft.2 == 100),
-                        Error::<T>::NFTCanNotMergeOrSplit
-                    );
+                    // only the sole owner of the whole share can merge the NFT
+                    let sender_share = OwnedNFTs::<T>::get(&sender, nft_item).ok_or(Error::<T>::NotOwner)?;
+                    ensure!(sender_share == Self::share_supply(nft_item), Error::<T>::NFTCanNotMergeOrSplit);
 
                     if index == 0 {
                         merged_nft = *nft_item;
-                        for nft in &nft_items {
-                            sub_nfts.try_push(*nft).unwrap_or_default();
-                        }
                     }
 
-                    let accounts = NFTOwners::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
-                    ensure!(accounts.len() == 1, Error::<T>::NFTCanNotMergeOrSplit);
                     nft_details.merged_nft = Some(merged_nft);
                     NFTDetails::<T>::mutate(nft_item, |details_wrap| {
                         if let Some(details) = details_wrap {
@@ -350,6 +581,7 @@ pub mod pallet {
                             }
                         }
                     });
+                    T::OnNftTransfer::on_mutate(*nft_item);
                 }
 
                 Self::deposit_event(Event::NFTMerged(sender, merged_nft, sub_nfts));
@@ -365,30 +597,21 @@ pub mod pallet {
             ///
             /// Emits `NFTSplited` event when successful.
             #[pallet::call_index(4)]
-            #[pallet::weight(T::WeightInfo::split_nft())]
+            #[pallet::weight(T::WeightInfo::split_nft().saturating_add(Self::hook_weight(T::MaxSubNftsLength::get())))]
             pub fn split_nft(origin: OriginFor<T>, nft_item: NftItem) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
                 let nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
                 let sub_nfts = nft_details.sub_nfts.clone();
 
-                let sender_owned_nfts = OwnedNFTs::<T>::get(&sender).ok_or(Error::<T>::NFTNotFound)?;
-                ensure!(
-                    sender_owned_nfts.iter().any(|&nft| nft.0 == nft_item.0 && nft.1 == nft_item.1),
-                    Error::<T>::NotOwner
-                );
-                ensure!(
-                    sender_owned_nfts.iter().any(|&nft| nft.0 == nft_item.0 && nft.1 == nft_item.1 && nft.2 == 100),
-                    Error::<T>::NFTCanNotMergeOrSplit
-                );
+                // only the sole owner of the whole share can split the NFT
+                let sender_share = OwnedNFTs::<T>::get(&sender, nft_item).ok_or(Error::<T>::NotOwner)?;
+                ensure!(sender_share == Self::share_supply(nft_item), Error::<T>::NFTCanNotMergeOrSplit);
 
                 ensure!(!nft_details.merged_nft.is_none(), Error::<T>::NFTNotMerged);
                 if let Some(merged_nft) = nft_details.merged_nft {
                     ensure!(nft_item == merged_nft, Error::<T>::NFTIsNotTheMerged);
                 }
 
-                let accounts = NFTOwners::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
-                ensure!(accounts.len() == 1, Error::<T>::NFTCanNotMergeOrSplit);
-
                 for (index, sub_nft_item) in sub_nfts.iter().enumerate() {
                     NFTDetails::<T>::mutate(sub_nft_item, |details_wrap| {
                         if let Some(details) = details_wrap {
@@ -398,10 +621,1231 @@ pub mod pallet {
                             details.merged_nft = None;
                         }
                     });
+                    T::OnNftTransfer::on_mutate(*sub_nft_item);
                 }
 
                 Self::deposit_event(Event::NFTSplited(sender, nft_item, sub_nfts));
                 Ok(())
             }
+
+            /// Set the issuer, admin and freezer of a collection.
+            ///
+            /// The origin must be signed by the owner of the collection.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `issuer`: The account which can mint NFTs, the owner if `None`.
+            /// - `admin`: The account which can change the collection settings, the owner if `None`.
+            /// - `freezer`: The account which can freeze and thaw the collection, the owner if `None`.
+            ///
+            /// Emits `CollectionTeamChanged` event when successful.
+            #[pallet::call_index(5)]
+            #[pallet::weight(T::WeightInfo::set_collection_team())]
+            pub fn set_collection_team(origin: OriginFor<T>,
+                                       collection_id: H256,
+                                       issuer: Option<T::AccountId>,
+                                       admin: Option<T::AccountId>,
+                                       freezer: Option<T::AccountId>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                NFTCollectionRoles::<T>::try_mutate(&collection_id, |roles_wrap| -> DispatchResult {
+                    let roles = roles_wrap.as_mut().ok_or(Error::<T>::CollectionNotFound)?;
+                    ensure!(roles.is_owner(&sender), Error::<T>::NoPermission);
+                    roles.issuer = issuer.clone();
+                    roles.admin = admin.clone();
+                    roles.freezer = freezer.clone();
+                    Ok(())
+                })?;
+
+                Self::deposit_event(Event::CollectionTeamChanged(collection_id, issuer, admin, freezer));
+                Ok(())
+            }
+
+            /// Hand over the ownership of a collection.
+            ///
+            /// The origin must be signed by the owner of the collection.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `new_owner`: The new owner of the collection.
+            ///
+            /// Emits `CollectionOwnerChanged` event when successful.
+            #[pallet::call_index(6)]
+            #[pallet::weight(T::WeightInfo::transfer_collection_ownership())]
+            pub fn transfer_collection_ownership(origin: OriginFor<T>, collection_id: H256, new_owner: T::AccountId) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                NFTCollectionRoles::<T>::try_mutate(&collection_id, |roles_wrap| -> DispatchResult {
+                    let roles = roles_wrap.as_mut().ok_or(Error::<T>::CollectionNotFound)?;
+                    ensure!(roles.is_owner(&sender), Error::<T>::NoPermission);
+                    roles.owner = new_owner.clone();
+                    Ok(())
+                })?;
+
+                Self::deposit_event(Event::CollectionOwnerChanged(collection_id, new_owner));
+                Ok(())
+            }
+
+            /// Change the maximum NFT number of a collection.
+            ///
+            /// The origin must be signed by the admin of the collection.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `max_items`: The new maximum NFT number, not less than the minted NFT number.
+            ///
+            /// Emits `CollectionMaxItemsSet` event when successful.
+            #[pallet::call_index(7)]
+            #[pallet::weight(T::WeightInfo::set_collection_max_items())]
+            pub fn set_collection_max_items(origin: OriginFor<T>, collection_id: H256, max_items: u32) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                let roles = NFTCollectionRoles::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_admin(&sender), Error::<T>::NoPermission);
+
+                NFTCollections::<T>::try_mutate(&collection_id, |collection_wrap| -> DispatchResult {
+                    let collection = collection_wrap.as_mut().ok_or(Error::<T>::CollectionNotFound)?;
+                    ensure!(max_items >= collection.1, Error::<T>::MaxItemsTooSmall);
+                    collection.0 = max_items;
+                    Ok(())
+                })?;
+
+                Self::deposit_event(Event::CollectionMaxItemsSet(collection_id, max_items));
+                Ok(())
+            }
+
+            /// Freeze a collection, its NFTs can not be minted or transferred until thawed.
+            ///
+            /// The origin must be signed by the freezer of the collection.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            ///
+            /// Emits `CollectionFrozen` event when successful.
+            #[pallet::call_index(8)]
+            #[pallet::weight(T::WeightInfo::freeze_collection())]
+            pub fn freeze_collection(origin: OriginFor<T>, collection_id: H256) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                let roles = NFTCollectionRoles::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_freezer(&sender), Error::<T>::NoPermission);
+
+                FrozenCollections::<T>::insert(&collection_id, ());
+
+                Self::deposit_event(Event::CollectionFrozen(collection_id));
+                Ok(())
+            }
+
+            /// Thaw a frozen collection.
+            ///
+            /// The origin must be signed by the freezer of the collection.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            ///
+            /// Emits `CollectionThawed` event when successful.
+            #[pallet::call_index(9)]
+            #[pallet::weight(T::WeightInfo::thaw_collection())]
+            pub fn thaw_collection(origin: OriginFor<T>, collection_id: H256) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                let roles = NFTCollectionRoles::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_freezer(&sender), Error::<T>::NoPermission);
+
+                FrozenCollections::<T>::remove(&collection_id);
+
+                Self::deposit_event(Event::CollectionThawed(collection_id));
+                Ok(())
+            }
+
+            /// Burn an NFT, or a part of the share owned by the signed account.
+            ///
+            /// The origin must be signed.
+            ///
+            /// The NFT is destroyed once no account owns a share of it. Burning a merged NFT
+            /// requires the whole share and also destroys all its sub NFTs.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT to burn.
+            /// - `share`: The NFT share to burn.
+            ///
+            /// Emits `NFTBurned` event when successful.
+            #[pallet::call_index(10)]
+            #[pallet::weight(T::WeightInfo::burn_nft(T::MaxSubNftsLength::get()).saturating_add(Self::hook_weight(T::MaxSubNftsLength::get())))]
+            pub fn burn_nft(origin: OriginFor<T>, nft_item: NftItem, share: Share) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::do_burn(sender, nft_item, share)
+            }
+
+            /// Approve a delegate to transfer the NFT share of the signed account.
+            ///
+            /// The origin must be signed. An NFT share can only have one delegate, a new approval
+            /// replaces the old one.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT to approve.
+            /// - `delegate`: The account which can transfer the NFT share.
+            /// - `maybe_deadline`: The last block number at which the approval is valid.
+            ///
+            /// Emits `TransferApproved` event when successful.
+            #[pallet::call_index(11)]
+            #[pallet::weight(T::WeightInfo::approve_transfer())]
+            pub fn approve_transfer(origin: OriginFor<T>,
+                                    nft_item: NftItem,
+                                    delegate: T::AccountId,
+                                    maybe_deadline: Option<BlockNumberFor<T>>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                ensure!(NFTDetails::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
+                ensure!(OwnedNFTs::<T>::contains_key(&sender, nft_item), Error::<T>::NotOwner);
+
+                NFTApprovals::<T>::insert(nft_item, &sender, (delegate.clone(), maybe_deadline));
+
+                Self::deposit_event(Event::TransferApproved(sender, nft_item, delegate, maybe_deadline));
+                Ok(())
+            }
+
+            /// Cancel the transfer approval of the NFT share of the signed account.
+            ///
+            /// The origin must be signed.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The approved NFT.
+            ///
+            /// Emits `ApprovalCancelled` event when successful.
+            #[pallet::call_index(12)]
+            #[pallet::weight(T::WeightInfo::cancel_approval())]
+            pub fn cancel_approval(origin: OriginFor<T>, nft_item: NftItem) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                let (delegate, _) = NFTApprovals::<T>::take(nft_item, &sender).ok_or(Error::<T>::NotApproved)?;
+
+                Self::deposit_event(Event::ApprovalCancelled(sender, nft_item, delegate));
+                Ok(())
+            }
+
+            /// Approve an operator to transfer all NFTs of the signed account.
+            ///
+            /// The origin must be signed.
+            ///
+            /// Parameters:
+            /// - `operator`: The account which can transfer all NFTs.
+            /// - `maybe_deadline`: The last block number at which the approval is valid.
+            ///
+            /// Emits `OperatorApproved` event when successful.
+            #[pallet::call_index(13)]
+            #[pallet::weight(T::WeightInfo::approve_operator())]
+            pub fn approve_operator(origin: OriginFor<T>,
+                                    operator: T::AccountId,
+                                    maybe_deadline: Option<BlockNumberFor<T>>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                OperatorApprovals::<T>::insert(&sender, &operator, maybe_deadline);
+
+                Self::deposit_event(Event::OperatorApproved(sender, operator, maybe_deadline));
+                Ok(())
+            }
+
+            /// Cancel an operator of the signed account.
+            ///
+            /// The origin must be signed.
+            ///
+            /// Parameters:
+            /// - `operator`: The approved operator.
+            ///
+            /// Emits `OperatorCancelled` event when successful.
+            #[pallet::call_index(14)]
+            #[pallet::weight(T::WeightInfo::cancel_operator())]
+            pub fn cancel_operator(origin: OriginFor<T>, operator: T::AccountId) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                ensure!(OperatorApprovals::<T>::contains_key(&sender, &operator), Error::<T>::NotApproved);
+                OperatorApprovals::<T>::remove(&sender, &operator);
+
+                Self::deposit_event(Event::OperatorCancelled(sender, operator));
+                Ok(())
+            }
+
+            /// Transfer an NFT share on behalf of its owner.
+            ///
+            /// The origin must be signed by an approved delegate of the NFT share or an operator of
+            /// the owner.
+            ///
+            /// Parameters:
+            /// - `from`: The owner of the NFT share.
+            /// - `to`: The target account id for the transfer.
+            /// - `nft_item`: The NFT to transfer.
+            /// - `share`: The NFT share to transfer.
+            ///
+            /// Emits `NFTTransferred` event when successful.
+            #[pallet::call_index(15)]
+            #[pallet::weight(T::WeightInfo::transfer_from().saturating_add(Self::hook_weight(T::MaxSubNftsLength::get())))]
+            pub fn transfer_from(origin: OriginFor<T>,
+                                 from: T::AccountId,
+                                 to: T::AccountId,
+                                 nft_item: NftItem,
+                                 share: Share) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_approved(&from, &sender, nft_item)?;
+
+                Self::do_transfer(from, to, nft_item, share)
+            }
+
+            /// Fractionalize an NFT into a new supply of fungible shares.
+            ///
+            /// The origin must be signed by the owner of the whole share supply, who receives all the
+            /// new shares.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT to fractionalize.
+            /// - `share_supply`: The new total share supply of the NFT.
+            ///
+            /// Emits `NFTFractionalized` event when successful.
+            #[pallet::call_index(16)]
+            #[pallet::weight(T::WeightInfo::fractionalize().saturating_add(Self::hook_weight(1)))]
+            pub fn fractionalize(origin: OriginFor<T>, nft_item: NftItem, share_supply: Share) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                ensure!(share_supply > 0, Error::<T>::ZeroShareSupply);
+
+                Self::ensure_whole_share(&sender, nft_item)?;
+                OwnedNFTs::<T>::insert(&sender, nft_item, share_supply);
+                ShareSupply::<T>::insert(nft_item, share_supply);
+                T::OnNftTransfer::on_mutate(nft_item);
+
+                Self::deposit_event(Event::NFTFractionalized(sender, nft_item, share_supply));
+                Ok(())
+            }
+
+            /// Unify a fractionalized NFT, the share supply is reset to the default share supply.
+            ///
+            /// The origin must be signed by the owner of the whole share supply.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT to unify.
+            ///
+            /// Emits `NFTUnified` event when successful.
+            #[pallet::call_index(17)]
+            #[pallet::weight(T::WeightInfo::unify().saturating_add(Self::hook_weight(1)))]
+            pub fn unify(origin: OriginFor<T>, nft_item: NftItem) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_whole_share(&sender, nft_item)?;
+                let share_supply = T::DefaultShareSupply::get();
+                OwnedNFTs::<T>::insert(&sender, nft_item, share_supply);
+                ShareSupply::<T>::insert(nft_item, share_supply);
+                T::OnNftTransfer::on_mutate(nft_item);
+
+                Self::deposit_event(Event::NFTUnified(sender, nft_item));
+                Ok(())
+            }
+
+            /// Set or clear the royalty paid on secondary sales of the NFTs of a collection.
+            ///
+            /// The origin must be signed by the admin of the collection.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `royalty`: The royalty recipient and basis points, `None` to clear the royalty.
+            ///
+            /// Emits `CollectionRoyaltySet` event when successful.
+            #[pallet::call_index(18)]
+            #[pallet::weight(T::WeightInfo::set_collection_royalty())]
+            pub fn set_collection_royalty(origin: OriginFor<T>,
+                                          collection_id: H256,
+                                          royalty: Option<RoyaltyInfo<T::AccountId>>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                let roles = NFTCollectionRoles::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_admin(&sender), Error::<T>::NoPermission);
+                ensure!(royalty.as_ref().map_or(true, |royalty| royalty.bps <= MAX_ROYALTY_BPS), Error::<T>::RoyaltyTooHigh);
+
+                CollectionRoyalties::<T>::set(&collection_id, royalty.clone());
+
+                Self::deposit_event(Event::CollectionRoyaltySet(collection_id, royalty));
+                Ok(())
+            }
+
+            /// Set or clear the royalty paid on secondary sales of an NFT.
+            ///
+            /// The origin must be signed by the admin of the collection.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT.
+            /// - `royalty`: The royalty recipient and basis points, `None` to clear the royalty.
+            ///
+            /// Emits `NFTRoyaltySet` event when successful.
+            #[pallet::call_index(19)]
+            #[pallet::weight(T::WeightInfo::set_nft_royalty())]
+            pub fn set_nft_royalty(origin: OriginFor<T>,
+                                   nft_item: NftItem,
+                                   royalty: Option<RoyaltyInfo<T::AccountId>>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                let roles = NFTCollectionRoles::<T>::get(&nft_item.0).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_admin(&sender), Error::<T>::NoPermission);
+                ensure!(NFTDetails::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
+                ensure!(royalty.as_ref().map_or(true, |royalty| royalty.bps <= MAX_ROYALTY_BPS), Error::<T>::RoyaltyTooHigh);
+
+                NFTRoyalties::<T>::set(nft_item, royalty.clone());
+
+                Self::deposit_event(Event::NFTRoyaltySet(nft_item, royalty));
+                Ok(())
+            }
+
+            /// Create an NFT collection with a deterministic id, derived from the creator and a salt
+            /// chosen by the creator.
+            ///
+            /// The origin must be signed. The deposits of the collection and its metadata are
+            /// reserved from the signed account.
+            ///
+            /// Parameters:
+            /// - `max_items`: The maximum NFT number of the collection.
+            /// - `metadata`: The collection metadata.
+            /// - `salt`: The salt of the collection id, a creator can use a salt once.
+            ///
+            /// Emits `NFTCollectionCreated` event when successful.
+            #[pallet::call_index(20)]
+            #[pallet::weight(T::WeightInfo::create_collection_with_salt())]
+            pub fn create_collection_with_salt(origin: OriginFor<T>,
+                                               max_items: u32,
+                                               metadata: BoundedVec<u8, T::MaxMetadataLength>,
+                                               salt: [u8; 32]) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let collection_id = Self::salted_collection_id(&sender, salt);
+
+                Self::do_create_collection(&sender, sender.clone(), collection_id, max_items, metadata)
+            }
+
+            /// Set the metadata of a collection.
+            ///
+            /// The origin must be signed by the admin of the collection. The metadata deposit is
+            /// reserved from the signed account and the old one is returned.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `metadata`: The new collection metadata.
+            ///
+            /// Emits `CollectionMetadataSet` event when successful.
+            #[pallet::call_index(21)]
+            #[pallet::weight(T::WeightInfo::set_collection_metadata())]
+            pub fn set_collection_metadata(origin: OriginFor<T>,
+                                           collection_id: H256,
+                                           metadata: BoundedVec<u8, T::MaxMetadataLength>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_collection_metadata_mutable(&sender, &collection_id)?;
+                Self::do_set_collection_metadata(&sender, &collection_id, metadata.clone())?;
+
+                Self::deposit_event(Event::CollectionMetadataSet(collection_id, metadata));
+                Ok(())
+            }
+
+            /// Clear the metadata of a collection.
+            ///
+            /// The origin must be signed by the admin of the collection. The metadata deposit is
+            /// returned.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            ///
+            /// Emits `CollectionMetadataCleared` event when successful.
+            #[pallet::call_index(22)]
+            #[pallet::weight(T::WeightInfo::clear_collection_metadata())]
+            pub fn clear_collection_metadata(origin: OriginFor<T>, collection_id: H256) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_collection_metadata_mutable(&sender, &collection_id)?;
+                Self::do_set_collection_metadata(&sender, &collection_id, BoundedVec::default())?;
+
+                Self::deposit_event(Event::CollectionMetadataCleared(collection_id));
+                Ok(())
+            }
+
+            /// Lock the metadata of a collection and all its NFTs permanently.
+            ///
+            /// The origin must be signed by the admin of the collection.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            ///
+            /// Emits `CollectionMetadataLocked` event when successful.
+            #[pallet::call_index(23)]
+            #[pallet::weight(T::WeightInfo::lock_collection_metadata())]
+            pub fn lock_collection_metadata(origin: OriginFor<T>, collection_id: H256) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_collection_metadata_mutable(&sender, &collection_id)?;
+                LockedCollectionMetadata::<T>::insert(&collection_id, ());
+
+                Self::deposit_event(Event::CollectionMetadataLocked(collection_id));
+                Ok(())
+            }
+
+            /// Set the metadata of an NFT.
+            ///
+            /// The origin must be signed by the admin of the collection. The metadata deposit is
+            /// reserved from the signed account and the old one is returned.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT.
+            /// - `metadata`: The new NFT metadata.
+            ///
+            /// Emits `NFTMetadataSet` event when successful.
+            #[pallet::call_index(24)]
+            #[pallet::weight(T::WeightInfo::set_nft_metadata())]
+            pub fn set_nft_metadata(origin: OriginFor<T>,
+                                    nft_item: NftItem,
+                                    metadata: BoundedVec<u8, T::MaxMetadataLength>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_nft_metadata_mutable(&sender, nft_item)?;
+                Self::do_set_nft_metadata(&sender, nft_item, metadata.clone())?;
+
+                Self::deposit_event(Event::NFTMetadataSet(nft_item, metadata));
+                Ok(())
+            }
+
+            /// Clear the metadata of an NFT.
+            ///
+            /// The origin must be signed by the admin of the collection. The metadata deposit is
+            /// returned.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT.
+            ///
+            /// Emits `NFTMetadataCleared` event when successful.
+            #[pallet::call_index(25)]
+            #[pallet::weight(T::WeightInfo::clear_nft_metadata())]
+            pub fn clear_nft_metadata(origin: OriginFor<T>, nft_item: NftItem) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_nft_metadata_mutable(&sender, nft_item)?;
+                Self::do_set_nft_metadata(&sender, nft_item, BoundedVec::default())?;
+
+                Self::deposit_event(Event::NFTMetadataCleared(nft_item));
+                Ok(())
+            }
+
+            /// Lock the metadata of an NFT permanently.
+            ///
+            /// The origin must be signed by the admin of the collection.
+            ///
+            /// Parameters:
+            /// - `nft_item`: The NFT.
+            ///
+            /// Emits `NFTMetadataLocked` event when successful.
+            #[pallet::call_index(26)]
+            #[pallet::weight(T::WeightInfo::lock_nft_metadata())]
+            pub fn lock_nft_metadata(origin: OriginFor<T>, nft_item: NftItem) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_nft_metadata_mutable(&sender, nft_item)?;
+                ensure!(NFTDetails::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
+                LockedNFTMetadata::<T>::insert(nft_item, ());
+
+                Self::deposit_event(Event::NFTMetadataLocked(nft_item));
+                Ok(())
+            }
+
+            /// Set an attribute of a collection or an NFT, a deposit for the key and value is
+            /// reserved from the signed account.
+            ///
+            /// The origin must be signed by the collection admin for the `CollectionOwner`
+            /// namespace, by the owner of the whole share of the NFT for the `ItemOwner` namespace,
+            /// or by an approved account for its own namespace.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `maybe_item`: The item id of the NFT, `None` for an attribute of the collection.
+            /// - `namespace`: The attribute namespace.
+            /// - `key`: The attribute key.
+            /// - `value`: The attribute value.
+            ///
+            /// Emits `AttributeSet` event when successful.
+            #[pallet::call_index(27)]
+            #[pallet::weight(T::WeightInfo::set_attribute())]
+            pub fn set_attribute(origin: OriginFor<T>,
+                                 collection_id: H256,
+                                 maybe_item: Option<u32>,
+                                 namespace: AttributeNamespace<T::AccountId>,
+                                 key: AttributeKeyOf<T>,
+                                 value: AttributeValueOf<T>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_attribute_permission(&sender, collection_id, maybe_item, &namespace)?;
+
+                let deposit = T::DepositPerByte::get()
+                    .saturating_mul(((key.len() + value.len()) as u32).into())
+                    .saturating_add(T::AttributeDepositBase::get());
+                if let Some((_, depositor, old_deposit)) = Attributes::<T>::get((collection_id, maybe_item, &namespace, &key)) {
+                    T::Currency::unreserve(&depositor, old_deposit);
+                }
+                T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+                Attributes::<T>::insert((collection_id, maybe_item, &namespace, &key), (value.clone(), sender, deposit));
+
+                Self::deposit_event(Event::AttributeSet(collection_id, maybe_item, namespace, key, value));
+                Ok(())
+            }
+
+            /// Clear an attribute of a collection or an NFT and return its deposit.
+            ///
+            /// The origin must be signed by an account which can set the attribute. The account in
+            /// the `Account` namespace, and the depositor once the NFT is burned, can always clear it.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `maybe_item`: The item id of the NFT, `None` for an attribute of the collection.
+            /// - `namespace`: The attribute namespace.
+            /// - `key`: The attribute key.
+            ///
+            /// Emits `AttributeCleared` event when successful.
+            #[pallet::call_index(28)]
+            #[pallet::weight(T::WeightInfo::clear_attribute())]
+            pub fn clear_attribute(origin: OriginFor<T>,
+                                   collection_id: H256,
+                                   maybe_item: Option<u32>,
+                                   namespace: AttributeNamespace<T::AccountId>,
+                                   key: AttributeKeyOf<T>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                let (_, depositor, deposit) = Attributes::<T>::get((collection_id, maybe_item, &namespace, &key))
+                    .ok_or(Error::<T>::AttributeNotFound)?;
+                let burned = maybe_item.map_or(false, |item_id| !NFTDetails::<T>::contains_key((collection_id, item_id)));
+                let own_namespace = matches!(&namespace, AttributeNamespace::Account(account) if *account == sender);
+                if !(own_namespace || (burned && depositor == sender)) {
+                    Self::ensure_attribute_permission(&sender, collection_id, maybe_item, &namespace)?;
+                }
+
+                T::Currency::unreserve(&depositor, deposit);
+                Attributes::<T>::remove((collection_id, maybe_item, &namespace, &key));
+
+                Self::deposit_event(Event::AttributeCleared(collection_id, maybe_item, namespace, key));
+                Ok(())
+            }
+
+            /// Approve an account to set attributes of a collection or an NFT in its own namespace.
+            ///
+            /// The origin must be signed by the collection admin for the collection, or by the owner
+            /// of the whole share of the NFT.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `maybe_item`: The item id of the NFT, `None` for the collection.
+            /// - `delegate`: The approved account.
+            ///
+            /// Emits `AttributesApproved` event when successful.
+            #[pallet::call_index(29)]
+            #[pallet::weight(T::WeightInfo::approve_attributes())]
+            pub fn approve_attributes(origin: OriginFor<T>,
+                                      collection_id: H256,
+                                      maybe_item: Option<u32>,
+                                      delegate: T::AccountId) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_attributes_approver(&sender, collection_id, maybe_item)?;
+                AttributeApprovals::<T>::insert((collection_id, maybe_item), &delegate, ());
+
+                Self::deposit_event(Event::AttributesApproved(collection_id, maybe_item, delegate));
+                Ok(())
+            }
+
+            /// Cancel the approval of an account to set attributes of a collection or an NFT. The
+            /// attributes already set by the account are kept.
+            ///
+            /// The origin must be signed by the collection admin for the collection, or by the owner
+            /// of the whole share of the NFT.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `maybe_item`: The item id of the NFT, `None` for the collection.
+            /// - `delegate`: The approved account.
+            ///
+            /// Emits `AttributesApprovalCancelled` event when successful.
+            #[pallet::call_index(30)]
+            #[pallet::weight(T::WeightInfo::cancel_attributes_approval())]
+            pub fn cancel_attributes_approval(origin: OriginFor<T>,
+                                              collection_id: H256,
+                                              maybe_item: Option<u32>,
+                                              delegate: T::AccountId) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+
+                Self::ensure_attributes_approver(&sender, collection_id, maybe_item)?;
+                ensure!(AttributeApprovals::<T>::contains_key((collection_id, maybe_item), &delegate), Error::<T>::NotApproved);
+                AttributeApprovals::<T>::remove((collection_id, maybe_item), &delegate);
+
+                Self::deposit_event(Event::AttributesApprovalCancelled(collection_id, maybe_item, delegate));
+                Ok(())
+            }
+
+            /// Set the deposits of a collection or an NFT, the old deposits are returned to their
+            /// depositors and the new ones are reserved from `depositor`.
+            ///
+            /// The origin must be root.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `maybe_item`: The item id of the NFT, `None` for the collection.
+            /// - `depositor`: The account the deposits are reserved from.
+            /// - `deposit`: The collection or NFT deposit.
+            /// - `metadata_deposit`: The metadata deposit.
+            ///
+            /// Emits `DepositsForceSet` event when successful.
+            #[pallet::call_index(31)]
+            #[pallet::weight(T::WeightInfo::force_set_deposits())]
+            pub fn force_set_deposits(origin: OriginFor<T>,
+                                      collection_id: H256,
+                                      maybe_item: Option<u32>,
+                                      depositor: T::AccountId,
+                                      deposit: BalanceOf<T>,
+                                      metadata_deposit: BalanceOf<T>) -> DispatchResult {
+                ensure_root(origin)?;
+                match maybe_item {
+                    None => ensure!(NFTCollections::<T>::contains_key(&collection_id), Error::<T>::CollectionNotFound),
+                    Some(item_id) => ensure!(NFTDetails::<T>::contains_key((collection_id, item_id)), Error::<T>::NFTNotFound),
+                }
+
+                Self::release_deposits((collection_id, maybe_item));
+                Self::reserve_deposit(&depositor, (collection_id, maybe_item), deposit)?;
+                if !metadata_deposit.is_zero() {
+                    T::Currency::reserve(&depositor, metadata_deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+                    MetadataDeposits::<T>::insert((collection_id, maybe_item), (depositor.clone(), metadata_deposit));
+                }
+
+                Self::deposit_event(Event::DepositsForceSet(collection_id, maybe_item, depositor, deposit, metadata_deposit));
+                Ok(())
+            }
+
+            /// Set the owner and the team of a collection.
+            ///
+            /// The origin must be root. Also assigns the roles of the collections created before the
+            /// collection roles, which have no owner.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection id.
+            /// - `owner`: The owner of the collection.
+            /// - `issuer`: The account which can mint NFTs, the owner if `None`.
+            /// - `admin`: The account which can change the collection settings, the owner if `None`.
+            /// - `freezer`: The account which can freeze and thaw the collection, the owner if `None`.
+            ///
+            /// Emits `CollectionOwnerChanged` and `CollectionTeamChanged` events when successful.
+            #[pallet::call_index(32)]
+            #[pallet::weight(T::WeightInfo::force_set_collection_team())]
+            pub fn force_set_collection_team(origin: OriginFor<T>,
+                                             collection_id: H256,
+                                             owner: T::AccountId,
+                                             issuer: Option<T::AccountId>,
+                                             admin: Option<T::AccountId>,
+                                             freezer: Option<T::AccountId>) -> DispatchResult {
+                ensure_root(origin)?;
+                ensure!(NFTCollections::<T>::contains_key(&collection_id), Error::<T>::CollectionNotFound);
+
+                NFTCollectionRoles::<T>::insert(&collection_id, CollectionRoles {
+                    owner: owner.clone(),
+                    issuer: issuer.clone(),
+                    admin: admin.clone(),
+                    freezer: freezer.clone(),
+                });
+
+                Self::deposit_event(Event::CollectionOwnerChanged(collection_id, owner));
+                Self::deposit_event(Event::CollectionTeamChanged(collection_id, issuer, admin, freezer));
+                Ok(())
+            }
+        }
+
+        impl<T: Config> Pallet<T> {
+            /// The deterministic id of a collection created by `creator` with `salt`.
+            pub fn salted_collection_id(creator: &T::AccountId, salt: [u8; 32]) -> H256 {
+                H256::from_slice(&(b"nft/salted_collection", creator, salt).using_encoded(blake2_256))
+            }
+
+            /// Whether the metadata of an NFT is locked, by itself or by its collection.
+            pub fn is_nft_metadata_locked(nft_item: NftItem) -> bool {
+                LockedNFTMetadata::<T>::contains_key(nft_item) || LockedCollectionMetadata::<T>::contains_key(&nft_item.0)
+            }
+
+            /// Ensure `who` is the admin of the collection and its metadata is not locked.
+            fn ensure_collection_metadata_mutable(who: &T::AccountId, collection_id: &H256) -> DispatchResult {
+                let roles = NFTCollectionRoles::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_admin(who), Error::<T>::NoPermission);
+                ensure!(!LockedCollectionMetadata::<T>::contains_key(collection_id), Error::<T>::MetadataIsLocked);
+                Ok(())
+            }
+
+            /// Ensure `who` is the admin of the collection of the NFT and its metadata is not locked.
+            fn ensure_nft_metadata_mutable(who: &T::AccountId, nft_item: NftItem) -> DispatchResult {
+                let roles = NFTCollectionRoles::<T>::get(&nft_item.0).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(roles.is_admin(who), Error::<T>::NoPermission);
+                ensure!(!Self::is_nft_metadata_locked(nft_item), Error::<T>::MetadataIsLocked);
+                Ok(())
+            }
+
+            fn do_set_collection_metadata(who: &T::AccountId,
+                                          collection_id: &H256,
+                                          metadata: BoundedVec<u8, T::MaxMetadataLength>) -> DispatchResult {
+                NFTCollections::<T>::try_mutate(collection_id, |collection_wrap| -> DispatchResult {
+                    let collection = collection_wrap.as_mut().ok_or(Error::<T>::CollectionNotFound)?;
+                    Self::update_metadata_deposit(who, (*collection_id, None), metadata.len())?;
+                    collection.2 = metadata;
+                    Ok(())
+                })
+            }
+
+            fn do_set_nft_metadata(who: &T::AccountId, nft_item: NftItem, metadata: BoundedVec<u8, T::MaxMetadataLength>) -> DispatchResult {
+                NFTDetails::<T>::try_mutate(nft_item, |nft_info_wrap| -> DispatchResult {
+                    let nft_info = nft_info_wrap.as_mut().ok_or(Error::<T>::NFTNotFound)?;
+                    Self::update_metadata_deposit(who, (nft_item.0, Some(nft_item.1)), metadata.len())?;
+                    nft_info.metadata = metadata;
+                    Ok(())
+                })
+            }
+
+            /// The value of an attribute of a collection or an NFT.
+            pub fn attribute(collection_id: H256,
+                             maybe_item: Option<u32>,
+                             namespace: &AttributeNamespace<T::AccountId>,
+                             key: &[u8]) -> Option<Vec<u8>> {
+                let key = AttributeKeyOf::<T>::try_from(key.to_vec()).ok()?;
+                Attributes::<T>::get((collection_id, maybe_item, namespace, key)).map(|(value, _, _)| value.into_inner())
+            }
+
+            /// Ensure `who` can set the attributes of a collection or an NFT in `namespace`.
+            fn ensure_attribute_permission(who: &T::AccountId,
+                                           collection_id: H256,
+                                           maybe_item: Option<u32>,
+                                           namespace: &AttributeNamespace<T::AccountId>) -> DispatchResult {
+                let roles = NFTCollectionRoles::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                if let Some(item_id) = maybe_item {
+                    ensure!(NFTDetails::<T>::contains_key((collection_id, item_id)), Error::<T>::NFTNotFound);
+                }
+                match (namespace, maybe_item) {
+                    (AttributeNamespace::CollectionOwner, None) => {
+                        ensure!(roles.is_admin(who), Error::<T>::NoPermission);
+                        ensure!(!LockedCollectionMetadata::<T>::contains_key(&collection_id), Error::<T>::MetadataIsLocked);
+                    },
+                    (AttributeNamespace::CollectionOwner, Some(item_id)) => {
+                        ensure!(roles.is_admin(who), Error::<T>::NoPermission);
+                        ensure!(!Self::is_nft_metadata_locked((collection_id, item_id)), Error::<T>::MetadataIsLocked);
+                    },
+                    (AttributeNamespace::ItemOwner, None) => return Err(Error::<T>::WrongNamespace.into()),
+                    (AttributeNamespace::ItemOwner, Some(item_id)) => {
+                        ensure!(Self::owns_whole_share(who, (collection_id, item_id)), Error::<T>::NoPermission);
+                    },
+                    (AttributeNamespace::Account(account), _) => {
+                        ensure!(account == who, Error::<T>::NoPermission);
+                        ensure!(AttributeApprovals::<T>::contains_key((collection_id, maybe_item), who), Error::<T>::NotApproved);
+                    },
+                }
+                Ok(())
+            }
+
+            /// Ensure `who` can approve accounts to set attributes of a collection or an NFT.
+            fn ensure_attributes_approver(who: &T::AccountId, collection_id: H256, maybe_item: Option<u32>) -> DispatchResult {
+                let roles = NFTCollectionRoles::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                match maybe_item {
+                    None => ensure!(roles.is_admin(who), Error::<T>::NoPermission),
+                    Some(item_id) => {
+                        ensure!(NFTDetails::<T>::contains_key((collection_id, item_id)), Error::<T>::NFTNotFound);
+                        ensure!(Self::owns_whole_share(who, (collection_id, item_id)), Error::<T>::NoPermission);
+                    },
+                }
+                Ok(())
+            }
+
+            pub(crate) fn owns_whole_share(who: &T::AccountId, nft_item: NftItem) -> bool {
+                OwnedNFTs::<T>::get(who, nft_item) == Some(Self::share_supply(nft_item))
+            }
+
+            /// The account owning the whole share supply of an NFT, if any. The first owner found
+            /// holding a share of the NFT holds the whole supply, or no owner does.
+            pub fn whole_share_owner(nft_item: NftItem) -> Option<T::AccountId> {
+                let share_supply = Self::share_supply(nft_item);
+                NFTOwners::<T>::iter_key_prefix(nft_item)
+                    .map(|owner| {
+                        let share = OwnedNFTs::<T>::get(&owner, nft_item).unwrap_or_default();
+                        (owner, share)
+                    })
+                    .find(|(_, share)| *share > 0)
+                    .filter(|(_, share)| *share == share_supply)
+                    .map(|(owner, _)| owner)
+            }
+
+            /// Reserve the deposit of a collection or an NFT from `who`.
+            fn reserve_deposit(who: &T::AccountId, target: (H256, Option<u32>), deposit: BalanceOf<T>) -> DispatchResult {
+                if !deposit.is_zero() {
+                    T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+                    Deposits::<T>::insert(target, (who.clone(), deposit));
+                }
+                Ok(())
+            }
+
+            /// Reserve the deposit of `metadata_len` bytes of metadata of a collection or an NFT from
+            /// `who`, the old metadata deposit is returned to its depositor.
+            fn update_metadata_deposit(who: &T::AccountId, target: (H256, Option<u32>), metadata_len: usize) -> DispatchResult {
+                if let Some((depositor, deposit)) = MetadataDeposits::<T>::take(target) {
+                    T::Currency::unreserve(&depositor, deposit);
+                }
+                let deposit = T::MetadataDepositPerByte::get().saturating_mul((metadata_len as u32).into());
+                if !deposit.is_zero() {
+                    T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+                    MetadataDeposits::<T>::insert(target, (who.clone(), deposit));
+                }
+                Ok(())
+            }
+
+            /// Return the deposit and the metadata deposit of a collection or an NFT.
+            fn release_deposits(target: (H256, Option<u32>)) {
+                if let Some((depositor, deposit)) = Deposits::<T>::take(target) {
+                    T::Currency::unreserve(&depositor, deposit);
+                }
+                if let Some((depositor, deposit)) = MetadataDeposits::<T>::take(target) {
+                    T::Currency::unreserve(&depositor, deposit);
+                }
+            }
+
+            /// Derive the id of the next collection created by `creator` and bump the nonce.
+            pub(crate) fn next_collection_id(creator: &T::AccountId) -> H256 {
+                let nonce = CollectionNonce::<T>::get();
+                let block_number = frame_system::Pallet::<T>::block_number();
+                CollectionNonce::<T>::put(nonce.saturating_add(1));
+                H256::from_slice(&(b"nft/collection", creator, nonce, block_number).using_encoded(blake2_256))
+            }
+
+            /// Mint the next NFT of a collection to `owner`, the deposits are reserved from `depositor`.
+            ///
+            /// Used by the extrinsics and the `nonfungibles_v2` traits, the caller must check the
+            /// permission.
+            pub(crate) fn do_mint(depositor: &T::AccountId,
+                                  owner: T::AccountId,
+                                  collection_id: H256,
+                                  metadata: BoundedVec<u8, T::MaxMetadataLength>) -> Result<u32, DispatchError> {
+                ensure!(NFTCollections::<T>::contains_key(&collection_id), Error::<T>::CollectionNotFound);
+                ensure!(!FrozenCollections::<T>::contains_key(&collection_id), Error::<T>::CollectionIsFrozen);
+
+                let (max_items, cur_item_index, collection_metadata) = NFTCollections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(cur_item_index < max_items, Error::<T>::NFTExceeds);
+
+                let nft_item = (collection_id, cur_item_index);
+                Self::reserve_deposit(depositor, (collection_id, Some(cur_item_index)), T::ItemDeposit::get())?;
+                Self::update_metadata_deposit(depositor, (collection_id, Some(cur_item_index)), metadata.len())?;
+                let share_supply = T::DefaultShareSupply::get();
+                OwnedNFTs::<T>::insert(&owner, nft_item, share_supply);
+                ShareSupply::<T>::insert(nft_item, share_supply);
+
+                let nft_info = NftInfo {
+                    merged_nft: None,
+                    sub_nfts: BoundedVec::default(),
+                    metadata: metadata,
+                };
+                NFTOwners::<T>::insert(nft_item, &owner, ());
+                NFTDetails::<T>::insert(nft_item, nft_info);
+                NFTCollections::<T>::insert(&collection_id, (max_items, cur_item_index + 1, collection_metadata));
+
+                Self::deposit_event(Event::NFTMinted(owner, nft_item));
+                Ok(cur_item_index)
+            }
+
+            /// Burn the `share` of an NFT held by `owner`.
+            ///
+            /// Used by the extrinsics and the `nonfungibles_v2` traits, the caller must check the
+            /// permission.
+            pub(crate) fn do_burn(owner: T::AccountId, nft_item: NftItem, share: Share) -> DispatchResult {
+                let nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
+                let owner_share = OwnedNFTs::<T>::get(&owner, nft_item).ok_or(Error::<T>::NotOwner)?;
+                ensure!(owner_share >= share, Error::<T>::NFTNotEnoughShare);
+
+                if let Some(merged_nft) = nft_details.merged_nft {
+                    ensure!(merged_nft == nft_item, Error::<T>::NFTIsFrozen);
+                    ensure!(share == Self::share_supply(nft_item), Error::<T>::NFTPartialBurnNotAllowed);
+
+                    for sub_nft_item in nft_details.sub_nfts.iter() {
+                        if *sub_nft_item != nft_item {
+                            Self::remove_nft(sub_nft_item);
+                            T::OnNftTransfer::on_mutate(*sub_nft_item);
+                        }
+                    }
+                }
+
+                if owner_share == share {
+                    OwnedNFTs::<T>::remove(&owner, nft_item);
+                    NFTOwners::<T>::remove(nft_item, &owner);
+                    NFTApprovals::<T>::remove(nft_item, &owner);
+                } else {
+                    OwnedNFTs::<T>::insert(&owner, nft_item, owner_share - share);
+                }
+
+                // the burned share is removed from the supply, the NFT is destroyed with the last share
+                let share_supply = Self::share_supply(nft_item).saturating_sub(share);
+                if share_supply == 0 {
+                    ShareSupply::<T>::remove(nft_item);
+                    NFTRoyalties::<T>::remove(nft_item);
+                    LockedNFTMetadata::<T>::remove(nft_item);
+                    Self::release_deposits((nft_item.0, Some(nft_item.1)));
+                    NFTDetails::<T>::remove(nft_item);
+                    T::OnNftTransfer::on_mutate(nft_item);
+                } else {
+                    ShareSupply::<T>::insert(nft_item, share_supply);
+                    // the other owners keep their shares
+                    T::OnNftTransfer::on_burn(&owner, nft_item, share);
+                }
+
+                Self::deposit_event(Event::NFTBurned(owner, nft_item, share));
+                Ok(())
+            }
+
+            /// Record a new collection owned by `owner`, the deposits are reserved from `depositor`.
+            pub(crate) fn do_create_collection(depositor: &T::AccountId,
+                                               owner: T::AccountId,
+                                               collection_id: H256,
+                                               max_items: u32,
+                                               metadata: BoundedVec<u8, T::MaxMetadataLength>) -> DispatchResult {
+                ensure!(!NFTCollections::<T>::contains_key(&collection_id), Error::<T>::CollectionAlreadyExists);
+
+                NFTCollectionIds::<T>::try_mutate(|col| -> DispatchResult {
+                    col.get_or_insert_with(BoundedVec::default)
+                        .try_push(collection_id)
+                        .map_err(|_| Error::<T>::CollectionExceeds)?;
+                    Ok(())
+                })?;
+                Self::reserve_deposit(depositor, (collection_id, None), T::CollectionDeposit::get())?;
+                Self::update_metadata_deposit(depositor, (collection_id, None), metadata.len())?;
+                NFTCollections::<T>::insert(&collection_id, (max_items, 0, metadata));
+                NFTCollectionRoles::<T>::insert(&collection_id, CollectionRoles {
+                    owner: owner.clone(),
+                    issuer: None,
+                    admin: None,
+                    freezer: None,
+                });
+
+                Self::deposit_event(Event::NFTCollectionCreated(owner, collection_id, max_items));
+                Ok(())
+            }
+
+            /// Transfer an NFT share from one account to another.
+            ///
+            /// Used by the extrinsics and other pallets, the caller must check the permission.
+            pub fn do_transfer(from: T::AccountId, to: T::AccountId, nft_item: NftItem, share: Share) -> DispatchResult {
+                Self::ensure_can_transfer(&from, nft_item, share)?;
+                let nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
+
+                if nft_details.merged_nft.is_some() {
+                    // the sub NFTs are moved together with the merged NFT
+                    for sub_nft_item in nft_details.sub_nfts.iter().skip(1) {
+                        let sub_share = OwnedNFTs::<T>::get(&from, sub_nft_item).unwrap_or_default();
+                        Self::move_share(&from, &to, *sub_nft_item, sub_share);
+                        if sub_share > 0 {
+                            T::OnNftTransfer::on_transfer(&from, &to, *sub_nft_item, sub_share);
+                        }
+                    }
+                }
+                Self::move_share(&from, &to, nft_item, share);
+                T::OnNftTransfer::on_transfer(&from, &to, nft_item, share);
+
+                Self::deposit_event(Event::NFTTransferred(from, to, nft_item));
+                Ok(())
+            }
+
+            /// Ensure `from` can transfer an NFT share, without changing the storage.
+            pub fn ensure_can_transfer(from: &T::AccountId, nft_item: NftItem, share: Share) -> DispatchResult {
+                // Retrieve NFT details and ensure the NFT exists
+                let nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
+                ensure!(!FrozenCollections::<T>::contains_key(&nft_item.0), Error::<T>::CollectionIsFrozen);
+                ensure!(share > 0, Error::<T>::ZeroShare);
+                // Ensure the sender owns enough share
+                let from_share = OwnedNFTs::<T>::get(from, nft_item).ok_or(Error::<T>::NotOwner)?;
+                ensure!(from_share >= share, Error::<T>::NFTNotEnoughShare);
+                if let Some(merged_nft) = nft_details.merged_nft {
+                    ensure!(merged_nft == nft_item, Error::<T>::NFTIsFrozen);
+                }
+                Ok(())
+            }
+
+            /// The royalty of an NFT, falls back to the royalty of its collection.
+            pub fn royalty(nft_item: NftItem) -> Option<RoyaltyInfo<T::AccountId>> {
+                NFTRoyalties::<T>::get(nft_item).or_else(|| CollectionRoyalties::<T>::get(&nft_item.0))
+            }
+
+            /// The total share supply of an NFT.
+            pub fn share_supply(nft_item: NftItem) -> Share {
+                ShareSupply::<T>::get(nft_item).unwrap_or_default()
+            }
+
+            /// The NFTs held by `account`, at most `limit` of them in the storage order after
+            /// `start_after`.
+            pub fn holdings(account: &T::AccountId, start_after: Option<NftItem>, limit: u32) -> Vec<api::NftHolding> {
+                let limit = limit.min(api::MAX_PAGE_SIZE) as usize;
+                let holdings = match start_after {
+                    Some(nft_item) => OwnedNFTs::<T>::iter_prefix_from(account, OwnedNFTs::<T>::hashed_key_for(account, nft_item)),
+                    None => OwnedNFTs::<T>::iter_prefix(account),
+                };
+                holdings
+                    .take(limit)
+                    .map(|(nft_item, share)| api::NftHolding { collection_id: nft_item.0, item_id: nft_item.1, share })
+                    .collect()
+            }
+
+            /// The details of an NFT and the metadata of its sub NFTs if it is merged.
+            pub fn item_details(nft_item: NftItem) -> Option<api::NftItemDetails<T::AccountId>> {
+                let nft_info = NFTDetails::<T>::get(nft_item)?;
+                let sub_nfts = nft_info.sub_nfts.iter()
+                    .map(|sub_nft_item| api::SubNft {
+                        collection_id: sub_nft_item.0,
+                        item_id: sub_nft_item.1,
+                        metadata: NFTDetails::<T>::get(sub_nft_item).map(|info| info.metadata.into_inner()).unwrap_or_default(),
+                    })
+                    .collect();
+                Some(api::NftItemDetails {
+                    collection_id: nft_item.0,
+                    item_id: nft_item.1,
+                    metadata: nft_info.metadata.into_inner(),
+                    share_supply: Self::share_supply(nft_item),
+                    merged_nft: nft_info.merged_nft,
+                    sub_nfts,
+                    royalty: Self::royalty(nft_item).map(|royalty| (royalty.recipient, royalty.bps)),
+                    metadata_locked: Self::is_nft_metadata_locked(nft_item),
+                })
+            }
+
+            /// The details of a collection.
+            pub fn collection_details(collection_id: H256) -> Option<api::CollectionDetails<T::AccountId>> {
+                let (max_items, minted_items, metadata) = NFTCollections::<T>::get(&collection_id)?;
+                Some(api::CollectionDetails {
+                    collection_id,
+                    max_items,
+                    minted_items,
+                    metadata: metadata.into_inner(),
+                    owner: NFTCollectionRoles::<T>::get(&collection_id).map(|roles| roles.owner),
+                    frozen: FrozenCollections::<T>::contains_key(&collection_id),
+                    royalty: CollectionRoyalties::<T>::get(&collection_id).map(|royalty| (royalty.recipient, royalty.bps)),
+                    metadata_locked: LockedCollectionMetadata::<T>::contains_key(&collection_id),
+                })
+            }
+
+            /// The attributes of a collection or an NFT, only those in `namespace` if given.
+            pub fn attributes(collection_id: H256,
+                              maybe_item: Option<u32>,
+                              namespace: Option<AttributeNamespace<T::AccountId>>) -> Vec<api::NftAttribute<T::AccountId>> {
+                match namespace {
+                    Some(namespace) => Attributes::<T>::iter_prefix((collection_id, maybe_item, namespace.clone()))
+                        .map(|(key, (value, _, _))| api::NftAttribute {
+                            namespace: namespace.clone(),
+                            key: key.into_inner(),
+                            value: value.into_inner(),
+                        })
+                        .collect(),
+                    None => Attributes::<T>::iter_prefix((collection_id, maybe_item))
+                        .map(|((namespace, key), (value, _, _))| api::NftAttribute {
+                            namespace,
+                            key: key.into_inner(),
+                            value: value.into_inner(),
+                        })
+                        .collect(),
+                }
+            }
+
+            /// The owners of an NFT and their shares, at most `limit` of them in the storage order
+            /// after `start_after`.
+            pub fn owners(nft_item: NftItem, start_after: Option<T::AccountId>, limit: u32) -> Vec<api::NftOwner<T::AccountId>> {
+                let limit = limit.min(api::MAX_PAGE_SIZE) as usize;
+                let owners = match start_after {
+                    Some(owner) => NFTOwners::<T>::iter_key_prefix_from(nft_item, NFTOwners::<T>::hashed_key_for(nft_item, owner)),
+                    None => NFTOwners::<T>::iter_key_prefix(nft_item),
+                };
+                owners
+                    .take(limit)
+                    .map(|account| {
+                        let share = OwnedNFTs::<T>::get(&account, nft_item).unwrap_or_default();
+                        api::NftOwner { account, share }
+                    })
+                    .collect()
+            }
+
+            /// The weight of `calls` calls of the NFT transfer hooks.
+            fn hook_weight(calls: u32) -> Weight {
+                T::OnNftTransfer::max_weight().saturating_mul(calls.into())
+            }
+
+            /// Move an NFT share between two accounts and keep the owners of the NFT in sync.
+            fn move_share(from: &T::AccountId, to: &T::AccountId, nft_item: NftItem, share: Share) {
+                if share == 0 {
+                    return;
+                }
+                let from_share = OwnedNFTs::<T>::get(from, nft_item).unwrap_or_default();
+                if from_share <= share {
+                    OwnedNFTs::<T>::remove(from, nft_item);
+                    NFTOwners::<T>::remove(nft_item, from);
+                    NFTApprovals::<T>::remove(nft_item, from);
+                } else {
+                    OwnedNFTs::<T>::insert(from, nft_item, from_share - share);
+                }
+
+                OwnedNFTs::<T>::mutate(to, nft_item, |to_share| *to_share = Some(to_share.unwrap_or_default() + share));
+                NFTOwners::<T>::insert(nft_item, to, ());
+            }
+
+            /// Ensure `owner` owns the whole share supply of a not merged NFT.
+            fn ensure_whole_share(owner: &T::AccountId, nft_item: NftItem) -> DispatchResult {
+                let nft_details = NFTDetails::<T>::get(nft_item).ok_or(Error::<T>::NFTNotFound)?;
+                ensure!(nft_details.merged_nft.is_none(), Error::<T>::NFTAlreadyMerged);
+                ensure!(!FrozenCollections::<T>::contains_key(&nft_item.0), Error::<T>::CollectionIsFrozen);
+                let share = OwnedNFTs::<T>::get(owner, nft_item).ok_or(Error::<T>::NotOwner)?;
+                ensure!(share == Self::share_supply(nft_item), Error::<T>::NFTNotEnoughShare);
+                Ok(())
+            }
+
+            /// Ensure `delegate` is allowed to transfer the NFT share of `owner`.
+            fn ensure_approved(owner: &T::AccountId, delegate: &T::AccountId, nft_item: NftItem) -> DispatchResult {
+                let now = frame_system::Pallet::<T>::block_number();
+
+                if let Some(maybe_deadline) = OperatorApprovals::<T>::get(owner, delegate) {
+                    ensure!(maybe_deadline.map_or(true, |deadline| now <= deadline), Error::<T>::ApprovalExpired);
+                    return Ok(());
+                }
+
+                let (approved, maybe_deadline) = NFTApprovals::<T>::get(nft_item, owner).ok_or(Error::<T>::NotApproved)?;
+                ensure!(approved == *delegate, Error::<T>::NotApproved);
+                ensure!(maybe_deadline.map_or(true, |deadline| now <= deadline), Error::<T>::ApprovalExpired);
+                Ok(())
+            }
+
+            /// Ensure `OwnedNFTs` and `NFTOwners` are consistent with each other.
+            ///
+            /// Every owned share must be recorded in the owners of the NFT, and every owner of an NFT must
+            /// own a share of it. The shares of an NFT sum up to its share supply.
+            #[cfg(any(feature = "try-runtime", test))]
+            pub fn do_try_state() -> Result<(), TryRuntimeError> {
+                for (owner, nft_item, share) in OwnedNFTs::<T>::iter() {
+                    ensure!(share > 0, "An owned NFT has no share");
+                    ensure!(NFTOwners::<T>::contains_key(nft_item, &owner), "An owned NFT does not record its owner");
+                }
+
+                for (nft_item, owner, ()) in NFTOwners::<T>::iter() {
+                    ensure!(NFTDetails::<T>::contains_key(nft_item), "An owned NFT has no details");
+                    ensure!(OwnedNFTs::<T>::contains_key(&owner, nft_item), "An NFT owner does not own the NFT");
+                }
+
+                for nft_item in NFTDetails::<T>::iter_keys() {
+                    let total_share: Share = NFTOwners::<T>::iter_key_prefix(nft_item)
+                        .map(|owner| OwnedNFTs::<T>::get(&owner, nft_item).unwrap_or_default())
+                        .sum();
+                    ensure!(total_share == Self::share_supply(nft_item), "The shares of an NFT do not sum up to its supply");
+                }
+
+                Ok(())
+            }
+
+            /// Remove an NFT from the details, the owners and all the owned NFTs of its owners.
+            fn remove_nft(nft_item: &NftItem) {
+                for (owner, ()) in NFTOwners::<T>::drain_prefix(nft_item) {
+                    OwnedNFTs::<T>::remove(&owner, nft_item);
+                }
+                let _ = NFTApprovals::<T>::clear_prefix(nft_item, u32::MAX, None);
+                ShareSupply::<T>::remove(nft_item);
+                NFTRoyalties::<T>::remove(nft_item);
+                LockedNFTMetadata::<T>::remove(nft_item);
+                Self::release_deposits((nft_item.0, Some(nft_item.1)));
+                NFTDetails::<T>::remove(nft_item);
+            }
         }
 }
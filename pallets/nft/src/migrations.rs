@@ -0,0 +1,228 @@
+/// Migrations of the NFT module storage.
+pub mod v1 {
+    use crate::*;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        storage_alias,
+        traits::OnRuntimeUpgrade,
+    };
+    use sp_core::H256;
+    use sp_std::{marker::PhantomData, vec::Vec};
+    #[cfg(feature = "try-runtime")]
+    use frame_support::sp_runtime::TryRuntimeError;
+
+    /// The NFT shares owned by an account, as percentages.
+    #[storage_alias]
+    pub type OwnedNFTs<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        Blake2_128Concat,
+        (H256, u32),
+        u8,
+    >;
+
+    /// The storage layout before the ownership was moved into double maps.
+    pub mod v0 {
+        use super::*;
+
+        /// The NFTs owned by an account, as a vector of (collection, item_id, share).
+        #[storage_alias]
+        pub type OwnedNFTs<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Vec<(H256, u32, u8)>,
+        >;
+
+        /// The owners of an NFT, as a vector of accounts.
+        #[storage_alias]
+        pub type NFTOwners<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            (H256, u32),
+            Vec<<T as frame_system::Config>::AccountId>,
+        >;
+    }
+
+    /// Move `OwnedNFTs` and `NFTOwners` from vectors into double maps.
+    ///
+    /// The old and the new storage share the same prefix, so the old entries are drained before the
+    /// new ones are written.
+    pub struct InnerMigrateV0ToV1<T: Config>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+
+            let owned_nfts: Vec<_> = v0::OwnedNFTs::<T>::drain().collect();
+            for (owner, nfts) in owned_nfts {
+                reads += 1;
+                writes += 1;
+                for (collection_id, item_id, share) in nfts {
+                    OwnedNFTs::<T>::insert(&owner, (collection_id, item_id), share);
+                    writes += 1;
+                }
+            }
+
+            let nft_owners: Vec<_> = v0::NFTOwners::<T>::drain().collect();
+            for (nft_item, owners) in nft_owners {
+                reads += 1;
+                writes += 1;
+                for owner in owners {
+                    NFTOwners::<T>::insert(nft_item, &owner, ());
+                    writes += 1;
+                }
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let owned_count: u32 = v0::OwnedNFTs::<T>::iter_values().map(|nfts| nfts.len() as u32).sum();
+            let owners_count: u32 = v0::NFTOwners::<T>::iter_values().map(|owners| owners.len() as u32).sum();
+            Ok((owned_count, owners_count).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (owned_count, owners_count): (u32, u32) =
+                Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre-upgrade state")?;
+            ensure!(OwnedNFTs::<T>::iter().count() as u32 == owned_count, "OwnedNFTs count mismatch");
+            ensure!(NFTOwners::<T>::iter().count() as u32 == owners_count, "NFTOwners count mismatch");
+            Ok(())
+        }
+    }
+
+    /// Migrate the NFT ownership storage from version 0 to version 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to the fungible NFT shares.
+pub mod v2 {
+    use crate::*;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::OnRuntimeUpgrade,
+    };
+    use sp_std::marker::PhantomData;
+    #[cfg(feature = "try-runtime")]
+    use frame_support::sp_runtime::TryRuntimeError;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// The share supply of the NFTs minted before the supply was configurable.
+    pub const V1_SHARE_SUPPLY: Share = 100;
+
+    /// Turn the percentage shares of `OwnedNFTs` into fungible shares and record the share supply
+    /// of every existing NFT.
+    pub struct InnerMigrateV1ToV2<T: Config>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+
+            OwnedNFTs::<T>::translate::<u8, _>(|_, _, share| {
+                reads += 1;
+                writes += 1;
+                Some(share as Share)
+            });
+
+            for nft_item in NFTDetails::<T>::iter_keys() {
+                reads += 1;
+                writes += 1;
+                ShareSupply::<T>::insert(nft_item, V1_SHARE_SUPPLY);
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((super::v1::OwnedNFTs::<T>::iter().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let owned_count: u32 =
+                Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre-upgrade state")?;
+            ensure!(OwnedNFTs::<T>::iter().count() as u32 == owned_count, "OwnedNFTs count mismatch");
+            Pallet::<T>::do_try_state()
+        }
+    }
+
+    /// Migrate the NFT share storage from version 1 to version 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to the collection ids independent of the collection metadata.
+pub mod v3 {
+    use crate::*;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::OnRuntimeUpgrade,
+    };
+    use sp_std::marker::PhantomData;
+    #[cfg(feature = "try-runtime")]
+    use frame_support::sp_runtime::TryRuntimeError;
+    #[cfg(feature = "try-runtime")]
+    use sp_core::H256;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Start the collection nonce at the number of existing collections. The existing collections
+    /// keep the ids derived from their metadata, only new collections get the new ids.
+    pub struct InnerMigrateV2ToV3<T: Config>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let collections_count = NFTCollectionIds::<T>::get().map_or(0, |ids| ids.len() as u64);
+            CollectionNonce::<T>::put(collections_count);
+
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(NFTCollectionIds::<T>::get().unwrap_or_default().into_inner().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let collection_ids: Vec<H256> =
+                Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre-upgrade state")?;
+            ensure!(NFTCollectionIds::<T>::get().unwrap_or_default().into_inner() == collection_ids, "Collection ids changed");
+            for collection_id in collection_ids {
+                ensure!(NFTCollections::<T>::contains_key(collection_id), "A collection lost its id");
+            }
+            Ok(())
+        }
+    }
+
+    /// Migrate the collection id derivation from version 2 to version 3.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
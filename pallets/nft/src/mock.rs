@@ -1,6 +1,7 @@
 use crate as pallet_nft;
 use frame_support::{
     derive_impl,
+    traits::{ConstU128, ConstU32},
 };
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -8,12 +9,14 @@ use sp_runtime::{
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
+type Balance = u128;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
         NftModule: pallet_nft,
     }
 );
@@ -35,17 +38,60 @@ impl frame_system::Config for Test {
     type PalletInfo = PalletInfo;
     type SystemWeightInfo = ();
     type OnSetCode = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+frame_support::parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = frame_system::Pallet<Test>;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
 }
 
 impl pallet_nft::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type DefaultShareSupply = ConstU128<100>;
+    type OnNftTransfer = ();
+    type MaxSubNftsLength = ConstU32<4>;
+    type MaxMetadataLength = ConstU32<8>;
+    type MaxCollectionsLength = ConstU32<3>;
+    type Currency = Balances;
+    type CollectionDeposit = ConstU128<10>;
+    type ItemDeposit = ConstU128<5>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type AttributeDepositBase = ConstU128<1>;
+    type DepositPerByte = ConstU128<1>;
+    type MaxAttributeKeyLength = ConstU32<8>;
+    type MaxAttributeValueLength = ConstU32<8>;
     type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (0, 1000000),
+            (1, 1000000),
+            (2, 1000000),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
 }
@@ -0,0 +1,128 @@
+//! Traits of the fungible shares of NFTs, on top of the `nonfungibles_v2` traits.
+//!
+//! The `nonfungibles_v2` traits move an NFT as a whole. Pallets trading NFT shares, like the NFT
+//! market, use [`InspectShares`] and [`TransferShares`] instead, and can run on top of any
+//! `nonfungibles_v2` implementation with [`WholeItems`].
+
+use crate::{Config, Pallet, Share};
+use frame_support::{
+    ensure,
+    traits::tokens::nonfungibles_v2::{Inspect, Transfer},
+};
+use sp_core::H256;
+use sp_runtime::{DispatchResult, TokenError};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// An NFT of a `nonfungibles_v2` implementation, its collection id and item id.
+pub type NftItemOf<N, AccountId> = (<N as Inspect<AccountId>>::CollectionId, <N as Inspect<AccountId>>::ItemId);
+
+/// Inspect the shares of NFTs.
+pub trait InspectShares<AccountId>: Inspect<AccountId> {
+    /// The total share supply of an NFT, zero if the NFT does not exist.
+    fn share_supply(nft_item: NftItemOf<Self, AccountId>) -> Share;
+
+    /// The share of an NFT owned by `who`.
+    fn share_of(who: &AccountId, nft_item: NftItemOf<Self, AccountId>) -> Share;
+
+    /// The royalty recipient of an NFT and the royalty in basis points.
+    fn royalty(_nft_item: NftItemOf<Self, AccountId>) -> Option<(AccountId, u16)> {
+        None
+    }
+}
+
+/// Transfer the shares of NFTs.
+pub trait TransferShares<AccountId>: InspectShares<AccountId> + Transfer<AccountId> {
+    /// Ensure `from` can transfer the `share` of an NFT.
+    fn can_transfer_share(from: &AccountId, nft_item: NftItemOf<Self, AccountId>, share: Share) -> DispatchResult;
+
+    /// Transfer the `share` of an NFT from `from` to `to`.
+    fn transfer_share(from: &AccountId, to: &AccountId, nft_item: NftItemOf<Self, AccountId>, share: Share) -> DispatchResult;
+}
+
+impl<T: Config> InspectShares<T::AccountId> for Pallet<T> {
+    fn share_supply(nft_item: (H256, u32)) -> Share {
+        Self::share_supply(nft_item)
+    }
+
+    fn share_of(who: &T::AccountId, nft_item: (H256, u32)) -> Share {
+        crate::OwnedNFTs::<T>::get(who, nft_item).unwrap_or_default()
+    }
+
+    fn royalty(nft_item: (H256, u32)) -> Option<(T::AccountId, u16)> {
+        Self::royalty(nft_item).map(|royalty| (royalty.recipient, royalty.bps))
+    }
+}
+
+impl<T: Config> TransferShares<T::AccountId> for Pallet<T> {
+    fn can_transfer_share(from: &T::AccountId, nft_item: (H256, u32), share: Share) -> DispatchResult {
+        Self::ensure_can_transfer(from, nft_item, share)
+    }
+
+    fn transfer_share(from: &T::AccountId, to: &T::AccountId, nft_item: (H256, u32), share: Share) -> DispatchResult {
+        Self::do_transfer(from.clone(), to.clone(), nft_item, share)
+    }
+}
+
+/// The shares of a `nonfungibles_v2` implementation without shares, every NFT has one share.
+pub struct WholeItems<N>(PhantomData<N>);
+
+impl<AccountId, N: Inspect<AccountId>> Inspect<AccountId> for WholeItems<N> {
+    type ItemId = N::ItemId;
+    type CollectionId = N::CollectionId;
+
+    fn owner(collection: &N::CollectionId, item: &N::ItemId) -> Option<AccountId> {
+        N::owner(collection, item)
+    }
+
+    fn collection_owner(collection: &N::CollectionId) -> Option<AccountId> {
+        N::collection_owner(collection)
+    }
+
+    fn attribute(collection: &N::CollectionId, item: &N::ItemId, key: &[u8]) -> Option<Vec<u8>> {
+        N::attribute(collection, item, key)
+    }
+
+    fn custom_attribute(account: &AccountId, collection: &N::CollectionId, item: &N::ItemId, key: &[u8]) -> Option<Vec<u8>> {
+        N::custom_attribute(account, collection, item, key)
+    }
+
+    fn collection_attribute(collection: &N::CollectionId, key: &[u8]) -> Option<Vec<u8>> {
+        N::collection_attribute(collection, key)
+    }
+
+    fn can_transfer(collection: &N::CollectionId, item: &N::ItemId) -> bool {
+        N::can_transfer(collection, item)
+    }
+}
+
+impl<AccountId, N: Transfer<AccountId>> Transfer<AccountId> for WholeItems<N> {
+    fn transfer(collection: &N::CollectionId, item: &N::ItemId, destination: &AccountId) -> DispatchResult {
+        N::transfer(collection, item, destination)
+    }
+}
+
+impl<AccountId: PartialEq, N: Inspect<AccountId>> InspectShares<AccountId> for WholeItems<N> {
+    fn share_supply((collection, item): NftItemOf<Self, AccountId>) -> Share {
+        N::owner(&collection, &item).map_or(0, |_| 1)
+    }
+
+    fn share_of(who: &AccountId, (collection, item): NftItemOf<Self, AccountId>) -> Share {
+        match N::owner(&collection, &item) {
+            Some(owner) if owner == *who => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl<AccountId: PartialEq, N: Transfer<AccountId>> TransferShares<AccountId> for WholeItems<N> {
+    fn can_transfer_share(from: &AccountId, nft_item: NftItemOf<Self, AccountId>, share: Share) -> DispatchResult {
+        ensure!(share == 1 && Self::share_of(from, nft_item.clone()) == 1, TokenError::FundsUnavailable);
+        ensure!(N::can_transfer(&nft_item.0, &nft_item.1), TokenError::Frozen);
+        Ok(())
+    }
+
+    fn transfer_share(from: &AccountId, to: &AccountId, nft_item: NftItemOf<Self, AccountId>, share: Share) -> DispatchResult {
+        Self::can_transfer_share(from, nft_item.clone(), share)?;
+        N::transfer(&nft_item.0, &nft_item.1, to)
+    }
+}
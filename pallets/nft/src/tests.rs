@@ -1,11 +1,29 @@
 use super::*;
 use crate::{mock::*, Error};
 
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use frame_support::{assert_noop, assert_ok, traits::{Get, ReservableCurrency}, BoundedVec};
 use sp_core::H256;
-use sp_core::hashing::blake2_256;
 
 type AccountId = <Test as frame_system::Config>::AccountId;
+type NftItem = (H256, u32);
+
+fn owned_nfts(account_id: AccountId) -> Vec<(H256, u32, Share)> {
+    let mut nfts: Vec<_> = OwnedNFTs::<Test>::iter_prefix(account_id)
+        .map(|(nft_item, share)| (nft_item.0, nft_item.1, share))
+        .collect();
+    nfts.sort();
+    nfts
+}
+
+fn last_collection_id() -> H256 {
+    *NFTCollectionIds::<Test>::get().unwrap().last().unwrap()
+}
+
+fn nft_owners(nft_item: NftItem) -> Vec<AccountId> {
+    let mut owners: Vec<_> = NFTOwners::<Test>::iter_key_prefix(nft_item).collect();
+    owners.sort();
+    owners
+}
 
 #[test]
 fn create_collections() {
@@ -15,7 +33,7 @@ fn create_collections() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         assert_eq!(
             NFTCollections::<Test>::get(&collection_id),
             Some((max_items, 0_u32, metainfo))
@@ -27,16 +45,49 @@ fn create_collections() {
 }
 
 #[test]
-fn create_collections_fail_when_already_exist() {
+fn create_collections_with_the_same_metadata() {
     new_test_ext().execute_with(|| {
-        let account_id: AccountId = 1;
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
         let max_items: u32 = 100;
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
-        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id1), max_items, metainfo.clone()));
+
+        let collection_ids = NFTCollectionIds::<Test>::get().unwrap();
+        assert_eq!(CollectionNonce::<Test>::get(), 3);
+        assert_ne!(collection_ids[0], collection_ids[1]);
+        assert_ne!(collection_ids[1], collection_ids[2]);
+        assert_ne!(collection_ids[0], collection_ids[2]);
+        for collection_id in collection_ids {
+            assert_eq!(NFTCollections::<Test>::get(&collection_id), Some((max_items, 0_u32, metainfo.clone())));
+        }
+    })
+}
+
+#[test]
+fn create_collection_with_salt_fail_when_already_exist() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let salt = [7u8; 32];
+        assert_ok!(NftModule::create_collection_with_salt(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone(), salt));
+
+        let collection_id = NftModule::salted_collection_id(&account_id0, salt);
+        assert_eq!(last_collection_id(), collection_id);
+        assert_eq!(NFTCollectionRoles::<Test>::get(&collection_id).map(|roles| roles.owner), Some(account_id0));
         assert_noop!(
-            NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()),
+            NftModule::create_collection_with_salt(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone(), salt),
             Error::<Test>::CollectionAlreadyExists
         );
+
+        // the salt of another creator gives another id
+        assert_ok!(NftModule::create_collection_with_salt(RuntimeOrigin::signed(account_id1), max_items, metainfo, salt));
+        assert_ne!(last_collection_id(), collection_id);
+        assert_eq!(CollectionNonce::<Test>::get(), 0);
     })
 }
 
@@ -48,18 +99,18 @@ fn mint() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
-        let mut items_boundedvec = BoundedVec::try_from(vec![(collection_id, 0, 100)]).unwrap();
-        assert_eq!(OwnedNFTs::<Test>::get(account_id), Some(items_boundedvec));
-        items_boundedvec = BoundedVec::try_from(vec![(collection_id, 0, 100), (collection_id, 1, 100)]).unwrap();
+        let mut items_boundedvec = vec![(collection_id, 0, 100)];
+        assert_eq!(owned_nfts(account_id), items_boundedvec);
+        items_boundedvec = vec![(collection_id, 0, 100), (collection_id, 1, 100)];
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
-        assert_eq!(OwnedNFTs::<Test>::get(account_id), Some(items_boundedvec));
+        assert_eq!(owned_nfts(account_id), items_boundedvec);
 
-        let account_vec = BoundedVec::try_from(vec![account_id]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(account_vec.clone()));
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 1)), Some(account_vec.clone()));
+        let account_vec = vec![account_id];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec.clone());
+        assert_eq!(nft_owners((collection_id, 1)), account_vec.clone());
     })
 }
 
@@ -71,7 +122,7 @@ fn mint_fail_when_exceed() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
@@ -91,19 +142,18 @@ fn transfer() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
-        let account_vec0 = BoundedVec::try_from(vec![account_id0]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(account_vec0));
+        let account_vec0 = vec![account_id0];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec0);
 
         assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 100));
-        let account_vec1 = BoundedVec::try_from(vec![account_id1]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(account_vec1));
-        let account0_owned_nfts = BoundedVec::default();
-        let account1_owned_nfts = BoundedVec::try_from(vec![(collection_id, 0, 100)]).unwrap();
-        assert_eq!(OwnedNFTs::<Test>::get(account_id0), Some(account0_owned_nfts));
-        assert_eq!(OwnedNFTs::<Test>::get(account_id1), Some(account1_owned_nfts));
+        let account_vec1 = vec![account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec1);
+        let account1_owned_nfts = vec![(collection_id, 0, 100)];
+        assert!(owned_nfts(account_id0).is_empty());
+        assert_eq!(owned_nfts(account_id1), account1_owned_nfts);
     })
 }
 #[test]
@@ -115,38 +165,37 @@ fn transfer_with_share() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
-        let account_vec0 = BoundedVec::try_from(vec![account_id0]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(account_vec0));
+        let account_vec0 = vec![account_id0];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec0);
 
         let transfer_share = 30;
         assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), transfer_share));
-        let account_vec01 = BoundedVec::try_from(vec![account_id0, account_id1]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(account_vec01));
-        let account0_owned_nfts = BoundedVec::try_from(vec![(collection_id, 0, 100 - transfer_share)]).unwrap();
-        let account1_owned_nfts = BoundedVec::try_from(vec![(collection_id, 0, transfer_share)]).unwrap();
-        assert_eq!(OwnedNFTs::<Test>::get(account_id0), Some(account0_owned_nfts));
-        assert_eq!(OwnedNFTs::<Test>::get(account_id1), Some(account1_owned_nfts));
+        let account_vec01 = vec![account_id0, account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec01);
+        let account0_owned_nfts = vec![(collection_id, 0, 100 - transfer_share)];
+        let account1_owned_nfts = vec![(collection_id, 0, transfer_share)];
+        assert_eq!(owned_nfts(account_id0), account0_owned_nfts);
+        assert_eq!(owned_nfts(account_id1), account1_owned_nfts);
 
         let transfer_share2 = 35;
         assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), transfer_share2));
-        let account_vec01 = BoundedVec::try_from(vec![account_id0, account_id1]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(account_vec01));
-        let account0_owned_nfts = BoundedVec::try_from(vec![(collection_id, 0, 100 - transfer_share - transfer_share2)]).unwrap();
-        let account1_owned_nfts = BoundedVec::try_from(vec![(collection_id, 0, transfer_share + transfer_share2)]).unwrap();
-        assert_eq!(OwnedNFTs::<Test>::get(account_id0), Some(account0_owned_nfts));
-        assert_eq!(OwnedNFTs::<Test>::get(account_id1), Some(account1_owned_nfts));
+        let account_vec01 = vec![account_id0, account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec01);
+        let account0_owned_nfts = vec![(collection_id, 0, 100 - transfer_share - transfer_share2)];
+        let account1_owned_nfts = vec![(collection_id, 0, transfer_share + transfer_share2)];
+        assert_eq!(owned_nfts(account_id0), account0_owned_nfts);
+        assert_eq!(owned_nfts(account_id1), account1_owned_nfts);
 
         let transfer_share2 = 35;
         assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), transfer_share2));
-        let account_vec1 = BoundedVec::try_from(vec![account_id1]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(account_vec1));
-        let account0_owned_nfts = BoundedVec::default();
-        let account1_owned_nfts = BoundedVec::try_from(vec![(collection_id, 0, 100)]).unwrap();
-        assert_eq!(OwnedNFTs::<Test>::get(account_id0), Some(account0_owned_nfts));
-        assert_eq!(OwnedNFTs::<Test>::get(account_id1), Some(account1_owned_nfts));
+        let account_vec1 = vec![account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec1);
+        let account1_owned_nfts = vec![(collection_id, 0, 100)];
+        assert!(owned_nfts(account_id0).is_empty());
+        assert_eq!(owned_nfts(account_id1), account1_owned_nfts);
     })
 }
 
@@ -159,7 +208,7 @@ fn transfer_when_nft_not_owned() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
 
         assert_noop!(
             NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 100),
@@ -168,6 +217,31 @@ fn transfer_when_nft_not_owned() {
     })
 }
 
+#[test]
+fn transfer_fail_when_share_is_zero() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo));
+
+        assert_noop!(
+            NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 0),
+            Error::<Test>::ZeroShare
+        );
+        // a third party with no share can not create an empty ownership either
+        assert_noop!(
+            NftModule::transfer_nft(RuntimeOrigin::signed(account_id1), account_id0, (collection_id, 0), 0),
+            Error::<Test>::ZeroShare
+        );
+        assert_eq!(nft_owners((collection_id, 0)), vec![account_id0]);
+        assert_ok!(NftModule::do_try_state());
+    })
+}
+
 #[test]
 fn merge_nft() {
     new_test_ext().execute_with(|| {
@@ -176,7 +250,7 @@ fn merge_nft() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
@@ -209,7 +283,7 @@ fn merge_nft_fail_when_no_sub_nfts() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
 
@@ -229,7 +303,7 @@ fn merge_nft_fail_when_nft_already_merged() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
@@ -252,7 +326,7 @@ fn merge_nft_fail_when_nft_part_share() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
@@ -274,7 +348,7 @@ fn split_nft() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
@@ -319,7 +393,7 @@ fn split_nft_fail_when_not_merged() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
 
@@ -338,7 +412,7 @@ fn split_nft_fail_when_not_the_merged_nft() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
@@ -363,7 +437,7 @@ fn split_nft_fail_when_nft_part_share() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
 
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
@@ -380,3 +454,1180 @@ fn split_nft_fail_when_nft_part_share() {
     })
 }
 
+
+#[test]
+fn create_collection_records_owner() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let roles = CollectionRoles {
+            owner: account_id,
+            issuer: None,
+            admin: None,
+            freezer: None,
+        };
+        assert_eq!(NFTCollectionRoles::<Test>::get(&collection_id), Some(roles));
+    })
+}
+
+#[test]
+fn mint_fail_when_not_issuer() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_noop!(
+            NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()),
+            Error::<Test>::NoPermission
+        );
+    })
+}
+
+#[test]
+fn set_collection_team() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let account_id2: AccountId = 3;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        assert_noop!(
+            NftModule::set_collection_team(RuntimeOrigin::signed(account_id1), collection_id, Some(account_id1), None, None),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::set_collection_team(RuntimeOrigin::signed(account_id0), collection_id, Some(account_id1), Some(account_id2), None));
+
+        // only the issuer can mint now
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_noop!(
+            NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        let items_boundedvec = vec![(collection_id, 0, 100)];
+        assert_eq!(owned_nfts(account_id1), items_boundedvec);
+
+        // only the admin can change the settings now
+        assert_noop!(
+            NftModule::set_collection_max_items(RuntimeOrigin::signed(account_id0), collection_id, 10),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::set_collection_max_items(RuntimeOrigin::signed(account_id2), collection_id, 10));
+
+        // the freezer is not set, so the owner is still the freezer
+        assert_ok!(NftModule::freeze_collection(RuntimeOrigin::signed(account_id0), collection_id));
+    })
+}
+
+#[test]
+fn transfer_collection_ownership() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        assert_noop!(
+            NftModule::transfer_collection_ownership(RuntimeOrigin::signed(account_id1), collection_id, account_id1),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::transfer_collection_ownership(RuntimeOrigin::signed(account_id0), collection_id, account_id1));
+        assert_eq!(NFTCollectionRoles::<Test>::get(&collection_id).unwrap().owner, account_id1);
+
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_noop!(
+            NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+    })
+}
+
+#[test]
+fn set_collection_max_items_fail_when_too_small() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+
+        assert_noop!(
+            NftModule::set_collection_max_items(RuntimeOrigin::signed(account_id), collection_id, 1),
+            Error::<Test>::MaxItemsTooSmall
+        );
+        assert_ok!(NftModule::set_collection_max_items(RuntimeOrigin::signed(account_id), collection_id, 2));
+        assert_eq!(NFTCollections::<Test>::get(&collection_id), Some((2, 2, metainfo)));
+    })
+}
+
+#[test]
+fn freeze_and_thaw_collection() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+
+        assert_noop!(
+            NftModule::freeze_collection(RuntimeOrigin::signed(account_id1), collection_id),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::freeze_collection(RuntimeOrigin::signed(account_id0), collection_id));
+        assert_noop!(
+            NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()),
+            Error::<Test>::CollectionIsFrozen
+        );
+        assert_noop!(
+            NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 100),
+            Error::<Test>::CollectionIsFrozen
+        );
+
+        assert_ok!(NftModule::thaw_collection(RuntimeOrigin::signed(account_id0), collection_id));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 100));
+    })
+}
+
+#[test]
+fn burn_nft() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id), (collection_id, 0), 100));
+        System::assert_last_event(Event::NFTBurned(account_id, (collection_id, 0), 100).into());
+
+        assert_eq!(NFTDetails::<Test>::get((collection_id, 0)), None);
+        assert!(nft_owners((collection_id, 0)).is_empty());
+        let items_boundedvec = vec![(collection_id, 1, 100)];
+        assert_eq!(owned_nfts(account_id), items_boundedvec);
+    })
+}
+
+#[test]
+fn burn_nft_with_share() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 40));
+
+        assert_noop!(
+            NftModule::burn_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0), 50),
+            Error::<Test>::NFTNotEnoughShare
+        );
+
+        // burn a part of the share
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0), 20));
+        let account0_owned_nfts = vec![(collection_id, 0, 40)];
+        assert_eq!(owned_nfts(account_id0), account0_owned_nfts);
+        let account_vec01 = vec![account_id0, account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec01);
+
+        // burn the whole share of an owner, the NFT is kept for the other owner
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0), 40));
+        assert!(owned_nfts(account_id0).is_empty());
+        let account_vec1 = vec![account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), account_vec1);
+        assert!(NFTDetails::<Test>::get((collection_id, 0)).is_some());
+
+        // burn the last share, the NFT is destroyed
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0), 40));
+        assert!(nft_owners((collection_id, 0)).is_empty());
+        assert_eq!(NFTDetails::<Test>::get((collection_id, 0)), None);
+    })
+}
+
+#[test]
+fn burn_merged_nft() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+
+        let nft_items = BoundedVec::try_from(vec![(collection_id, 0), (collection_id, 1), (collection_id, 2)]).unwrap();
+        assert_ok!(NftModule::merge_nfts(RuntimeOrigin::signed(account_id), nft_items.clone()));
+
+        assert_noop!(
+            NftModule::burn_nft(RuntimeOrigin::signed(account_id), (collection_id, 1), 100),
+            Error::<Test>::NFTIsFrozen
+        );
+        assert_noop!(
+            NftModule::burn_nft(RuntimeOrigin::signed(account_id), (collection_id, 0), 50),
+            Error::<Test>::NFTPartialBurnNotAllowed
+        );
+
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id), (collection_id, 0), 100));
+        for item_id in 0..3 {
+            assert_eq!(NFTDetails::<Test>::get((collection_id, item_id)), None);
+            assert!(nft_owners((collection_id, item_id)).is_empty());
+        }
+        let items_boundedvec = vec![(collection_id, 3, 100)];
+        assert_eq!(owned_nfts(account_id), items_boundedvec);
+    })
+}
+
+#[test]
+fn approve_transfer_and_transfer_from() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let account_id2: AccountId = 3;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+
+        assert_noop!(
+            NftModule::approve_transfer(RuntimeOrigin::signed(account_id1), (collection_id, 0), account_id2, None),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            NftModule::transfer_from(RuntimeOrigin::signed(account_id1), account_id0, account_id2, (collection_id, 0), 100),
+            Error::<Test>::NotApproved
+        );
+
+        assert_ok!(NftModule::approve_transfer(RuntimeOrigin::signed(account_id0), (collection_id, 0), account_id1, None));
+        System::assert_last_event(Event::TransferApproved(account_id0, (collection_id, 0), account_id1, None).into());
+
+        assert_ok!(NftModule::transfer_from(RuntimeOrigin::signed(account_id1), account_id0, account_id2, (collection_id, 0), 40));
+        let account2_owned_nfts = vec![(collection_id, 0, 40)];
+        assert_eq!(owned_nfts(account_id2), account2_owned_nfts);
+        // the approval is kept while the owner still has a share
+        assert_eq!(NFTApprovals::<Test>::get((collection_id, 0), account_id0), Some((account_id1, None)));
+
+        // the approval is cleared when the whole share is transferred
+        assert_ok!(NftModule::transfer_from(RuntimeOrigin::signed(account_id1), account_id0, account_id2, (collection_id, 0), 60));
+        assert_eq!(NFTApprovals::<Test>::get((collection_id, 0), account_id0), None);
+    })
+}
+
+#[test]
+fn transfer_from_fail_when_approval_expired_or_cancelled() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+
+        assert_ok!(NftModule::approve_transfer(RuntimeOrigin::signed(account_id0), (collection_id, 0), account_id1, Some(5)));
+        System::set_block_number(6);
+        assert_noop!(
+            NftModule::transfer_from(RuntimeOrigin::signed(account_id1), account_id0, account_id1, (collection_id, 0), 100),
+            Error::<Test>::ApprovalExpired
+        );
+
+        assert_ok!(NftModule::cancel_approval(RuntimeOrigin::signed(account_id0), (collection_id, 0)));
+        System::assert_last_event(Event::ApprovalCancelled(account_id0, (collection_id, 0), account_id1).into());
+        assert_noop!(
+            NftModule::cancel_approval(RuntimeOrigin::signed(account_id0), (collection_id, 0)),
+            Error::<Test>::NotApproved
+        );
+        assert_noop!(
+            NftModule::transfer_from(RuntimeOrigin::signed(account_id1), account_id0, account_id1, (collection_id, 0), 100),
+            Error::<Test>::NotApproved
+        );
+    })
+}
+
+#[test]
+fn operator_transfer_from() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+
+        assert_ok!(NftModule::approve_operator(RuntimeOrigin::signed(account_id0), account_id1, None));
+        System::assert_last_event(Event::OperatorApproved(account_id0, account_id1, None).into());
+        assert_ok!(NftModule::transfer_from(RuntimeOrigin::signed(account_id1), account_id0, account_id1, (collection_id, 0), 100));
+
+        assert_ok!(NftModule::cancel_operator(RuntimeOrigin::signed(account_id0), account_id1));
+        System::assert_last_event(Event::OperatorCancelled(account_id0, account_id1).into());
+        assert_noop!(
+            NftModule::cancel_operator(RuntimeOrigin::signed(account_id0), account_id1),
+            Error::<Test>::NotApproved
+        );
+        assert_noop!(
+            NftModule::transfer_from(RuntimeOrigin::signed(account_id1), account_id0, account_id1, (collection_id, 1), 100),
+            Error::<Test>::NotApproved
+        );
+    })
+}
+
+#[test]
+fn create_collections_up_to_max_collections_length() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let max_collections = <Test as Config>::MaxCollectionsLength::get();
+        for index in 0..max_collections {
+            let metainfo = BoundedVec::try_from(vec![index as u8]).unwrap();
+            assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo));
+        }
+
+        assert_eq!(NFTCollectionIds::<Test>::get().unwrap().len() as u32, max_collections);
+    })
+}
+
+#[test]
+fn metadata_at_max_metadata_length() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let max_metadata_length = <Test as Config>::MaxMetadataLength::get() as usize;
+        let metainfo: BoundedVec<u8, <Test as Config>::MaxMetadataLength> = BoundedVec::try_from(vec![0; max_metadata_length]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1: BoundedVec<u8, <Test as Config>::MaxMetadataLength> = BoundedVec::try_from(vec![1; max_metadata_length]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        assert_eq!(NFTDetails::<Test>::get((collection_id, 0)).unwrap().metadata, metainfo1);
+
+        assert!(BoundedVec::<u8, <Test as Config>::MaxMetadataLength>::try_from(vec![0; max_metadata_length + 1]).is_err());
+    })
+}
+
+#[test]
+fn merge_nft_with_max_sub_nfts_length() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let max_sub_nfts = <Test as Config>::MaxSubNftsLength::get();
+        for _ in 0..max_sub_nfts {
+            assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        }
+
+        let items: Vec<NftItem> = (0..max_sub_nfts).map(|item_id| (collection_id, item_id)).collect();
+        let nft_items: BoundedVec<NftItem, <Test as Config>::MaxSubNftsLength> = BoundedVec::try_from(items.clone()).unwrap();
+        assert_ok!(NftModule::merge_nfts(RuntimeOrigin::signed(account_id), nft_items.clone()));
+        assert_eq!(NFTDetails::<Test>::get((collection_id, 0)).unwrap().sub_nfts, nft_items);
+
+        let mut items = items;
+        items.push((collection_id, max_sub_nfts));
+        assert!(BoundedVec::<NftItem, <Test as Config>::MaxSubNftsLength>::try_from(items).is_err());
+    })
+}
+
+#[test]
+fn create_collection_fail_when_exceed_max_collections_length() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let max_collections = <Test as Config>::MaxCollectionsLength::get();
+        for index in 0..max_collections {
+            let metainfo = BoundedVec::try_from(vec![index as u8]).unwrap();
+            assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo));
+        }
+
+        let metainfo = BoundedVec::try_from(vec![max_collections as u8]).unwrap();
+        assert_noop!(
+            NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()),
+            Error::<Test>::CollectionExceeds
+        );
+        assert_eq!(NFTCollections::<Test>::iter().count() as u32, max_collections);
+    })
+}
+
+#[test]
+fn mint_many_nfts_to_one_account() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        for _ in 0..max_items {
+            assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
+        }
+
+        assert_eq!(owned_nfts(account_id).len() as u32, max_items);
+        assert_eq!(OwnedNFTs::<Test>::get(account_id, (collection_id, max_items - 1)), Some(100));
+        assert_ok!(NftModule::do_try_state());
+    })
+}
+
+#[test]
+fn migrate_ownership_to_double_maps() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+        use crate::migrations::v1::{self, v0, MigrateV0ToV1};
+
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let collection_id = H256::zero();
+        StorageVersion::new(0).put::<NftModule>();
+        for item_id in 0..2 {
+            let nft_info = NftInfo {
+                merged_nft: None,
+                sub_nfts: BoundedVec::default(),
+                metadata: BoundedVec::default(),
+            };
+            NFTDetails::<Test>::insert((collection_id, item_id), nft_info);
+        }
+        v0::OwnedNFTs::<Test>::insert(account_id0, vec![(collection_id, 0, 100), (collection_id, 1, 40)]);
+        v0::OwnedNFTs::<Test>::insert(account_id1, vec![(collection_id, 1, 60)]);
+        v0::NFTOwners::<Test>::insert((collection_id, 0), vec![account_id0]);
+        v0::NFTOwners::<Test>::insert((collection_id, 1), vec![account_id0, account_id1]);
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(NftModule::on_chain_storage_version(), 1);
+        assert_eq!(v1::OwnedNFTs::<Test>::get(account_id0, (collection_id, 0)), Some(100));
+        assert_eq!(v1::OwnedNFTs::<Test>::get(account_id0, (collection_id, 1)), Some(40));
+        assert_eq!(v1::OwnedNFTs::<Test>::get(account_id1, (collection_id, 1)), Some(60));
+        assert_eq!(nft_owners((collection_id, 0)), vec![account_id0]);
+        assert_eq!(nft_owners((collection_id, 1)), vec![account_id0, account_id1]);
+
+        // the migration only runs once
+        v1::OwnedNFTs::<Test>::remove(account_id1, (collection_id, 1));
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert!(!v1::OwnedNFTs::<Test>::contains_key(account_id1, (collection_id, 1)));
+    })
+}
+
+#[test]
+fn migrate_shares_to_share_supply() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+        use crate::migrations::{v1, v2::MigrateV1ToV2};
+
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let collection_id = H256::zero();
+        StorageVersion::new(1).put::<NftModule>();
+        for item_id in 0..2 {
+            let nft_info = NftInfo {
+                merged_nft: None,
+                sub_nfts: BoundedVec::default(),
+                metadata: BoundedVec::default(),
+            };
+            NFTDetails::<Test>::insert((collection_id, item_id), nft_info);
+        }
+        v1::OwnedNFTs::<Test>::insert(account_id0, (collection_id, 0), 100);
+        v1::OwnedNFTs::<Test>::insert(account_id0, (collection_id, 1), 40);
+        v1::OwnedNFTs::<Test>::insert(account_id1, (collection_id, 1), 60);
+        NFTOwners::<Test>::insert((collection_id, 0), account_id0, ());
+        NFTOwners::<Test>::insert((collection_id, 1), account_id0, ());
+        NFTOwners::<Test>::insert((collection_id, 1), account_id1, ());
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(NftModule::on_chain_storage_version(), 2);
+        assert_eq!(owned_nfts(account_id0), vec![(collection_id, 0, 100), (collection_id, 1, 40)]);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 60)]);
+        assert_eq!(ShareSupply::<Test>::get((collection_id, 0)), Some(100));
+        assert_eq!(ShareSupply::<Test>::get((collection_id, 1)), Some(100));
+        assert_ok!(NftModule::do_try_state());
+    })
+}
+
+#[test]
+fn migrate_collection_nonce_and_keep_collection_ids() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+        use crate::migrations::v3::MigrateV2ToV3;
+
+        let account_id: AccountId = 1;
+        let max_items: u32 = 100;
+        StorageVersion::new(2).put::<NftModule>();
+        // the collections created before version 3 have ids derived from their metadata
+        let metainfo: BoundedVec<u8, <Test as Config>::MaxMetadataLength> = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let collection_id = H256::from_slice(&sp_core::hashing::blake2_256(&metainfo));
+        NFTCollectionIds::<Test>::put(BoundedVec::try_from(vec![collection_id]).unwrap());
+        NFTCollections::<Test>::insert(&collection_id, (max_items, 0, metainfo.clone()));
+
+        MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(NftModule::on_chain_storage_version(), 3);
+        assert_eq!(CollectionNonce::<Test>::get(), 1);
+        assert_eq!(NFTCollections::<Test>::get(&collection_id), Some((max_items, 0, metainfo.clone())));
+
+        // the same metadata no longer collides with the existing collection
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo));
+        assert_ne!(last_collection_id(), collection_id);
+        assert_eq!(NFTCollectionIds::<Test>::get().unwrap().len(), 2);
+    })
+}
+
+#[test]
+fn fractionalize_and_unify_nft() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+        let nft_item = (collection_id, 0);
+        let default_share_supply: Share = <Test as Config>::DefaultShareSupply::get();
+        assert_eq!(ShareSupply::<Test>::get(nft_item), Some(default_share_supply));
+
+        assert_noop!(
+            NftModule::fractionalize(RuntimeOrigin::signed(account_id0), nft_item, 0),
+            Error::<Test>::ZeroShareSupply
+        );
+        assert_noop!(
+            NftModule::fractionalize(RuntimeOrigin::signed(account_id1), nft_item, 1_000_000),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(NftModule::fractionalize(RuntimeOrigin::signed(account_id0), nft_item, 1_000_000));
+        System::assert_last_event(Event::NFTFractionalized(account_id0, nft_item, 1_000_000).into());
+        assert_eq!(owned_nfts(account_id0), vec![(collection_id, 0, 1_000_000)]);
+        assert_eq!(ShareSupply::<Test>::get(nft_item), Some(1_000_000));
+
+        // only the owner of the whole supply can unify the NFT
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, nft_item, 1));
+        assert_noop!(
+            NftModule::unify(RuntimeOrigin::signed(account_id0), nft_item),
+            Error::<Test>::NFTNotEnoughShare
+        );
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id1), account_id0, nft_item, 1));
+        assert_ok!(NftModule::unify(RuntimeOrigin::signed(account_id0), nft_item));
+        System::assert_last_event(Event::NFTUnified(account_id0, nft_item).into());
+        assert_eq!(owned_nfts(account_id0), vec![(collection_id, 0, default_share_supply)]);
+        assert_eq!(ShareSupply::<Test>::get(nft_item), Some(default_share_supply));
+        assert_ok!(NftModule::do_try_state());
+    })
+}
+
+#[test]
+fn transfer_shares_to_many_co_owners() {
+    new_test_ext().execute_with(|| {
+        let account_id: AccountId = 1;
+        let co_owners: u64 = 1_000;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo.clone()));
+        let nft_item = (collection_id, 0);
+        assert_ok!(NftModule::fractionalize(RuntimeOrigin::signed(account_id), nft_item, 2 * co_owners as Share));
+
+        for co_owner in 2..co_owners + 2 {
+            assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id), co_owner, nft_item, 1));
+        }
+
+        assert_eq!(nft_owners(nft_item).len() as u64, co_owners + 1);
+        assert_eq!(owned_nfts(account_id), vec![(collection_id, 0, co_owners as Share)]);
+        assert_eq!(owned_nfts(co_owners + 1), vec![(collection_id, 0, 1)]);
+        assert_ok!(NftModule::do_try_state());
+    })
+}
+
+#[test]
+fn burn_nft_share_decreases_share_supply() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+        let nft_item = (collection_id, 0);
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, nft_item, 40));
+
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id1), nft_item, 40));
+        assert_eq!(ShareSupply::<Test>::get(nft_item), Some(60));
+        assert_ok!(NftModule::do_try_state());
+
+        // the owner of the remaining supply owns the whole NFT again
+        assert_ok!(NftModule::unify(RuntimeOrigin::signed(account_id0), nft_item));
+        assert_eq!(owned_nfts(account_id0), vec![(collection_id, 0, 100)]);
+
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id0), nft_item, 100));
+        assert!(!ShareSupply::<Test>::contains_key(nft_item));
+        assert!(!NFTDetails::<Test>::contains_key(nft_item));
+    })
+}
+
+#[test]
+fn set_collection_and_nft_royalty() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+
+        let collection_royalty = RoyaltyInfo { recipient: account_id0, bps: 500 };
+        assert_noop!(
+            NftModule::set_collection_royalty(RuntimeOrigin::signed(account_id1), collection_id, Some(collection_royalty.clone())),
+            Error::<Test>::NoPermission
+        );
+        assert_noop!(
+            NftModule::set_collection_royalty(RuntimeOrigin::signed(account_id0), collection_id, Some(RoyaltyInfo { recipient: account_id0, bps: 10_001 })),
+            Error::<Test>::RoyaltyTooHigh
+        );
+        assert_ok!(NftModule::set_collection_royalty(RuntimeOrigin::signed(account_id0), collection_id, Some(collection_royalty.clone())));
+        assert_eq!(NftModule::royalty((collection_id, 0)), Some(collection_royalty.clone()));
+        assert_eq!(NftModule::royalty((collection_id, 1)), Some(collection_royalty.clone()));
+
+        // the NFT royalty overrides the collection royalty
+        let nft_royalty = RoyaltyInfo { recipient: account_id1, bps: 1_000 };
+        assert_noop!(
+            NftModule::set_nft_royalty(RuntimeOrigin::signed(account_id0), (collection_id, 2), Some(nft_royalty.clone())),
+            Error::<Test>::NFTNotFound
+        );
+        assert_ok!(NftModule::set_nft_royalty(RuntimeOrigin::signed(account_id0), (collection_id, 1), Some(nft_royalty.clone())));
+        assert_eq!(NftModule::royalty((collection_id, 0)), Some(collection_royalty));
+        assert_eq!(NftModule::royalty((collection_id, 1)), Some(nft_royalty));
+
+        assert_ok!(NftModule::set_collection_royalty(RuntimeOrigin::signed(account_id0), collection_id, None));
+        assert_ok!(NftModule::set_nft_royalty(RuntimeOrigin::signed(account_id0), (collection_id, 1), None));
+        assert_eq!(NftModule::royalty((collection_id, 0)), None);
+        assert_eq!(NftModule::royalty((collection_id, 1)), None);
+    })
+}
+
+#[test]
+fn set_clear_and_lock_metadata() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        for _ in 0..2 {
+            assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+        }
+        let nft_item = (collection_id, 0);
+        let new_metainfo: BoundedVec<u8, <Test as Config>::MaxMetadataLength> = BoundedVec::try_from(vec![2, 3]).unwrap();
+
+        // only the admin can change the metadata
+        assert_noop!(
+            NftModule::set_nft_metadata(RuntimeOrigin::signed(account_id1), nft_item, new_metainfo.clone()),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::set_collection_team(RuntimeOrigin::signed(account_id0), collection_id, None, Some(account_id1), None));
+        assert_ok!(NftModule::set_nft_metadata(RuntimeOrigin::signed(account_id1), nft_item, new_metainfo.clone()));
+        assert_eq!(NFTDetails::<Test>::get(nft_item).unwrap().metadata, new_metainfo);
+        System::assert_last_event(Event::NFTMetadataSet(nft_item, new_metainfo.clone()).into());
+        assert_ok!(NftModule::set_collection_metadata(RuntimeOrigin::signed(account_id1), collection_id, new_metainfo.clone()));
+        assert_eq!(NFTCollections::<Test>::get(&collection_id).unwrap().2, new_metainfo);
+        System::assert_last_event(Event::CollectionMetadataSet(collection_id, new_metainfo.clone()).into());
+        assert_noop!(
+            NftModule::set_nft_metadata(RuntimeOrigin::signed(account_id1), (collection_id, 2), new_metainfo.clone()),
+            Error::<Test>::NFTNotFound
+        );
+
+        assert_ok!(NftModule::clear_nft_metadata(RuntimeOrigin::signed(account_id1), nft_item));
+        assert!(NFTDetails::<Test>::get(nft_item).unwrap().metadata.is_empty());
+        System::assert_last_event(Event::NFTMetadataCleared(nft_item).into());
+
+        // a locked NFT keeps its metadata, the other NFTs of the collection can still change
+        assert_ok!(NftModule::lock_nft_metadata(RuntimeOrigin::signed(account_id1), nft_item));
+        System::assert_last_event(Event::NFTMetadataLocked(nft_item).into());
+        assert_noop!(
+            NftModule::set_nft_metadata(RuntimeOrigin::signed(account_id1), nft_item, new_metainfo.clone()),
+            Error::<Test>::MetadataIsLocked
+        );
+        assert_noop!(NftModule::lock_nft_metadata(RuntimeOrigin::signed(account_id1), nft_item), Error::<Test>::MetadataIsLocked);
+        assert_ok!(NftModule::set_nft_metadata(RuntimeOrigin::signed(account_id1), (collection_id, 1), new_metainfo.clone()));
+
+        // a locked collection locks the metadata of all its NFTs
+        assert_ok!(NftModule::lock_collection_metadata(RuntimeOrigin::signed(account_id1), collection_id));
+        System::assert_last_event(Event::CollectionMetadataLocked(collection_id).into());
+        assert_noop!(
+            NftModule::clear_collection_metadata(RuntimeOrigin::signed(account_id1), collection_id),
+            Error::<Test>::MetadataIsLocked
+        );
+        assert_noop!(
+            NftModule::clear_nft_metadata(RuntimeOrigin::signed(account_id1), (collection_id, 1)),
+            Error::<Test>::MetadataIsLocked
+        );
+        assert!(NftModule::item_details((collection_id, 1)).unwrap().metadata_locked);
+        assert!(NftModule::collection_details(collection_id).unwrap().metadata_locked);
+
+        // burning an NFT removes its lock
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id0), nft_item, 100));
+        assert!(!LockedNFTMetadata::<Test>::contains_key(nft_item));
+    })
+}
+
+#[test]
+fn reserve_and_return_deposits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        // the collection deposit is 10, the item deposit is 5 and the metadata deposit is 1 per byte
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        let collection_id = last_collection_id();
+        assert_eq!(Balances::reserved_balance(account_id0), 12);
+        assert_eq!(Deposits::<Test>::get((collection_id, None)), Some((account_id0, 10)));
+        assert_eq!(MetadataDeposits::<Test>::get((collection_id, None)), Some((account_id0, 2)));
+
+        assert_ok!(NftModule::set_collection_team(RuntimeOrigin::signed(account_id0), collection_id, Some(account_id1), None, None));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, BoundedVec::default()));
+        assert_eq!(Balances::reserved_balance(account_id1), 12);
+        assert_eq!(MetadataDeposits::<Test>::get((collection_id, Some(1))), None);
+
+        // the metadata deposit follows the account which set the metadata
+        let new_metainfo = BoundedVec::try_from(vec![2, 3, 4]).unwrap();
+        assert_ok!(NftModule::set_nft_metadata(RuntimeOrigin::signed(account_id0), (collection_id, 0), new_metainfo));
+        assert_eq!(Balances::reserved_balance(account_id0), 15);
+        assert_eq!(Balances::reserved_balance(account_id1), 10);
+        assert_ok!(NftModule::clear_collection_metadata(RuntimeOrigin::signed(account_id0), collection_id));
+        assert_eq!(Balances::reserved_balance(account_id0), 13);
+
+        // burning the NFT returns its deposits
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0), 100));
+        assert_eq!(Balances::reserved_balance(account_id0), 10);
+        assert_eq!(Balances::reserved_balance(account_id1), 5);
+        assert_eq!(Deposits::<Test>::get((collection_id, Some(0))), None);
+
+        // the minter pays the deposits
+        let account_id2: AccountId = 3;
+        assert_ok!(NftModule::set_collection_team(RuntimeOrigin::signed(account_id0), collection_id, Some(account_id2), None, None));
+        assert_noop!(
+            NftModule::mint_nft(RuntimeOrigin::signed(account_id2), collection_id, metainfo),
+            Error::<Test>::InsufficientBalance
+        );
+    })
+}
+
+#[test]
+fn force_set_deposits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo));
+        assert_eq!(Balances::reserved_balance(account_id0), 19);
+
+        assert_noop!(
+            NftModule::force_set_deposits(RuntimeOrigin::signed(account_id0), collection_id, None, account_id0, 0, 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            NftModule::force_set_deposits(RuntimeOrigin::root(), collection_id, Some(1), account_id0, 0, 0),
+            Error::<Test>::NFTNotFound
+        );
+
+        // the old deposits are returned and the new ones reserved from the given depositor
+        assert_ok!(NftModule::force_set_deposits(RuntimeOrigin::root(), collection_id, Some(0), account_id1, 20, 3));
+        System::assert_last_event(Event::DepositsForceSet(collection_id, Some(0), account_id1, 20, 3).into());
+        assert_eq!(Balances::reserved_balance(account_id0), 12);
+        assert_eq!(Balances::reserved_balance(account_id1), 23);
+        assert_eq!(Deposits::<Test>::get((collection_id, Some(0))), Some((account_id1, 20)));
+        assert_eq!(MetadataDeposits::<Test>::get((collection_id, Some(0))), Some((account_id1, 3)));
+
+        // zero deposits are removed
+        assert_ok!(NftModule::force_set_deposits(RuntimeOrigin::root(), collection_id, None, account_id0, 0, 0));
+        assert_eq!(Balances::reserved_balance(account_id0), 0);
+        assert_eq!(Deposits::<Test>::get((collection_id, None)), None);
+        assert_eq!(MetadataDeposits::<Test>::get((collection_id, None)), None);
+
+        assert_noop!(
+            NftModule::force_set_deposits(RuntimeOrigin::root(), collection_id, None, account_id0, 2_000_000, 0),
+            Error::<Test>::InsufficientBalance
+        );
+    })
+}
+
+#[test]
+fn set_and_clear_attributes_in_namespaces() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo));
+        let key: AttributeKeyOf<Test> = BoundedVec::try_from(b"level".to_vec()).unwrap();
+        let value: AttributeValueOf<Test> = BoundedVec::try_from(vec![7]).unwrap();
+        // the deposit is the base and a unit per byte of the key and value
+        let deposit = 1 + (key.len() + value.len()) as u128;
+        let reserved = Balances::reserved_balance(account_id0);
+
+        // the collection admin sets the collection owner namespace of the collection and its NFTs
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id0),
+                                            collection_id,
+                                            None,
+                                            AttributeNamespace::CollectionOwner,
+                                            key.clone(),
+                                            value.clone()));
+        System::assert_last_event(Event::AttributeSet(collection_id, None, AttributeNamespace::CollectionOwner, key.clone(), value.clone()).into());
+        assert_eq!(Balances::reserved_balance(account_id0), reserved + deposit);
+        assert_noop!(
+            NftModule::set_attribute(RuntimeOrigin::signed(account_id0), collection_id, None, AttributeNamespace::ItemOwner, key.clone(), value.clone()),
+            Error::<Test>::WrongNamespace
+        );
+        assert_noop!(
+            NftModule::set_attribute(RuntimeOrigin::signed(account_id1), collection_id, Some(0), AttributeNamespace::CollectionOwner, key.clone(), value.clone()),
+            Error::<Test>::NoPermission
+        );
+
+        // the item owner namespace needs the whole share of the NFT
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 40));
+        assert_noop!(
+            NftModule::set_attribute(RuntimeOrigin::signed(account_id1), collection_id, Some(0), AttributeNamespace::ItemOwner, key.clone(), value.clone()),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 60));
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id1),
+                                            collection_id,
+                                            Some(0),
+                                            AttributeNamespace::ItemOwner,
+                                            key.clone(),
+                                            value.clone()));
+        assert_eq!(Balances::reserved_balance(account_id1), deposit);
+
+        // an external account sets its own namespace once approved by the NFT owner
+        let namespace = AttributeNamespace::Account(account_id0);
+        assert_noop!(
+            NftModule::set_attribute(RuntimeOrigin::signed(account_id0), collection_id, Some(0), namespace.clone(), key.clone(), value.clone()),
+            Error::<Test>::NotApproved
+        );
+        assert_ok!(NftModule::approve_attributes(RuntimeOrigin::signed(account_id1), collection_id, Some(0), account_id0));
+        System::assert_last_event(Event::AttributesApproved(collection_id, Some(0), account_id0).into());
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id0),
+                                            collection_id,
+                                            Some(0),
+                                            namespace.clone(),
+                                            key.clone(),
+                                            value.clone()));
+        assert_eq!(NftModule::attribute(collection_id, Some(0), &namespace, b"level"), Some(vec![7]));
+        assert_eq!(NftModule::attributes(collection_id, Some(0), None).len(), 2);
+        assert_eq!(
+            NftModule::attributes(collection_id, Some(0), Some(AttributeNamespace::ItemOwner)),
+            vec![api::NftAttribute { namespace: AttributeNamespace::ItemOwner, key: b"level".to_vec(), value: vec![7] }]
+        );
+        assert_ok!(NftModule::cancel_attributes_approval(RuntimeOrigin::signed(account_id1), collection_id, Some(0), account_id0));
+        System::assert_last_event(Event::AttributesApprovalCancelled(collection_id, Some(0), account_id0).into());
+        assert_noop!(
+            NftModule::set_attribute(RuntimeOrigin::signed(account_id0), collection_id, Some(0), namespace.clone(), key.clone(), value.clone()),
+            Error::<Test>::NotApproved
+        );
+
+        // the account can still clear its own namespace and gets the deposit back
+        assert_ok!(NftModule::clear_attribute(RuntimeOrigin::signed(account_id0), collection_id, Some(0), namespace.clone(), key.clone()));
+        System::assert_last_event(Event::AttributeCleared(collection_id, Some(0), namespace, key.clone()).into());
+        assert_eq!(Balances::reserved_balance(account_id0), reserved + deposit);
+
+        // the depositor clears the attributes of a burned NFT
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0), 100));
+        assert_ok!(NftModule::clear_attribute(RuntimeOrigin::signed(account_id1), collection_id, Some(0), AttributeNamespace::ItemOwner, key.clone()));
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+        assert_noop!(
+            NftModule::clear_attribute(RuntimeOrigin::signed(account_id1), collection_id, Some(0), AttributeNamespace::ItemOwner, key),
+            Error::<Test>::AttributeNotFound
+        );
+    })
+}
+
+#[test]
+fn collection_owner_attributes_are_locked_with_the_metadata() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 100));
+        let key: AttributeKeyOf<Test> = BoundedVec::try_from(b"rarity".to_vec()).unwrap();
+        let value: AttributeValueOf<Test> = BoundedVec::try_from(vec![1]).unwrap();
+        let reserved = Balances::reserved_balance(account_id0);
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id0),
+                                            collection_id,
+                                            Some(0),
+                                            AttributeNamespace::CollectionOwner,
+                                            key.clone(),
+                                            value.clone()));
+
+        // overwriting an attribute moves the deposit to the new depositor
+        assert_ok!(NftModule::set_collection_team(RuntimeOrigin::signed(account_id0), collection_id, None, Some(account_id1), None));
+        let new_value: AttributeValueOf<Test> = BoundedVec::try_from(vec![2, 3]).unwrap();
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id1),
+                                            collection_id,
+                                            Some(0),
+                                            AttributeNamespace::CollectionOwner,
+                                            key.clone(),
+                                            new_value));
+        assert_eq!(Balances::reserved_balance(account_id0), reserved);
+        assert_eq!(Balances::reserved_balance(account_id1), 1 + (key.len() + 2) as u128);
+
+        assert_ok!(NftModule::lock_collection_metadata(RuntimeOrigin::signed(account_id1), collection_id));
+        assert_noop!(
+            NftModule::set_attribute(RuntimeOrigin::signed(account_id1), collection_id, Some(0), AttributeNamespace::CollectionOwner, key.clone(), value.clone()),
+            Error::<Test>::MetadataIsLocked
+        );
+        assert_noop!(
+            NftModule::clear_attribute(RuntimeOrigin::signed(account_id1), collection_id, Some(0), AttributeNamespace::CollectionOwner, key.clone()),
+            Error::<Test>::MetadataIsLocked
+        );
+        // the item owner namespace is not locked
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id1), collection_id, Some(0), AttributeNamespace::ItemOwner, key, value));
+    })
+}
+
+#[test]
+fn query_holdings_details_and_owners() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        for metadata in [vec![1], vec![2], vec![3]] {
+            assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, BoundedVec::try_from(metadata).unwrap()));
+        }
+        let nft_items = BoundedVec::try_from(vec![(collection_id, 1), (collection_id, 2)]).unwrap();
+        assert_ok!(NftModule::merge_nfts(RuntimeOrigin::signed(account_id0), nft_items));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 40));
+
+        // the holdings are paged after the last NFT of the previous page
+        let first_page = NftModule::holdings(&account_id0, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let last = first_page.last().map(|holding| (holding.collection_id, holding.item_id));
+        let second_page = NftModule::holdings(&account_id0, last, 2);
+        assert_eq!(second_page.len(), 1);
+        let mut holdings: Vec<_> = first_page.into_iter().chain(second_page)
+            .map(|holding| (holding.collection_id, holding.item_id, holding.share))
+            .collect();
+        holdings.sort();
+        assert_eq!(holdings, owned_nfts(account_id0));
+
+        let details = NftModule::item_details((collection_id, 1)).unwrap();
+        assert_eq!(details.metadata, vec![2]);
+        assert_eq!(details.share_supply, 100);
+        assert_eq!(details.merged_nft, Some((collection_id, 1)));
+        assert_eq!(
+            details.sub_nfts,
+            vec![
+                api::SubNft { collection_id, item_id: 1, metadata: vec![2] },
+                api::SubNft { collection_id, item_id: 2, metadata: vec![3] },
+            ]
+        );
+        assert_eq!(NftModule::item_details((collection_id, 3)), None);
+
+        assert_eq!(
+            NftModule::collection_details(collection_id),
+            Some(api::CollectionDetails {
+                collection_id,
+                max_items,
+                minted_items: 3,
+                metadata: vec![0, 1],
+                owner: Some(account_id0),
+                frozen: false,
+                royalty: None,
+                metadata_locked: false,
+            })
+        );
+        assert_eq!(NftModule::collection_details(H256::zero()), None);
+
+        let mut owners: Vec<_> = NftModule::owners((collection_id, 0), None, 10).into_iter()
+            .map(|owner| (owner.account, owner.share))
+            .collect();
+        owners.sort();
+        assert_eq!(owners, vec![(account_id0, 60), (account_id1, 40)]);
+        let first_owner = NftModule::owners((collection_id, 0), None, 1);
+        let rest = NftModule::owners((collection_id, 0), Some(first_owner[0].account), 10);
+        assert_eq!(rest.len(), 1);
+        assert_ne!(rest[0].account, first_owner[0].account);
+    })
+}
+
+#[test]
+fn nonfungibles_traits() {
+    use frame_support::traits::tokens::nonfungibles_v2::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
+    use crate::shares::{InspectShares, TransferShares};
+
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 0;
+        let account_id1: AccountId = 1;
+        let account_id2: AccountId = 2;
+        let config = CollectionConfig { max_items: 2 };
+        // the admin owns the collection, the creator pays the deposit
+        let collection_id = <NftModule as Create<_, _>>::create_collection(&account_id0, &account_id1, &config).unwrap();
+        assert_eq!(<NftModule as Inspect<_>>::collection_owner(&collection_id), Some(account_id1));
+        assert!(NFTCollectionRoles::<Test>::get(&collection_id).unwrap().is_admin(&account_id1));
+        assert_eq!(Deposits::<Test>::get((collection_id, None)), Some((account_id0, 10)));
+        assert_eq!(<NftModule as InspectEnumerable<_>>::collections().collect::<Vec<_>>(), vec![collection_id]);
+
+        // only the next item id can be minted, the deposits go to the collection owner on request
+        assert_noop!(
+            <NftModule as Mutate<_, _>>::mint_into(&collection_id, &1, &account_id1, &(), false),
+            Error::<Test>::WrongItemId
+        );
+        assert_ok!(<NftModule as Mutate<_, _>>::mint_into(&collection_id, &0, &account_id1, &(), true));
+        assert_eq!(Deposits::<Test>::get((collection_id, Some(0))), Some((account_id1, 5)));
+        assert_ok!(<NftModule as Mutate<_, _>>::mint_into(&collection_id, &1, &account_id1, &(), false));
+        assert_eq!(Deposits::<Test>::get((collection_id, Some(1))), Some((account_id1, 5)));
+        assert_noop!(
+            <NftModule as Mutate<_, _>>::mint_into(&collection_id, &2, &account_id1, &(), false),
+            Error::<Test>::NFTExceeds
+        );
+        assert_eq!(<NftModule as Inspect<_>>::owner(&collection_id, &0), Some(account_id1));
+        assert_eq!(<NftModule as InspectEnumerable<_>>::items(&collection_id).collect::<Vec<_>>(), vec![0, 1]);
+
+        // the whole owner is found among stale owner entries without a share
+        for stale_owner in 10..20 {
+            NFTOwners::<Test>::insert((collection_id, 0), stale_owner, ());
+        }
+        assert_eq!(<NftModule as Inspect<_>>::owner(&collection_id, &0), Some(account_id1));
+        for stale_owner in 10..20 {
+            NFTOwners::<Test>::remove((collection_id, 0), stale_owner);
+        }
+
+        // an NFT has a whole owner only while a single account holds its whole share supply
+        assert_ok!(<NftModule as TransferShares<_>>::transfer_share(&account_id1, &account_id2, (collection_id, 0), 40));
+        assert_eq!(<NftModule as InspectShares<_>>::share_of(&account_id2, (collection_id, 0)), 40);
+        assert_eq!(<NftModule as Inspect<_>>::owner(&collection_id, &0), None);
+        assert!(!<NftModule as Inspect<_>>::can_transfer(&collection_id, &0));
+        assert_noop!(
+            <NftModule as Transfer<_>>::transfer(&collection_id, &0, &account_id0),
+            Error::<Test>::NFTNotEnoughShare
+        );
+        assert_eq!(<NftModule as InspectEnumerable<_>>::owned(&account_id1).collect::<Vec<_>>(), vec![(collection_id, 1)]);
+
+        assert_ok!(<NftModule as Transfer<_>>::transfer(&collection_id, &1, &account_id2));
+        assert_eq!(owned_nfts(account_id2), vec![(collection_id, 0, 40), (collection_id, 1, 100)]);
+        assert_eq!(
+            <NftModule as InspectEnumerable<_>>::owned_in_collection(&collection_id, &account_id2).collect::<Vec<_>>(),
+            vec![1]
+        );
+
+        assert_noop!(
+            <NftModule as Mutate<_, _>>::burn(&collection_id, &1, Some(&account_id1)),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(<NftModule as Mutate<_, _>>::burn(&collection_id, &1, Some(&account_id2)));
+        assert_eq!(NFTDetails::<Test>::get((collection_id, 1)), None);
+        assert_eq!(Deposits::<Test>::get((collection_id, Some(1))), None);
+        assert_eq!(<NftModule as InspectEnumerable<_>>::items(&collection_id).collect::<Vec<_>>(), vec![0]);
+    })
+}
+
+#[test]
+fn force_set_collection_team_of_a_collection_without_roles() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        let collection_id = last_collection_id();
+        // a collection created before the collection roles has no owner
+        NFTCollectionRoles::<Test>::remove(&collection_id);
+        assert_noop!(
+            NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()),
+            Error::<Test>::CollectionNotFound
+        );
+
+        assert_noop!(
+            NftModule::force_set_collection_team(RuntimeOrigin::signed(account_id0), collection_id, account_id0, None, None, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            NftModule::force_set_collection_team(RuntimeOrigin::root(), H256::zero(), account_id0, None, None, None),
+            Error::<Test>::CollectionNotFound
+        );
+
+        assert_ok!(NftModule::force_set_collection_team(RuntimeOrigin::root(), collection_id, account_id0, Some(account_id1), None, None));
+        System::assert_last_event(Event::CollectionTeamChanged(collection_id, Some(account_id1), None, None).into());
+        let roles = NFTCollectionRoles::<Test>::get(&collection_id).unwrap();
+        assert!(roles.is_owner(&account_id0));
+        assert!(roles.is_issuer(&account_id1));
+
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo));
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100)]);
+    })
+}
@@ -1,13 +1,11 @@
 
-//! Autogenerated weights for `pallet_nft`
+//! Weights for `pallet_nft`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-10-18, STEPS: `20`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `MacBook-Air.local`, CPU: `<UNKNOWN>`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//! No benchmark run produced these weights, every number is a placeholder estimated from the
+//! storage accessed by the extrinsic. Regenerate this file with the benchmarks before relying on
+//! the weights.
 
-// Executed Command:
+// Regenerate with:
 // ./target/production/solochain-template-node
 // benchmark
 // pallet
@@ -43,155 +41,623 @@ pub trait WeightInfo {
 	fn transfer_nft() -> Weight;
 	fn merge_nfts() -> Weight;
 	fn split_nft() -> Weight;
+	fn set_collection_team() -> Weight;
+	fn transfer_collection_ownership() -> Weight;
+	fn set_collection_max_items() -> Weight;
+	fn freeze_collection() -> Weight;
+	fn thaw_collection() -> Weight;
+	fn burn_nft(s: u32) -> Weight;
+	fn approve_transfer() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn approve_operator() -> Weight;
+	fn cancel_operator() -> Weight;
+	fn transfer_from() -> Weight;
+	fn fractionalize() -> Weight;
+	fn unify() -> Weight;
+	fn set_collection_royalty() -> Weight;
+	fn set_nft_royalty() -> Weight;
+	fn create_collection_with_salt() -> Weight;
+	fn set_collection_metadata() -> Weight;
+	fn clear_collection_metadata() -> Weight;
+	fn lock_collection_metadata() -> Weight;
+	fn set_nft_metadata() -> Weight;
+	fn clear_nft_metadata() -> Weight;
+	fn lock_nft_metadata() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn approve_attributes() -> Weight;
+	fn cancel_attributes_approval() -> Weight;
+	fn force_set_deposits() -> Weight;
+	fn force_set_collection_team() -> Weight;
 }
 
 /// Weights for `pallet_nft` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
-	/// Proof: `NftModule::NFTCollections` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTCollectionIds` (r:1 w:1)
-	/// Proof: `NftModule::NFTCollectionIds` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:0 w:1)
+	/// Storage: `NftModule::CollectionNonce` (r:1 w:1)
+	/// Storage: `NftModule::Deposits` (r:0 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
 	fn create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `4`
-		//  Estimated: `4687`
-		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(9_000_000, 4687)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
-	/// Proof: `NftModule::NFTCollections` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
-	/// Proof: `NftModule::OwnedNFTs` (`max_values`: None, `max_size`: Some(370050), added: 372525, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:1 w:1)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTDetails` (r:0 w:1)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ShareSupply` (r:0 w:1)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::Deposits` (r:0 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
 	fn mint_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `121`
-		//  Estimated: `373515`
-		// Minimum execution time: 16_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 373515)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
-	/// Proof: `NftModule::OwnedNFTs` (`max_values`: None, `max_size`: Some(370050), added: 372525, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:1 w:1)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
 	fn transfer_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `338`
-		//  Estimated: `373515`
-		// Minimum execution time: 21_000_000 picoseconds.
 		Weight::from_parts(22_000_000, 373515)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `NftModule::NFTDetails` (r:2 w:2)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:2 w:0)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
 	fn merge_nfts() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `422`
-		//  Estimated: `7356`
-		// Minimum execution time: 25_000_000 picoseconds.
 		Weight::from_parts(26_000_000, 7356)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `NftModule::NFTDetails` (r:3 w:3)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:1 w:0)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
 	fn split_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `373`
-		//  Estimated: `10539`
-		// Minimum execution time: 19_000_000 picoseconds.
 		Weight::from_parts(20_000_000, 10539)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:1)
+	fn set_collection_team() -> Weight {
+		Weight::from_parts(9_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:1)
+	fn transfer_collection_ownership() -> Weight {
+		Weight::from_parts(8_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	fn set_collection_max_items() -> Weight {
+		Weight::from_parts(11_000_000, 3779)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:0 w:1)
+	fn freeze_collection() -> Weight {
+		Weight::from_parts(8_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:0 w:1)
+	fn thaw_collection() -> Weight {
+		Weight::from_parts(8_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:10)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
+	/// Storage: `NftModule::NFTOwners` (r:10 w:10)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:1)
+	/// The range of component `s` is `[2, 10]`.
+	/// Storage: `NftModule::LockedNFTMetadata` (r:0 w:1)
+	fn burn_nft(s: u32) -> Weight {
+		Weight::from_parts(24_000_000, 373515)
+			.saturating_add(Weight::from_parts(4_123_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(s.into())))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	fn approve_transfer() -> Weight {
+		Weight::from_parts(13_000_000, 373515)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTApprovals` (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		Weight::from_parts(9_000_000, 3602)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::OperatorApprovals` (r:0 w:1)
+	fn approve_operator() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::OperatorApprovals` (r:1 w:1)
+	fn cancel_operator() -> Weight {
+		Weight::from_parts(9_000_000, 3566)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::OperatorApprovals` (r:1 w:0)
+	/// Storage: `NftModule::NFTApprovals` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:1 w:1)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(27_000_000, 373515)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:1)
+	fn fractionalize() -> Weight {
+		Weight::from_parts(15_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:1)
+	fn unify() -> Weight {
+		Weight::from_parts(14_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:0 w:1)
+	fn set_collection_royalty() -> Weight {
+		Weight::from_parts(9_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::NFTRoyalties` (r:0 w:1)
+	fn set_nft_royalty() -> Weight {
+		Weight::from_parts(12_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	/// Storage: `NftModule::NFTCollectionIds` (r:1 w:1)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:0 w:1)
+	/// Storage: `NftModule::Deposits` (r:0 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn create_collection_with_salt() -> Weight {
+		Weight::from_parts(9_000_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn set_collection_metadata() -> Weight {
+		Weight::from_parts(12_000_000, 3779)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn clear_collection_metadata() -> Weight {
+		Weight::from_parts(11_000_000, 3779)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:1)
+	fn lock_collection_metadata() -> Weight {
+		Weight::from_parts(9_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn set_nft_metadata() -> Weight {
+		Weight::from_parts(14_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn clear_nft_metadata() -> Weight {
+		Weight::from_parts(13_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:1)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	fn lock_nft_metadata() -> Weight {
+		Weight::from_parts(12_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::Attributes` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn set_attribute() -> Weight {
+		Weight::from_parts(24_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::Attributes` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		Weight::from_parts(21_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftModule::AttributeApprovals` (r:0 w:1)
+	fn approve_attributes() -> Weight {
+		Weight::from_parts(14_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftModule::AttributeApprovals` (r:1 w:1)
+	fn cancel_attributes_approval() -> Weight {
+		Weight::from_parts(16_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::Deposits` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	fn force_set_deposits() -> Weight {
+		Weight::from_parts(31_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftModule::NFTCollections` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:0 w:1)
+	fn force_set_collection_team() -> Weight {
+		Weight::from_parts(12_000_000, 3779)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
-	/// Proof: `NftModule::NFTCollections` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTCollectionIds` (r:1 w:1)
-	/// Proof: `NftModule::NFTCollectionIds` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:0 w:1)
+	/// Storage: `NftModule::CollectionNonce` (r:1 w:1)
+	/// Storage: `NftModule::Deposits` (r:0 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
 	fn create_collection() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `4`
-		//  Estimated: `4687`
-		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(9_000_000, 4687)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
-	/// Proof: `NftModule::NFTCollections` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
-	/// Proof: `NftModule::OwnedNFTs` (`max_values`: None, `max_size`: Some(370050), added: 372525, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:1 w:1)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTDetails` (r:0 w:1)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ShareSupply` (r:0 w:1)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::Deposits` (r:0 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
 	fn mint_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `121`
-		//  Estimated: `373515`
-		// Minimum execution time: 16_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 373515)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
-	/// Proof: `NftModule::OwnedNFTs` (`max_values`: None, `max_size`: Some(370050), added: 372525, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:1 w:1)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
 	fn transfer_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `338`
-		//  Estimated: `373515`
-		// Minimum execution time: 21_000_000 picoseconds.
 		Weight::from_parts(22_000_000, 373515)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `NftModule::NFTDetails` (r:2 w:2)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:2 w:0)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
 	fn merge_nfts() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `422`
-		//  Estimated: `7356`
-		// Minimum execution time: 25_000_000 picoseconds.
 		Weight::from_parts(26_000_000, 7356)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `NftModule::NFTDetails` (r:3 w:3)
-	/// Proof: `NftModule::NFTDetails` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
 	/// Storage: `NftModule::NFTOwners` (r:1 w:0)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
 	fn split_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `373`
-		//  Estimated: `10539`
-		// Minimum execution time: 19_000_000 picoseconds.
 		Weight::from_parts(20_000_000, 10539)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:1)
+	fn set_collection_team() -> Weight {
+		Weight::from_parts(9_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:1)
+	fn transfer_collection_ownership() -> Weight {
+		Weight::from_parts(8_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	fn set_collection_max_items() -> Weight {
+		Weight::from_parts(11_000_000, 3779)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:0 w:1)
+	fn freeze_collection() -> Weight {
+		Weight::from_parts(8_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:0 w:1)
+	fn thaw_collection() -> Weight {
+		Weight::from_parts(8_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:10)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
+	/// Storage: `NftModule::NFTOwners` (r:10 w:10)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:1)
+	/// The range of component `s` is `[2, 10]`.
+	/// Storage: `NftModule::LockedNFTMetadata` (r:0 w:1)
+	fn burn_nft(s: u32) -> Weight {
+		Weight::from_parts(24_000_000, 373515)
+			.saturating_add(Weight::from_parts(4_123_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(s.into())))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	fn approve_transfer() -> Weight {
+		Weight::from_parts(13_000_000, 373515)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTApprovals` (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		Weight::from_parts(9_000_000, 3602)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::OperatorApprovals` (r:0 w:1)
+	fn approve_operator() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::OperatorApprovals` (r:1 w:1)
+	fn cancel_operator() -> Weight {
+		Weight::from_parts(9_000_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::OperatorApprovals` (r:1 w:0)
+	/// Storage: `NftModule::NFTApprovals` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:1 w:1)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(27_000_000, 373515)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:1)
+	fn fractionalize() -> Weight {
+		Weight::from_parts(15_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:1)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:1)
+	fn unify() -> Weight {
+		Weight::from_parts(14_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:0 w:1)
+	fn set_collection_royalty() -> Weight {
+		Weight::from_parts(9_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::NFTRoyalties` (r:0 w:1)
+	fn set_nft_royalty() -> Weight {
+		Weight::from_parts(12_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	/// Storage: `NftModule::NFTCollectionIds` (r:1 w:1)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:0 w:1)
+	/// Storage: `NftModule::Deposits` (r:0 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn create_collection_with_salt() -> Weight {
+		Weight::from_parts(9_000_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn set_collection_metadata() -> Weight {
+		Weight::from_parts(12_000_000, 3779)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollections` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn clear_collection_metadata() -> Weight {
+		Weight::from_parts(11_000_000, 3779)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:1)
+	fn lock_collection_metadata() -> Weight {
+		Weight::from_parts(9_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn set_nft_metadata() -> Weight {
+		Weight::from_parts(14_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn clear_nft_metadata() -> Weight {
+		Weight::from_parts(13_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:1)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	fn lock_nft_metadata() -> Weight {
+		Weight::from_parts(12_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::LockedNFTMetadata` (r:1 w:0)
+	/// Storage: `NftModule::LockedCollectionMetadata` (r:1 w:0)
+	/// Storage: `NftModule::Attributes` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn set_attribute() -> Weight {
+		Weight::from_parts(24_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::Attributes` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		Weight::from_parts(21_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftModule::AttributeApprovals` (r:0 w:1)
+	fn approve_attributes() -> Weight {
+		Weight::from_parts(14_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTCollectionRoles` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::ShareSupply` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftModule::AttributeApprovals` (r:1 w:1)
+	fn cancel_attributes_approval() -> Weight {
+		Weight::from_parts(16_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::Deposits` (r:1 w:1)
+	/// Storage: `NftModule::MetadataDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	fn force_set_deposits() -> Weight {
+		Weight::from_parts(31_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftModule::NFTCollections` (r:1 w:0)
+	/// Storage: `NftModule::NFTCollectionRoles` (r:0 w:1)
+	fn force_set_collection_team() -> Weight {
+		Weight::from_parts(12_000_000, 3779)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
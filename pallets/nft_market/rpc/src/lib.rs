@@ -0,0 +1,142 @@
+//! RPC interface for the NFT market pallet, the `market_*` methods.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{DeserializeOwned, RpcResult, Serialize},
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_nft_market_runtime_api::{
+    BuyQuote, MarketApi as MarketRuntimeApi, MarketListing, MarketOffer, Share,
+};
+
+#[rpc(client, server)]
+pub trait MarketApi<BlockHash, CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber> {
+    /// The listings of a collection priced in an asset, or in the native currency without an
+    /// asset, sorted by price.
+    #[method(name = "market_collectionListings")]
+    fn collection_listings(
+        &self,
+        collection_id: CollectionId,
+        asset: Option<AssetId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<MarketListing<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>>;
+
+    /// The offers received by a seller.
+    #[method(name = "market_sellerOffers")]
+    fn seller_offers(
+        &self,
+        seller: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<MarketOffer<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>>;
+
+    /// The offers made by a buyer.
+    #[method(name = "market_buyerOffers")]
+    fn buyer_offers(
+        &self,
+        buyer: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<MarketOffer<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>>;
+
+    /// The price of buying a listing now, with the market fee and the creator royalty.
+    #[method(name = "market_buyQuote")]
+    fn buy_quote(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        share: Share,
+        seller: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<BuyQuote<AccountId, Balance, AssetId>>>;
+}
+
+/// Provides the `market_*` RPC methods.
+pub struct Market<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Market<C, Block> {
+    /// Create a new instance with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), "Unable to query the market.", Some(format!("{:?}", e)))
+}
+
+impl<C, Block, CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>
+    MarketApiServer<<Block as BlockT>::Hash, CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber> for Market<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MarketRuntimeApi<Block, CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>,
+    CollectionId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    ItemId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn collection_listings(
+        &self,
+        collection_id: CollectionId,
+        asset: Option<AssetId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<MarketListing<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().collection_listings(at_hash, collection_id, asset).map_err(runtime_error)
+    }
+
+    fn seller_offers(
+        &self,
+        seller: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<MarketOffer<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().seller_offers(at_hash, seller).map_err(runtime_error)
+    }
+
+    fn buyer_offers(
+        &self,
+        buyer: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<MarketOffer<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().buyer_offers(at_hash, buyer).map_err(runtime_error)
+    }
+
+    fn buy_quote(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        share: Share,
+        seller: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<BuyQuote<AccountId, Balance, AssetId>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().buy_quote(at_hash, collection_id, item_id, share, seller).map_err(runtime_error)
+    }
+}
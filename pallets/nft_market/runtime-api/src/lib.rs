@@ -0,0 +1,30 @@
+//! Runtime API definition for the NFT market pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_nft_market::api::{BuyQuote, MarketListing, MarketOffer, Share};
+
+sp_api::decl_runtime_apis! {
+    /// Query the listings, offers and prices of the market.
+    pub trait MarketApi<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber> where
+        CollectionId: Codec,
+        ItemId: Codec,
+        AccountId: Codec,
+        Balance: Codec,
+        AssetId: Codec,
+        BlockNumber: Codec,
+    {
+        /// The listings of a collection priced in an asset, or in the native currency without an
+        /// asset, sorted by price.
+        fn collection_listings(collection_id: CollectionId, asset: Option<AssetId>) -> Vec<MarketListing<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>;
+        /// The offers received by a seller.
+        fn seller_offers(seller: AccountId) -> Vec<MarketOffer<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>;
+        /// The offers made by a buyer.
+        fn buyer_offers(buyer: AccountId) -> Vec<MarketOffer<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber>>;
+        /// The price of buying a listing now, with the market fee and the creator royalty.
+        fn buy_quote(collection_id: CollectionId, item_id: ItemId, share: Share, seller: AccountId) -> Option<BuyQuote<AccountId, Balance, AssetId>>;
+    }
+}
@@ -0,0 +1,52 @@
+//! Types returned by the market runtime API, readable by front ends without the storage layout.
+
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::RuntimeDebug;
+pub use crate::Share;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// A listing or a started Dutch auction of an NFT share.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MarketListing<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber> {
+    pub collection_id: CollectionId,
+    pub item_id: ItemId,
+    pub share: Share,
+    pub seller: AccountId,
+    pub price: Balance, // the current price of a Dutch auction
+    pub asset: Option<AssetId>, // the native currency if None
+    pub expiry: Option<BlockNumber>,
+    pub dutch_auction: bool,
+}
+
+/// An offer for a listed NFT share.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MarketOffer<CollectionId, ItemId, AccountId, Balance, AssetId, BlockNumber> {
+    pub collection_id: CollectionId,
+    pub item_id: ItemId,
+    pub share: Share,
+    pub seller: AccountId,
+    pub buyer: AccountId,
+    pub offered_nfts: Vec<(CollectionId, ItemId, Share)>,
+    pub token_amount: Balance,
+    pub asset: Option<AssetId>, // the native currency if None
+    pub expiry: Option<BlockNumber>,
+}
+
+/// What buying a listing costs and where the price goes.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BuyQuote<AccountId, Balance, AssetId> {
+    pub price: Balance, // paid by the buyer
+    pub asset: Option<AssetId>, // the native currency if None
+    pub market_fee: Balance,
+    pub royalty: Option<(AccountId, Balance)>, // (recipient, amount)
+    pub seller_amount: Balance,
+}
@@ -3,17 +3,79 @@
 use super::*;
 
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
-use frame_support::traits::Currency;
+use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
+use frame_support::traits::{Currency, Hooks, ReservableCurrency};
 use frame_support::{BoundedVec,  pallet_prelude::Get};
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 use sp_core::hashing::blake2_256;
-use sp_core::H256;
 use frame_support::pallet_prelude::*;
-use pallet_nft::{NFTOwners, OwnedNFTs};
+use pallet_nft::shares::InspectShares;
 
-type MaxNftOwners = ConstU32<10>;
-type MaxNftsLength = ConstU32<10000>;
+fn auctioned_nft<T: Config>(seller: T::AccountId) -> NftItemWithShare<T> {
+    let collection_id = T::BenchmarkHelper::create_collection(&seller);
+    let item_id = T::BenchmarkHelper::mint_nft(collection_id, &seller);
+    (collection_id, item_id, T::Nfts::share_supply((collection_id, item_id)))
+}
+
+fn swap_nft<T: Config>(owner: T::AccountId, collection_id: T::CollectionId) -> NftItemWithShare<T> {
+    let item_id = T::BenchmarkHelper::mint_nft(collection_id, &owner);
+    (collection_id, item_id, T::Nfts::share_supply((collection_id, item_id)))
+}
+
+/// `l` legs of a swap between two parties, the first leg gives `n` NFTs and tokens of `first` and
+/// the other legs give tokens of `second`. An NFT costs the same whatever leg moves it.
+fn swap_legs<T: Config>(first: T::AccountId, second: T::AccountId, l: u32, n: u32) -> BoundedVec<SwapLeg<T>, T::MaxSwapLegs> {
+    let collection_id = T::BenchmarkHelper::create_collection(&first);
+    let first_leg = SwapLeg {
+        from: first.clone(),
+        to: second.clone(),
+        nfts: (0..n).map(|_| swap_nft::<T>(first.clone(), collection_id)).collect::<Vec<_>>().try_into().unwrap(),
+        token_amount: BalanceOf::<T>::from(100u32),
+    };
+    let other_legs = (1..l).map(|_| SwapLeg {
+        from: second.clone(),
+        to: first.clone(),
+        nfts: BoundedVec::default(),
+        token_amount: BalanceOf::<T>::from(100u32),
+    });
+    sp_std::iter::once(first_leg).chain(other_legs).collect::<Vec<_>>().try_into().unwrap()
+}
+
+/// The most attributes of a collection-wide bid, with the longest keys and values.
+fn bid_attributes<T: Config>() -> BoundedVec<BidAttributeOf<T>, T::MaxBidAttributes> {
+    (0..T::MaxBidAttributes::get())
+        .map(|i| {
+            let mut key = vec![0u8; T::MaxBidAttributeLength::get() as usize];
+            key[0] = i as u8;
+            (BoundedVec::truncate_from(key), BoundedVec::truncate_from(vec![1u8; T::MaxBidAttributeLength::get() as usize]))
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let account: T::AccountId = account(name, index, 0);
+    T::Currency::make_free_balance_be(&account, BalanceOf::<T>::from(1_000_000u32));
+    account
+}
+
+fn offered_nfts<T: Config>(buyer: T::AccountId, n: u32) -> BoundedVec<NftItemWithShare<T>, T::MaxOfferNftsLength> {
+    let collection_id = T::BenchmarkHelper::create_collection(&buyer);
+    (0..n).map(|_| swap_nft::<T>(buyer.clone(), collection_id)).collect::<Vec<_>>().try_into().unwrap()
+}
+
+/// List an NFT of `seller` and place `o` offers on it, each with the most offered NFTs.
+fn listed_nft_with_offers<T: Config>(seller: T::AccountId, o: u32) -> Result<NftItemWithShare<T>, BenchmarkError> {
+    let nft_item_with_share = auctioned_nft::<T>(seller.clone());
+    Pallet::<T>::list_nft(RawOrigin::Signed(seller.clone()).into(), nft_item_with_share, BalanceOf::<T>::from(100u32), None, None)?;
+    for i in 0..o {
+        let buyer = funded_account::<T>("buyer", i);
+        let offered_nfts = offered_nfts::<T>(buyer.clone(), T::MaxOfferNftsLength::get());
+        Pallet::<T>::place_offer(RawOrigin::Signed(buyer).into(), nft_item_with_share, offered_nfts, BalanceOf::<T>::from(100u32), seller.clone(), None, None)?;
+    }
+    Ok(nft_item_with_share)
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -23,20 +85,326 @@ mod benchmarks {
     fn list_nft() {
         let caller: T::AccountId = whitelisted_caller();
 
-        let collection_id = H256::zero();
-        let item_id = 0u32;
+        let collection_id = T::BenchmarkHelper::create_collection(&caller);
+        let item_id = T::BenchmarkHelper::mint_nft(collection_id, &caller);
         let share = 40;
         let price = BalanceOf::<T>::from(100u32);
 
-        let mut nft_owners = BoundedVec::<T::AccountId, MaxNftOwners>::default();
-        nft_owners.try_push(caller.clone()).unwrap();
-        NFTOwners::<T>::insert((collection_id, item_id), nft_owners);
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), (collection_id, item_id, share), price, Some(10u32.into()), None);
+    }
 
-        let mut owned_nfts = BoundedVec::try_from(vec![(collection_id, item_id, 100)]).unwrap();
-        OwnedNFTs::<T>::insert(caller.clone(), owned_nfts);
+    #[benchmark]
+    fn unlist_nft(o: Linear<0, { T::MaxOffersLength::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = listed_nft_with_offers::<T>(caller.clone(), o)?;
 
         #[extrinsic_call]
-        _(RawOrigin::Signed(caller), (collection_id, item_id, share), price);
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share);
+
+        assert!(!Listings::<T>::contains_key(nft_item_with_share, &caller));
+        assert!(!Offers::<T>::contains_key(nft_item_with_share, &caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn buy_nft(o: Linear<0, { T::MaxOffersLength::get() }>) -> Result<(), BenchmarkError> {
+        let seller = funded_account::<T>("seller", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item_with_share = listed_nft_with_offers::<T>(seller.clone(), o)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share, seller.clone(), BalanceOf::<T>::from(100u32));
+
+        assert!(T::Nfts::share_of(&caller, (nft_item_with_share.0, nft_item_with_share.1)) > 0);
+        assert!(!Offers::<T>::contains_key(nft_item_with_share, &seller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn place_offer(n: Linear<0, { T::MaxOfferNftsLength::get() }>) -> Result<(), BenchmarkError> {
+        let seller = funded_account::<T>("seller", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item_with_share = listed_nft_with_offers::<T>(seller.clone(), 0)?;
+        let offered_nfts = offered_nfts::<T>(caller.clone(), n);
+        frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), nft_item_with_share, offered_nfts, BalanceOf::<T>::from(100u32), seller.clone(), Some(10u32.into()), None);
+
+        assert_eq!(Offers::<T>::get(nft_item_with_share, &seller).map(|offers| offers.len()), Some(1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_offer(n: Linear<0, { T::MaxOfferNftsLength::get() }>) -> Result<(), BenchmarkError> {
+        let seller = funded_account::<T>("seller", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item_with_share = listed_nft_with_offers::<T>(seller.clone(), 0)?;
+        let offered_nfts = offered_nfts::<T>(caller.clone(), n);
+        Pallet::<T>::place_offer(RawOrigin::Signed(caller.clone()).into(), nft_item_with_share, offered_nfts.clone(), BalanceOf::<T>::from(100u32), seller.clone(), None, None)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share, offered_nfts, BalanceOf::<T>::from(100u32), seller, None);
+
+        assert_eq!(T::Currency::reserved_balance(&caller), BalanceOf::<T>::from(0u32));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reject_offer(n: Linear<0, { T::MaxOfferNftsLength::get() }>) -> Result<(), BenchmarkError> {
+        let buyer = funded_account::<T>("buyer", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = listed_nft_with_offers::<T>(caller.clone(), 0)?;
+        let offered_nfts = offered_nfts::<T>(buyer.clone(), n);
+        Pallet::<T>::place_offer(RawOrigin::Signed(buyer.clone()).into(), nft_item_with_share, offered_nfts.clone(), BalanceOf::<T>::from(100u32), caller.clone(), None, None)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), nft_item_with_share, offered_nfts, BalanceOf::<T>::from(100u32), buyer.clone(), None);
+
+        assert_eq!(T::Currency::reserved_balance(&buyer), BalanceOf::<T>::from(0u32));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn accept_offer(n: Linear<0, { T::MaxOfferNftsLength::get() }>, o: Linear<0, { T::MaxOffersLength::get() - 1 }>) -> Result<(), BenchmarkError> {
+        let buyer = funded_account::<T>("accepted", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        // the other offers are released once the offer is accepted
+        let nft_item_with_share = listed_nft_with_offers::<T>(caller.clone(), o)?;
+        let offered_nfts = offered_nfts::<T>(buyer.clone(), n);
+        Pallet::<T>::place_offer(RawOrigin::Signed(buyer.clone()).into(), nft_item_with_share, offered_nfts.clone(), BalanceOf::<T>::from(100u32), caller.clone(), None, None)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share, offered_nfts, BalanceOf::<T>::from(100u32), buyer.clone(), None);
+
+        assert!(T::Nfts::share_of(&buyer, (nft_item_with_share.0, nft_item_with_share.1)) > 0);
+        assert!(!Offers::<T>::contains_key(nft_item_with_share, &caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn update_list_price() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = listed_nft_with_offers::<T>(caller.clone(), 0)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share, BalanceOf::<T>::from(200u32));
+
+        assert_eq!(Listings::<T>::get(nft_item_with_share, &caller).map(|list_info| list_info.price), Some(BalanceOf::<T>::from(200u32)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_auction() {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = auctioned_nft::<T>(caller.clone());
+        let duration: BlockNumberFor<T> = 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share, BalanceOf::<T>::from(100u32), BalanceOf::<T>::from(10u32), duration);
+
+        assert!(Auctions::<T>::contains_key(nft_item_with_share, &caller));
+    }
+
+    #[benchmark]
+    fn bid() -> Result<(), BenchmarkError> {
+        let seller = funded_account::<T>("seller", 0);
+        let outbid_bidder = funded_account::<T>("bidder", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let nft_item_with_share = auctioned_nft::<T>(seller.clone());
+        // the auction ends in the next block, so the bid extends it
+        Pallet::<T>::create_auction(RawOrigin::Signed(seller.clone()).into(), nft_item_with_share, BalanceOf::<T>::from(100u32), BalanceOf::<T>::from(10u32), 1u32.into())?;
+        Pallet::<T>::bid(RawOrigin::Signed(outbid_bidder).into(), nft_item_with_share, seller.clone(), BalanceOf::<T>::from(100u32))?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share, seller.clone(), BalanceOf::<T>::from(200u32));
+
+        assert_eq!(Auctions::<T>::get(nft_item_with_share, &seller).unwrap().highest_bid, Some((caller, BalanceOf::<T>::from(200u32))));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_auction() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = auctioned_nft::<T>(caller.clone());
+        Pallet::<T>::create_auction(RawOrigin::Signed(caller.clone()).into(), nft_item_with_share, BalanceOf::<T>::from(100u32), BalanceOf::<T>::from(10u32), 10u32.into())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share);
+
+        assert!(!Auctions::<T>::contains_key(nft_item_with_share, &caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn settle_auction() -> Result<(), BenchmarkError> {
+        let seller = funded_account::<T>("seller", 0);
+        let bidder = funded_account::<T>("bidder", 0);
+        let nft_item_with_share = auctioned_nft::<T>(seller.clone());
+        Pallet::<T>::create_auction(RawOrigin::Signed(seller.clone()).into(), nft_item_with_share, BalanceOf::<T>::from(100u32), BalanceOf::<T>::from(10u32), 1u32.into())?;
+        Pallet::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), nft_item_with_share, seller.clone(), BalanceOf::<T>::from(100u32))?;
+        let end_block = Auctions::<T>::get(nft_item_with_share, &seller).unwrap().end_block;
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(end_block);
+        }
+
+        assert!(T::Nfts::share_of(&bidder, (nft_item_with_share.0, nft_item_with_share.1)) > 0);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_dutch_auction() {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = auctioned_nft::<T>(caller.clone());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share, BalanceOf::<T>::from(1000u32), BalanceOf::<T>::from(100u32), 1u32.into(), 10u32.into());
+
+        assert!(DutchAuctions::<T>::contains_key(nft_item_with_share, &caller));
+    }
+
+    #[benchmark]
+    fn cancel_dutch_auction() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = auctioned_nft::<T>(caller.clone());
+        Pallet::<T>::create_dutch_auction(RawOrigin::Signed(caller.clone()).into(), nft_item_with_share, BalanceOf::<T>::from(1000u32), BalanceOf::<T>::from(100u32), 1u32.into(), 10u32.into())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), nft_item_with_share);
+
+        assert!(!DutchAuctions::<T>::contains_key(nft_item_with_share, &caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_swap(l: Linear<1, { T::MaxSwapLegs::get() }>, n: Linear<0, { T::MaxOfferNftsLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let first = funded_account::<T>("first", 0);
+        let second = funded_account::<T>("second", 0);
+        let legs = swap_legs::<T>(first, second, l, n);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), legs, 10u32.into());
+
+        assert!(Swaps::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn approve_swap(l: Linear<1, { T::MaxSwapLegs::get() }>, n: Linear<0, { T::MaxOfferNftsLength::get() }>) -> Result<(), BenchmarkError> {
+        let first = funded_account::<T>("first", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        // the proposer gives the tokens of the other legs and approves with the proposal, the
+        // approval of the caller settles the swap
+        let legs = swap_legs::<T>(caller.clone(), first.clone(), l, n);
+        let given_nfts = legs[0].nfts.clone();
+        Pallet::<T>::propose_swap(RawOrigin::Signed(first.clone()).into(), legs, 10u32.into())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0);
+
+        assert!(!Swaps::<T>::contains_key(0));
+        for (collection_id, item_id, share) in given_nfts {
+            assert_eq!(T::Nfts::share_of(&first, (collection_id, item_id)), share);
+        }
+        Ok(())
     }
-}
 
+    #[benchmark]
+    fn cancel_swap() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let first = funded_account::<T>("first", 0);
+        let second = funded_account::<T>("second", 0);
+        let legs = swap_legs::<T>(first, second, 2, 1);
+        Pallet::<T>::propose_swap(RawOrigin::Signed(caller.clone()).into(), legs, 10u32.into())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0);
+
+        assert!(!Swaps::<T>::contains_key(0));
+        assert_eq!(T::Currency::reserved_balance(&caller), BalanceOf::<T>::from(0u32));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn place_collection_bid() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let collection_id = T::BenchmarkHelper::create_collection(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection_id, 100, BalanceOf::<T>::from(100u32), bid_attributes::<T>(), Some(10u32.into()));
+
+        assert!(CollectionBids::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn cancel_collection_bid() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::from(1_000_000u32));
+        let collection_id = T::BenchmarkHelper::create_collection(&caller);
+        Pallet::<T>::place_collection_bid(RawOrigin::Signed(caller.clone()).into(), collection_id, 100, BalanceOf::<T>::from(100u32), BoundedVec::default(), None)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0);
+
+        assert!(!CollectionBids::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn fill_collection_bid(a: Linear<0, { T::MaxBidAttributes::get() }>) -> Result<(), BenchmarkError> {
+        let bidder = funded_account::<T>("bidder", 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let nft_item_with_share = auctioned_nft::<T>(caller.clone());
+        let attributes: BoundedVec<_, T::MaxBidAttributes> = bid_attributes::<T>().into_iter().take(a as usize).collect::<Vec<_>>().try_into().unwrap();
+        for (key, value) in attributes.iter() {
+            T::BenchmarkHelper::set_attribute(nft_item_with_share.0, nft_item_with_share.1, key, value);
+        }
+        Pallet::<T>::place_collection_bid(RawOrigin::Signed(bidder.clone()).into(), nft_item_with_share.0, nft_item_with_share.2, BalanceOf::<T>::from(100u32), attributes, None)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, nft_item_with_share.1);
+
+        assert!(T::Nfts::share_of(&bidder, (nft_item_with_share.0, nft_item_with_share.1)) > 0);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn sweep_expired(n: Linear<1, { T::MaxExpiriesPerBlock::get() }>) -> Result<(), BenchmarkError> {
+        let seller = funded_account::<T>("seller", 0);
+        let buyer = funded_account::<T>("buyer", 0);
+        let nft_item_with_share = auctioned_nft::<T>(seller.clone());
+        frame_system::Pallet::<T>::set_block_number(1u32.into());
+        Pallet::<T>::list_nft(RawOrigin::Signed(seller.clone()).into(), nft_item_with_share, BalanceOf::<T>::from(100u32), None, None)?;
+        for i in 0..n {
+            Pallet::<T>::place_offer(RawOrigin::Signed(buyer.clone()).into(), nft_item_with_share, BoundedVec::default(), BalanceOf::<T>::from(i + 1), seller.clone(), Some(1u32.into()), None)?;
+        }
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+        #[block]
+        {
+            Pallet::<T>::on_idle(2u32.into(), Weight::MAX);
+        }
+
+        assert_eq!(T::Currency::reserved_balance(&buyer), BalanceOf::<T>::from(0u32));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_market_fee() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 250);
+
+        assert_eq!(MarketFeeBps::<T>::get(), 250);
+    }
+}
@@ -6,6 +6,10 @@ pub use pallet::*;
 pub mod weights;
 pub use weights::*;
 
+pub mod migrations;
+
+pub mod api;
+
 #[cfg(test)]
 mod mock;
 
@@ -15,47 +19,265 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+use frame_support::sp_runtime::{
+    traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating},
+    Perbill,
+};
+
+/// The price curve of a Dutch auction.
+pub trait DutchAuctionPrice<Balance, BlockNumber> {
+    /// The price `elapsed` blocks into a Dutch auction of `duration` blocks, declining from
+    /// `start_price` to `end_price`.
+    fn price(start_price: Balance, end_price: Balance, elapsed: BlockNumber, duration: BlockNumber) -> Balance;
+}
+
+/// The price declines linearly from the start price to the end price.
+pub struct LinearDecline;
+
+impl<Balance: AtLeast32BitUnsigned + Copy, BlockNumber: AtLeast32BitUnsigned> DutchAuctionPrice<Balance, BlockNumber> for LinearDecline {
+    fn price(start_price: Balance, end_price: Balance, elapsed: BlockNumber, duration: BlockNumber) -> Balance {
+        if elapsed >= duration {
+            return end_price;
+        }
+        let declined = Perbill::from_rational(elapsed.saturated_into::<u64>(), duration.saturated_into::<u64>());
+        start_price.saturating_sub(declined * start_price.saturating_sub(end_price))
+    }
+}
+
+/// An amount of NFT shares, the share supply of an NFT is set by the NFTs traded in the market.
+pub type Share = u128;
+
+/// Creates the collections and NFTs traded in the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, CollectionId, ItemId> {
+    /// Create a collection owned by `owner`.
+    fn create_collection(owner: &AccountId) -> CollectionId;
+    /// Mint an NFT of a collection to `owner`, returns its item id.
+    fn mint_nft(collection_id: CollectionId, owner: &AccountId) -> ItemId;
+    /// Set an attribute of an NFT in the namespace of the collection owner.
+    fn set_attribute(collection_id: CollectionId, item_id: ItemId, key: &[u8], value: &[u8]);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_nft::Config> BenchmarkHelper<T::AccountId, sp_core::H256, u32> for pallet_nft::Pallet<T> {
+    fn create_collection(owner: &T::AccountId) -> sp_core::H256 {
+        use frame_support::traits::{nonfungibles_v2::Create, Currency};
+        <T as pallet_nft::Config>::Currency::deposit_creating(owner, pallet_nft::BalanceOf::<T>::from(1_000_000u32));
+        let config = pallet_nft::CollectionConfig { max_items: 100 };
+        <Self as Create<_, _>>::create_collection(owner, owner, &config).expect("the owner pays the deposit")
+    }
+
+    fn mint_nft(collection_id: sp_core::H256, owner: &T::AccountId) -> u32 {
+        use frame_support::traits::{nonfungibles_v2::Mutate, Currency};
+        <T as pallet_nft::Config>::Currency::deposit_creating(owner, pallet_nft::BalanceOf::<T>::from(1_000_000u32));
+        let item_id = pallet_nft::NFTCollections::<T>::get(collection_id).map_or(0, |(_, cur_item_index, _)| cur_item_index);
+        <Self as Mutate<_, _>>::mint_into(&collection_id, &item_id, owner, &(), false).expect("the collection has room");
+        item_id
+    }
+
+    fn set_attribute(collection_id: sp_core::H256, item_id: u32, key: &[u8], value: &[u8]) {
+        let owner = pallet_nft::NFTCollectionRoles::<T>::get(collection_id).expect("the collection exists").owner;
+        pallet_nft::Pallet::<T>::set_attribute(
+            frame_system::RawOrigin::Signed(owner).into(),
+            collection_id,
+            Some(item_id),
+            pallet_nft::AttributeNamespace::CollectionOwner,
+            frame_support::BoundedVec::truncate_from(key.to_vec()),
+            frame_support::BoundedVec::truncate_from(value.to_vec()),
+        ).expect("the owner pays the deposit");
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
         use super::*;
-        use frame_support::traits::OriginTrait;
         use frame_system::pallet_prelude::*;
         use frame_support::pallet_prelude::*;
-        use frame_support::traits::Currency;
-        use frame_support::sp_runtime::traits::Zero;
-        use sp_core::H256;
+        use frame_support::traits::{
+            fungible::{InspectHold, MutateHold},
+            fungibles::{self, Inspect as FungiblesInspect, Mutate as FungiblesMutate},
+            nonfungibles_v2::{self, Inspect as NonfungiblesInspect},
+            tokens::{Precision, Preservation},
+            Currency, ExistenceRequirement, ReservableCurrency,
+        };
+        use frame_support::sp_runtime::{traits::{AccountIdConversion, IdentifyAccount, Saturating, Verify, Zero}, Permill};
+        use frame_support::{storage::with_storage_layer, PalletId};
         use scale_info::TypeInfo;
-        use scale_info::prelude::fmt;
-        use pallet_nft::{Pallet as NftPallet, NFTOwners, OwnedNFTs};
-        type MaxNftsLength = ConstU32<10000>;
-        type MaxOfferNftsLength = ConstU32<10>;
-        type NftItemWithShare = (H256, u32, u8);
+        use sp_std::vec::Vec;
+        use pallet_nft::shares::{InspectShares, TransferShares};
+        /// An NFT with a share of it.
+        pub type NftItemWithShare<T> = (<T as Config>::CollectionId, <T as Config>::ItemId, Share);
+        /// An NFT, its collection id and item id.
+        pub type NftItemOf<T> = (<T as Config>::CollectionId, <T as Config>::ItemId);
         pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+        pub type MarketListingOf<T> = api::MarketListing<<T as Config>::CollectionId, <T as Config>::ItemId, <T as frame_system::Config>::AccountId, BalanceOf<T>, <T as Config>::AssetId, BlockNumberFor<T>>;
+        pub type MarketOfferOf<T> = api::MarketOffer<<T as Config>::CollectionId, <T as Config>::ItemId, <T as frame_system::Config>::AccountId, BalanceOf<T>, <T as Config>::AssetId, BlockNumberFor<T>>;
+        pub type BidAttributeOf<T> = (BoundedVec<u8, <T as Config>::MaxBidAttributeLength>, BoundedVec<u8, <T as Config>::MaxBidAttributeLength>);
+        pub type BuyQuoteOf<T> = api::BuyQuote<<T as frame_system::Config>::AccountId, BalanceOf<T>, <T as Config>::AssetId>;
 
 
         /// The module configuration trait.
         #[pallet::config]
-        pub trait Config: frame_system::Config + pallet_nft::Config + TypeInfo + fmt::Debug {
+        pub trait Config: frame_system::Config {
             type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-            type Currency: frame_support::traits::Currency<Self::AccountId>;
+            /// The overarching hold reason.
+            type RuntimeHoldReason: From<HoldReason>;
+            type Currency: ReservableCurrency<Self::AccountId>;
+            /// Holds the native token amounts of the offers under `HoldReason::Offer`, apart from the
+            /// other reserves of the buyers.
+            type OfferHolds: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason, Balance = BalanceOf<Self>>;
+            /// The collection id of the NFTs traded in the market.
+            type CollectionId: Member + Parameter + Copy + MaxEncodedLen;
+            /// The item id of the NFTs traded in the market.
+            type ItemId: Member + Parameter + Copy + MaxEncodedLen;
+            /// The NFTs traded in the market, and their shares. NFTs without shares are traded as a
+            /// single share with `pallet_nft::shares::WholeItems`.
+            type Nfts: nonfungibles_v2::Inspect<Self::AccountId, CollectionId = Self::CollectionId, ItemId = Self::ItemId>
+                + nonfungibles_v2::Transfer<Self::AccountId>
+                + TransferShares<Self::AccountId>;
+            /// The id of the assets the NFTs can be priced in.
+            type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+            /// The assets the NFTs can be priced in besides the native currency, the token amounts of
+            /// the offers in an asset are escrowed in the market account.
+            type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+                + fungibles::Mutate<Self::AccountId>;
+            /// The market pallet id, used to derive the account holding the escrowed NFTs.
+            #[pallet::constant]
+            type PalletId: Get<PalletId>;
+            /// The account receiving the market fee.
+            type FeeDestination: Get<Self::AccountId>;
+            /// The maximum market fee in basis points.
+            #[pallet::constant]
+            type MaxMarketFeeBps: Get<u16>;
+            /// The maximum number of NFTs in an offer.
+            #[pallet::constant]
+            type MaxOfferNftsLength: Get<u32>;
+            /// The maximum number of offers on a listed NFT, unlisting or selling the NFT releases all
+            /// of them.
+            #[pallet::constant]
+            type MaxOffersLength: Get<u32>;
+            /// A bid placed within this many blocks of the end of an auction extends the auction
+            /// to this many blocks after the bid.
+            #[pallet::constant]
+            type AuctionExtensionPeriod: Get<BlockNumberFor<Self>>;
+            /// The maximum number of auctions ending in the same block.
+            #[pallet::constant]
+            type MaxAuctionsPerBlock: Get<u32>;
+            /// The maximum number of listings and offers swept in the same block.
+            #[pallet::constant]
+            type MaxExpiriesPerBlock: Get<u32>;
+            /// The maximum number of blocks after its expiry block a listing or an offer is swept
+            /// in, and after its end block an auction is settled in, when the blocks before are full.
+            #[pallet::constant]
+            type MaxExpirySpillover: Get<u32>;
+            /// The maximum number of listings and Dutch auctions of an NFT by all its sellers, bounds
+            /// the listings dropped when the NFT moves or changes.
+            #[pallet::constant]
+            type MaxListingsPerNft: Get<u32>;
+            /// The price curve of Dutch auctions.
+            type DutchAuctionPrice: DutchAuctionPrice<BalanceOf<Self>, BlockNumberFor<Self>>;
+            /// The maximum number of legs in a swap.
+            #[pallet::constant]
+            type MaxSwapLegs: Get<u32>;
+            /// The maximum number of attributes a collection-wide bid filters the NFTs by.
+            #[pallet::constant]
+            type MaxBidAttributes: Get<u32>;
+            /// The maximum length of the key and the value of an attribute of a collection-wide bid.
+            #[pallet::constant]
+            type MaxBidAttributeLength: Get<u32>;
+            /// The deposit reserved from the creator of a swap until it is settled or cancelled.
+            #[pallet::constant]
+            type SwapDeposit: Get<BalanceOf<Self>>;
+            /// The maximum number of open swaps proposed by the same account.
+            #[pallet::constant]
+            type MaxSwapsPerAccount: Get<u32>;
+            /// Off-chain signature type approving a swap.
+            type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+            /// The public key of the off-chain signer of a swap approval.
+            type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
 		    /// Weights required by the dispatchables
 		    type WeightInfo: WeightInfo;
+            /// Creates the collections and NFTs of the benchmarks.
+            #[cfg(feature = "runtime-benchmarks")]
+            type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::CollectionId, Self::ItemId>;
         }
 
+        /// The in-code storage version.
+        const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
         #[pallet::pallet]
+        #[pallet::storage_version(STORAGE_VERSION)]
         pub struct Pallet<T>(_);
 
-        #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
         pub struct Offer<T: Config> {
-            pub offered_nfts: BoundedVec<NftItemWithShare, MaxOfferNftsLength>,
+            pub offered_nfts: BoundedVec<NftItemWithShare<T>, T::MaxOfferNftsLength>,
             pub token_amount: BalanceOf<T>,
             pub buyer: T::AccountId,
+            pub expiry: Option<BlockNumberFor<T>>, // the offer can not be accepted after this block
+            pub asset: Option<T::AssetId>, // the asset of the token amount, the native currency if None
         }
 
-        #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
         pub struct ListInfo<T: Config> {
             pub owner: T::AccountId,
             pub price: BalanceOf<T>,
+            pub expiry: Option<BlockNumberFor<T>>, // the NFT can not be bought after this block
+            pub asset: Option<T::AssetId>, // the asset of the price, the native currency if None
+        }
+
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
+        pub enum ExpiringEntry<T: Config> {
+            Listing(NftItemWithShare<T>, T::AccountId), // nft, seller
+            Offer(NftItemWithShare<T>, T::AccountId, Offer<T>), // nft, seller, offer
+            CollectionBid(u32), // bid id
+            Offers(NftItemWithShare<T>, T::AccountId), // nft, seller of a dropped listing
+        }
+
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
+        pub struct AuctionInfo<T: Config> {
+            pub reserve_price: BalanceOf<T>, // the lowest accepted bid
+            pub min_bid_increment: BalanceOf<T>, // a new bid must exceed the highest bid by this amount
+            pub end_block: BlockNumberFor<T>, // the auction is settled at this block
+            pub highest_bid: Option<(T::AccountId, BalanceOf<T>)>, // bidder and reserved amount
+        }
+
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
+        pub struct DutchAuctionInfo<T: Config> {
+            pub start_price: BalanceOf<T>, // the price at the start block
+            pub end_price: BalanceOf<T>, // the price after the duration
+            pub start_block: BlockNumberFor<T>, // the NFT can be bought from this block
+            pub duration: BlockNumberFor<T>, // the number of blocks the price declines
+        }
+
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
+        pub struct SwapLeg<T: Config> {
+            pub from: T::AccountId, // the party giving the NFTs and tokens
+            pub to: T::AccountId, // the party receiving the NFTs and tokens
+            pub nfts: BoundedVec<NftItemWithShare<T>, T::MaxOfferNftsLength>,
+            pub token_amount: BalanceOf<T>,
+        }
+
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
+        pub struct SwapInfo<T: Config> {
+            pub creator: T::AccountId,
+            pub legs: BoundedVec<SwapLeg<T>, T::MaxSwapLegs>,
+            pub approvals: BoundedVec<T::AccountId, T::MaxSwapLegs>, // the parties that approved the swap
+            pub deadline: BlockNumberFor<T>, // the swap can not be approved after this block
         }
 
         /// The listed NFTs, account and the list infos
@@ -63,7 +285,7 @@ pub mod pallet {
         pub type Listings<T: Config> = StorageDoubleMap<
             _,
             Twox64Concat,
-            NftItemWithShare,
+            NftItemWithShare<T>,
             Twox64Concat,
             T::AccountId,
             ListInfo<T>,
@@ -74,31 +296,211 @@ pub mod pallet {
         pub type Offers<T: Config> = StorageDoubleMap<
             _,
             Twox64Concat,
-            NftItemWithShare,
+            NftItemWithShare<T>,
             Twox64Concat,
             T::AccountId,
-            BoundedVec<Offer<T>, MaxNftsLength>,
+            BoundedVec<Offer<T>, T::MaxOffersLength>,
+        >;
+
+        /// The number of offers of a buyer for an NFT share of a seller, indexes `Offers` by buyer.
+        #[pallet::storage]
+        pub type BuyerOffers<T: Config> = StorageDoubleMap<
+            _,
+            Twox64Concat,
+            T::AccountId, // buyer
+            Twox64Concat,
+            (NftItemWithShare<T>, T::AccountId), // nft, seller
+            u32,
+        >;
+
+        /// The number of offers for an NFT share of a seller, indexes `Offers` by seller.
+        #[pallet::storage]
+        pub type SellerOffers<T: Config> = StorageDoubleMap<
+            _,
+            Twox64Concat,
+            T::AccountId, // seller
+            Twox64Concat,
+            NftItemWithShare<T>,
+            u32,
+        >;
+
+        /// The English auctions of NFTs, the NFT share is escrowed until the auction is settled.
+        #[pallet::storage]
+        pub type Auctions<T: Config> = StorageDoubleMap<
+            _,
+            Twox64Concat,
+            NftItemWithShare<T>,
+            Twox64Concat,
+            T::AccountId, // seller
+            AuctionInfo<T>,
+        >;
+
+        /// The Dutch auctions of NFTs, the NFTs are sold at the declining price of the auction.
+        #[pallet::storage]
+        pub type DutchAuctions<T: Config> = StorageDoubleMap<
+            _,
+            Twox64Concat,
+            NftItemWithShare<T>,
+            Twox64Concat,
+            T::AccountId, // seller
+            DutchAuctionInfo<T>,
+        >;
+
+        /// The auctions to settle at a block, the ones ending at the block or spilled over from full
+        /// blocks before.
+        #[pallet::storage]
+        pub type AuctionsEndingAt<T: Config> = StorageMap<
+            _,
+            Twox64Concat,
+            BlockNumberFor<T>,
+            BoundedVec<(NftItemWithShare<T>, T::AccountId), T::MaxAuctionsPerBlock>, // nft, seller
+            ValueQuery,
         >;
 
+        #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, MaxEncodedLen, TypeInfo)]
+        #[codec(mel_bound(T: Config))]
+        #[scale_info(skip_type_params(T))]
+        pub struct CollectionBid<T: Config> {
+            pub bidder: T::AccountId,
+            pub collection_id: T::CollectionId, // any NFT of the collection fills the bid
+            pub share: Share, // the share of the NFT to buy
+            pub price: BalanceOf<T>, // the reserved amount paid to the seller
+            pub attributes: BoundedVec<BidAttributeOf<T>, T::MaxBidAttributes>, // the attributes the NFT must have
+            pub expiry: Option<BlockNumberFor<T>>, // the bid can not be filled after this block
+        }
+
+        /// The market fee in basis points, charged on the price of every sale.
+        #[pallet::storage]
+        pub type MarketFeeBps<T: Config> = StorageValue<_, u16, ValueQuery>;
+
+        /// The sellers and listed shares of an NFT, in the listings and the Dutch auctions.
+        #[pallet::storage]
+        pub type ListedShares<T: Config> = StorageMap<
+            _,
+            Twox64Concat,
+            NftItemOf<T>,
+            BoundedVec<(T::AccountId, Share), T::MaxListingsPerNft>,
+        >;
+
+        /// The listings and Dutch auctions of a collection, indexes `Listings` and `DutchAuctions`
+        /// by collection.
+        #[pallet::storage]
+        pub type CollectionListings<T: Config> = StorageDoubleMap<
+            _,
+            Twox64Concat,
+            T::CollectionId, // collection
+            Twox64Concat,
+            (T::ItemId, Share, T::AccountId), // item, share, seller
+            (),
+        >;
+
+        /// The listings and offers swept at a block, the ones expiring at the block or spilled over
+        /// from full blocks before.
+        #[pallet::storage]
+        pub type ExpiringAt<T: Config> = StorageMap<
+            _,
+            Twox64Concat,
+            BlockNumberFor<T>,
+            BoundedVec<ExpiringEntry<T>, T::MaxExpiriesPerBlock>,
+            ValueQuery,
+        >;
+
+        /// The next block whose expired listings and offers are swept, set by the first expiry.
+        #[pallet::storage]
+        pub type NextExpiryToSweep<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+        /// The collection-wide bids.
+        #[pallet::storage]
+        pub type CollectionBids<T: Config> = StorageMap<_, Twox64Concat, u32, CollectionBid<T>>;
+
+        /// The id of the next collection-wide bid.
+        #[pallet::storage]
+        pub type NextCollectionBidId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+        /// The proposed swaps.
+        #[pallet::storage]
+        pub type Swaps<T: Config> = StorageMap<_, Twox64Concat, u32, SwapInfo<T>>;
+
+        /// The id of the next proposed swap.
+        #[pallet::storage]
+        pub type NextSwapId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+        /// The deposits reserved from the creators of the swaps.
+        #[pallet::storage]
+        pub type SwapDeposits<T: Config> = StorageMap<_, Twox64Concat, u32, BalanceOf<T>>;
+
+        /// The number of open swaps proposed by an account.
+        #[pallet::storage]
+        pub type ProposedSwaps<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32>;
+
         #[pallet::event]
         #[pallet::generate_deposit(pub(super) fn deposit_event)]
         pub enum Event<T: Config> {
             /// An NFT was listed.
-            NftListed(T::AccountId, NftItemWithShare),
+            NftListed(T::AccountId, NftItemWithShare<T>),
             /// An NFT was unlisted.
-            NftUnlisted(T::AccountId, NftItemWithShare),
+            NftUnlisted(T::AccountId, NftItemWithShare<T>),
             /// Buy NFT success.
-            BuySuccess(NftItemWithShare, T::AccountId, BalanceOf<T>),
+            BuySuccess(NftItemWithShare<T>, T::AccountId, BalanceOf<T>),
             /// An NFT offer was palced.
-            OfferPlaced(NftItemWithShare, T::AccountId, Offer<T>),
+            OfferPlaced(NftItemWithShare<T>, T::AccountId, Offer<T>),
             /// An NFT offer was palced.
-            OfferCanceled(NftItemWithShare, T::AccountId, Offer<T>),
+            OfferCanceled(NftItemWithShare<T>, T::AccountId, Offer<T>),
             /// An NFT offer was accepted.
-            OfferAccepted(T::AccountId, NftItemWithShare, T::AccountId, Offer<T>),
+            OfferAccepted(T::AccountId, NftItemWithShare<T>, T::AccountId, Offer<T>),
             /// An NFT offer was rejected.
-            OfferRejected(T::AccountId, NftItemWithShare, T::AccountId, Offer<T>),
+            OfferRejected(T::AccountId, NftItemWithShare<T>, T::AccountId, Offer<T>),
             /// NFT price updated.
-            NftPriceUpdated(T::AccountId, NftItemWithShare),
+            NftPriceUpdated(T::AccountId, NftItemWithShare<T>),
+            /// The market fee was set.
+            MarketFeeSet(u16), // fee bps
+            /// The market fee was paid for a sold NFT.
+            MarketFeePaid(NftItemWithShare<T>, BalanceOf<T>), // nft, amount
+            /// A creator royalty was paid for a sold NFT.
+            RoyaltyPaid(NftItemWithShare<T>, T::AccountId, BalanceOf<T>), // nft, recipient, amount
+            /// An auction was created.
+            AuctionCreated(T::AccountId, NftItemWithShare<T>, BalanceOf<T>, BlockNumberFor<T>), // seller, nft, reserve price, end block
+            /// A bid was placed on an auction.
+            BidPlaced(T::AccountId, NftItemWithShare<T>, T::AccountId, BalanceOf<T>), // bidder, nft, seller, amount
+            /// An auction was extended by a late bid.
+            AuctionExtended(NftItemWithShare<T>, T::AccountId, BlockNumberFor<T>), // nft, seller, end block
+            /// An auction without bids was cancelled.
+            AuctionCancelled(T::AccountId, NftItemWithShare<T>), // seller, nft
+            /// An auction was settled, the NFT went to the highest bidder or back to the seller.
+            AuctionSettled(NftItemWithShare<T>, T::AccountId, Option<(T::AccountId, BalanceOf<T>)>), // nft, seller, winning bid
+            /// An auction could not be settled, the highest bid was refunded and the NFT returned to the
+            /// seller, or kept in the auction until the seller cancels it.
+            AuctionSettlementFailed(NftItemWithShare<T>, T::AccountId), // nft, seller
+            /// A Dutch auction was created.
+            DutchAuctionCreated(T::AccountId, NftItemWithShare<T>, BalanceOf<T>, BalanceOf<T>), // seller, nft, start price, end price
+            /// A Dutch auction was cancelled.
+            DutchAuctionCancelled(T::AccountId, NftItemWithShare<T>), // seller, nft
+            /// A listing expired and was removed.
+            ListingExpired(T::AccountId, NftItemWithShare<T>), // seller, nft
+            /// A listing or a Dutch auction was removed, the seller no longer holds the listed share
+            /// or the NFT changed.
+            ListingInvalidated(T::AccountId, NftItemWithShare<T>), // seller, nft
+            /// An offer expired, the reserved token amount and the escrowed NFT shares were returned.
+            OfferExpired(NftItemWithShare<T>, T::AccountId, Offer<T>), // nft, seller, offer
+            /// The listing of an offer was removed, the reserved token amount and the escrowed NFT
+            /// shares were returned.
+            OfferReleased(NftItemWithShare<T>, T::AccountId, Offer<T>), // nft, seller, offer
+            /// A swap was proposed.
+            SwapProposed(u32, T::AccountId), // swap id, creator
+            /// A party approved a swap.
+            SwapApproved(u32, T::AccountId), // swap id, party
+            /// Every party approved a swap and it was settled.
+            SwapSettled(u32), // swap id
+            /// A swap was cancelled.
+            SwapCancelled(u32), // swap id
+            /// A collection-wide bid was placed.
+            CollectionBidPlaced(u32, T::AccountId, T::CollectionId, Share, BalanceOf<T>), // bid id, bidder, collection, share, price
+            /// A collection-wide bid was cancelled.
+            CollectionBidCancelled(u32), // bid id
+            /// A collection-wide bid was filled.
+            CollectionBidFilled(u32, T::AccountId, NftItemWithShare<T>), // bid id, seller, nft
+            /// A collection-wide bid expired, the bid price was unreserved.
+            CollectionBidExpired(u32), // bid id
         }
 
         #[pallet::error]
@@ -117,6 +519,125 @@ pub mod pallet {
             InsufficientBalance,
             /// The share of NFT is not enough.
             ShareNotEnough,
+            /// The auction duration can not be zero.
+            InvalidAuctionDuration,
+            /// The NFT is already auctioned by the seller.
+            AuctionAlreadyExists,
+            /// The auction is not found.
+            AuctionNotFound,
+            /// The auction has ended.
+            AuctionEnded,
+            /// The bid is below the reserve price or the minimum bid increment.
+            BidTooLow,
+            /// The seller can not bid on its own auction.
+            CannotBidOwnAuction,
+            /// The auction already has bids.
+            AuctionHasBids,
+            /// Too many offers on a listed NFT.
+            TooManyOffers,
+            /// The offer can not be accepted, a share or the escrowed token amount is missing or an
+            /// NFT can not be transferred.
+            OfferNotAcceptable,
+            /// Too many auctions end in the same block.
+            TooManyAuctionsEnding,
+            /// The end price of a Dutch auction is higher than the start price.
+            InvalidDutchAuctionPrice,
+            /// The Dutch auction has not started.
+            DutchAuctionNotStarted,
+            /// The price is higher than the max price of the buyer.
+            PriceExceedsMaxPrice,
+            /// The expiry block has passed.
+            InvalidExpiry,
+            /// The listing has expired.
+            ListingExpired,
+            /// The offer has expired.
+            OfferExpired,
+            /// Too many listings and offers expire in the same block and the blocks after.
+            TooManyExpiries,
+            /// Too many listings and Dutch auctions of an NFT.
+            TooManyListings,
+            /// The market fee is higher than `MaxMarketFeeBps`.
+            FeeTooHigh,
+            /// The swap has no legs.
+            EmptySwap,
+            /// The swap is not found.
+            SwapNotFound,
+            /// The deadline of the swap has passed.
+            SwapExpired,
+            /// The account is not a party of the swap.
+            NotSwapParty,
+            /// The party already approved the swap.
+            SwapAlreadyApproved,
+            /// The off-chain signature does not match the swap.
+            InvalidSignature,
+            /// The swap can not be settled, a share or token amount is missing or an NFT can not be
+            /// transferred.
+            SwapNotSettleable,
+            /// The collection is not found.
+            CollectionNotFound,
+            /// The collection-wide bid is not found.
+            CollectionBidNotFound,
+            /// The signed account did not place the bid.
+            NotBidder,
+            /// The share of a bid can not be zero.
+            ZeroShare,
+            /// The asset of the price or token amount does not exist.
+            UnknownAsset,
+            /// The account proposed too many open swaps.
+            TooManySwaps,
+            /// The collection-wide bid has expired.
+            CollectionBidExpired,
+            /// The NFT does not have the attributes of the collection-wide bid.
+            AttributeMismatch,
+        }
+
+        /// The reasons the market holds funds for.
+        #[pallet::composite_enum]
+        pub enum HoldReason {
+            /// The native token amount of an offer, held until the offer is accepted or released.
+            Offer,
+        }
+
+        #[pallet::hooks]
+        impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+            fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+                let mut weight = T::DbWeight::get().reads_writes(1, 1);
+                if weight.any_gt(remaining_weight) {
+                    return Weight::zero();
+                }
+                let Some(mut block) = NextExpiryToSweep::<T>::get() else { return T::DbWeight::get().reads(1) };
+
+                // the entries of a block expire once the block has passed
+                while block < now {
+                    let entries = ExpiringAt::<T>::get(block);
+                    let block_weight = entries.iter().fold(
+                        <T as pallet::Config>::WeightInfo::sweep_expired(entries.len() as u32),
+                        |block_weight, entry| block_weight.saturating_add(Self::release_weight(entry)),
+                    );
+                    if weight.saturating_add(block_weight).any_gt(remaining_weight) {
+                        break;
+                    }
+                    ExpiringAt::<T>::remove(block);
+                    for entry in entries {
+                        Self::expire(entry, block);
+                    }
+                    weight = weight.saturating_add(block_weight);
+                    block = block.saturating_add(1u32.into());
+                }
+
+                NextExpiryToSweep::<T>::put(block);
+                weight
+            }
+
+            fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+                let ending_auctions = AuctionsEndingAt::<T>::take(now);
+                let mut weight = T::DbWeight::get().reads_writes(1, 1);
+                for (nft_item_with_share, seller) in ending_auctions {
+                    Self::settle_auction(nft_item_with_share, seller);
+                    weight.saturating_accrue(<T as pallet::Config>::WeightInfo::settle_auction());
+                }
+                weight
+            }
         }
 
         #[pallet::call]
@@ -128,33 +649,46 @@ pub mod pallet {
             /// Parameters:
             /// - `nft_item_with_share`: The NFT to be listed.
             /// - `price`: Price of the NFT.
+            /// - `expiry`: The last block the NFT can be bought at, if any.
+            /// - `asset`: The asset of the price, the native currency if `None`.
             ///
             /// Emits `NftListed` event when successful.
             #[pallet::call_index(0)]
-            #[pallet::weight(<T as pallet::Config>::WeightInfo::list_nft())]
-            pub fn list_nft(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare, price: BalanceOf<T>) -> DispatchResult {
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::list_nft().saturating_add(Self::expiry_spillover_weight()))]
+            pub fn list_nft(origin: OriginFor<T>,
+                            nft_item_with_share: NftItemWithShare<T>,
+                            price: BalanceOf<T>,
+                            expiry: Option<BlockNumberFor<T>>,
+                            asset: Option<T::AssetId>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
                 let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
                 let share = nft_item_with_share.2;
-                ensure!(NFTOwners::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
-                let owned_nfts_with_share = OwnedNFTs::<T>::get(sender.clone()).ok_or(Error::<T>::NotOwner)?;
-                let owned_nft_with_share = owned_nfts_with_share.iter().find(|owned_nft| {
-                    owned_nft.0 == nft_item.0 && owned_nft.1 == nft_item.1}).ok_or(Error::<T>::NotOwner)?;
-                ensure!(owned_nft_with_share.2 >= share, Error::<T>::ShareNotEnough);
+                ensure!(T::Nfts::share_supply(nft_item) > 0, Error::<T>::NFTNotFound);
+                Self::ensure_asset_exists(asset)?;
+                let owned_share = T::Nfts::share_of(&sender, nft_item);
+                ensure!(owned_share > 0, Error::<T>::NotOwner);
+                ensure!(owned_share >= share, Error::<T>::ShareNotEnough);
+                // a frozen or merged NFT can never be sold
+                T::Nfts::can_transfer_share(&sender, nft_item, share)?;
+                ensure!(!DutchAuctions::<T>::contains_key(nft_item_with_share, &sender), Error::<T>::NftAlreadyListed);
 
                 let list_info = ListInfo {
                     owner: sender.clone(),
                     price,
+                    expiry,
+                    asset,
                 };
                 let nft_item_with_share = (nft_item.0, nft_item.1, share);
+                Self::register_expiry(expiry, ExpiringEntry::Listing(nft_item_with_share, sender.clone()))?;
                 Listings::<T>::insert(nft_item_with_share, sender.clone(), &list_info);
+                Self::add_listed_share(nft_item_with_share, &sender)?;
 
                 Self::deposit_event(Event::NftListed(sender.clone(), nft_item_with_share));
 
                 Ok(())
             }
 
-            /// Unlist an NFT.
+            /// Unlist an NFT, the escrow of every offer on it is returned.
             ///
             /// The origin must be signed.
             ///
@@ -163,50 +697,66 @@ pub mod pallet {
             ///
             /// Emits `NftUnlisted` event when successful.
             #[pallet::call_index(1)]
-            #[pallet::weight({10_000})]
-            pub fn unlist_nft(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare) -> DispatchResult {
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::unlist_nft(T::MaxOffersLength::get()))]
+            pub fn unlist_nft(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare<T>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
                 let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
-                ensure!(NFTOwners::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
+                ensure!(T::Nfts::share_supply(nft_item) > 0, Error::<T>::NFTNotFound);
 
                 Listings::<T>::remove(nft_item_with_share, sender.clone());
-                Offers::<T>::remove(nft_item_with_share, sender.clone());
+                Self::remove_listed_share(nft_item_with_share, &sender);
+                Self::release_offers(nft_item_with_share, &sender);
 
                 Self::deposit_event(Event::NftUnlisted(sender, nft_item_with_share));
 
                 Ok(())
             }
 
-            /// Buy an NFT.
+            /// Buy a listed NFT or an NFT in a Dutch auction at the current price, the escrow of every
+            /// offer on it is returned.
             ///
             /// The origin must be signed.
             ///
             /// Parameters:
             /// - `nft_item_with_share`: The NFT to buy.
             /// - `seller`: Seller of the NFT.
+            /// - `max_price`: The highest price the buyer accepts.
             ///
             /// Emits `BuySucess` event when successful.
             #[pallet::call_index(2)]
-            #[pallet::weight({10_000})]
-            pub fn buy_nft(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare, seller: T::AccountId) -> DispatchResult {
-                let buyer = ensure_signed(origin.clone())?;
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::buy_nft(T::MaxOffersLength::get()))]
+            pub fn buy_nft(origin: OriginFor<T>,
+                           nft_item_with_share: NftItemWithShare<T>,
+                           seller: T::AccountId,
+                           max_price: BalanceOf<T>) -> DispatchResult {
+                let buyer = ensure_signed(origin)?;
                 let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
                 let share = nft_item_with_share.2;
-                let list_info = Listings::<T>::get(nft_item_with_share, seller.clone()).ok_or(Error::<T>::NotListed)?;
-                let buyer_balance = T::Currency::free_balance(&buyer.clone());
-
-                ensure!(buyer_balance >= list_info.price, Error::<T>::InsufficientBalance);
-                T::Currency::transfer(&buyer.clone(), &seller.clone(), list_info.price, frame_support::traits::ExistenceRequirement::AllowDeath)?;
-                let seller_origin = frame_system::RawOrigin::Signed(seller.clone()).into();
-                NftPallet::<T>::transfer_nft(seller_origin, buyer.clone(), nft_item, share)?;
+                Self::remove_listed_share(nft_item_with_share, &seller);
+                let (price, asset) = match Listings::<T>::take(nft_item_with_share, seller.clone()) {
+                    Some(list_info) => {
+                        Self::ensure_not_expired(list_info.expiry, Error::<T>::ListingExpired)?;
+                        (list_info.price, list_info.asset)
+                    },
+                    None => {
+                        let dutch_auction = DutchAuctions::<T>::take(nft_item_with_share, &seller).ok_or(Error::<T>::NotListed)?;
+                        (Self::dutch_auction_price(&dutch_auction)?, None)
+                    },
+                };
+                ensure!(price <= max_price, Error::<T>::PriceExceedsMaxPrice);
+                let buyer_balance = Self::token_balance(asset, &buyer);
 
-                Listings::<T>::remove(nft_item_with_share, seller.clone());
+                ensure!(buyer_balance >= price, Error::<T>::InsufficientBalance);
+                Self::pay_with_royalty(asset, &buyer, &seller, nft_item_with_share, price)?;
+                T::Nfts::transfer_share(&seller, &buyer, nft_item, share)?;
+                Self::release_offers(nft_item_with_share, &seller);
 
-                Self::deposit_event(Event::BuySuccess(nft_item_with_share, seller, list_info.price));
+                Self::deposit_event(Event::BuySuccess(nft_item_with_share, seller, price));
                 Ok(())
             }
 
-            /// Provide an offer to buy an NFT.
+            /// Provide an offer to buy an NFT, the token amount is reserved and the offered NFT shares
+            /// are escrowed until the offer is cancelled, rejected or accepted.
             ///
             /// The origin must be signed.
             ///
@@ -215,50 +765,56 @@ pub mod pallet {
             /// - `offered_nfts`: The NFTs that needs to be used as an offer.
             /// - `token_amount`: The token amount that needs to be used as an offer.
             /// - `seller`: Seller of the NFT.
+            /// - `expiry`: The last block the offer can be accepted at, if any.
+            /// - `asset`: The asset of the token amount, the native currency if `None`.
             ///
             /// Emits `OfferPlaced` event when successful.
             #[pallet::call_index(3)]
-            #[pallet::weight({10_000})]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::place_offer(offered_nfts.len() as u32).saturating_add(Self::expiry_spillover_weight()))]
             pub fn place_offer(origin: OriginFor<T>,
-                               nft_item_with_share: NftItemWithShare,
-                               offered_nfts: BoundedVec<NftItemWithShare, MaxOfferNftsLength>,
+                               nft_item_with_share: NftItemWithShare<T>,
+                               offered_nfts: BoundedVec<NftItemWithShare<T>, T::MaxOfferNftsLength>,
                                token_amount: BalanceOf<T>,
-                               seller: T::AccountId) -> DispatchResult {
+                               seller: T::AccountId,
+                               expiry: Option<BlockNumberFor<T>>,
+                               asset: Option<T::AssetId>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
-                ensure!(Listings::<T>::contains_key(nft_item_with_share, seller.clone()), Error::<T>::NotListed);
+                let list_info = Listings::<T>::get(nft_item_with_share, seller.clone()).ok_or(Error::<T>::NotListed)?;
+                Self::ensure_not_expired(list_info.expiry, Error::<T>::ListingExpired)?;
+                Self::ensure_asset_exists(asset)?;
 
-                let owned_nfts_with_share = OwnedNFTs::<T>::get(sender.clone()).unwrap_or_default();
                 for offered_nft_item_with_share in offered_nfts.clone().into_iter() {
                     let offered_nft_item = (offered_nft_item_with_share.0, offered_nft_item_with_share.1);
-                    ensure!(NFTOwners::<T>::contains_key(offered_nft_item), Error::<T>::NFTNotFound);
-                    let owned_nft_item_with_share = owned_nfts_with_share.iter().find(|nft| nft.0 == offered_nft_item.0 && nft.1 == offered_nft_item.1);
-                    if let Some(nft_item_with_share) = owned_nft_item_with_share {
-                        ensure!(nft_item_with_share.2 >= offered_nft_item_with_share.2, Error::<T>::ShareNotEnough);
-                    } else {
-                        return Err(Error::<T>::NotOwner.into());
-                    }
+                    ensure!(T::Nfts::share_supply(offered_nft_item) > 0, Error::<T>::NFTNotFound);
+                    let owned_share = T::Nfts::share_of(&sender, offered_nft_item);
+                    ensure!(owned_share > 0, Error::<T>::NotOwner);
+                    ensure!(owned_share >= offered_nft_item_with_share.2, Error::<T>::ShareNotEnough);
+                    T::Nfts::can_transfer_share(&sender, offered_nft_item, offered_nft_item_with_share.2)?;
                 }
 
                 let offer_item = Offer {
                     offered_nfts,
                     token_amount,
                     buyer: sender.clone(),
+                    expiry,
+                    asset,
                 };
 
-                Offers::<T>::mutate(nft_item_with_share, seller, |offer_items| {
-                    if offer_items.is_none() {
-                        *offer_items = Some(BoundedVec::<Offer<T>, MaxNftsLength>::default());
-                    }
-                    if let Some(offer_items_value) = offer_items {
-                        offer_items_value.try_push(offer_item.clone()).unwrap_or_default();
-                    }
-                });
+                Offers::<T>::try_mutate(nft_item_with_share, seller.clone(), |offer_items| -> DispatchResult {
+                    offer_items.get_or_insert_with(BoundedVec::default)
+                        .try_push(offer_item.clone())
+                        .map_err(|_| Error::<T>::TooManyOffers)?;
+                    Ok(())
+                })?;
+                Self::index_offer(nft_item_with_share, &seller, &sender);
+                Self::register_expiry(expiry, ExpiringEntry::Offer(nft_item_with_share, seller, offer_item.clone()))?;
+                Self::escrow_offer(&offer_item)?;
 
                 Self::deposit_event(Event::OfferPlaced(nft_item_with_share, sender, offer_item));
                 Ok(())
             }
 
-            /// Cancel an NFT offer.
+            /// Cancel an NFT offer, the reserved token amount and the escrowed NFT shares are returned.
             ///
             /// The origin must be signed.
             ///
@@ -267,52 +823,38 @@ pub mod pallet {
             /// - `offered_nfts`: The NFTs that needs to be used as an offer.
             /// - `token_amount`: The token amount that needs to be used as an offer.
             /// - `seller`: Seller of the NFT.
+            /// - `asset`: The asset of the token amount, the native currency if `None`.
             ///
             /// Emits `OfferCanceled` event when successful.
             #[pallet::call_index(4)]
-            #[pallet::weight({10_000})]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_offer(offered_nfts.len() as u32))]
             pub fn cancel_offer(origin: OriginFor<T>,
-                                nft_item_with_share: NftItemWithShare,
-                                offered_nfts: BoundedVec<NftItemWithShare, MaxOfferNftsLength>,
+                                nft_item_with_share: NftItemWithShare<T>,
+                                offered_nfts: BoundedVec<NftItemWithShare<T>, T::MaxOfferNftsLength>,
                                 token_amount: BalanceOf<T>,
-                                seller: T::AccountId) -> DispatchResult {
+                                seller: T::AccountId,
+                                asset: Option<T::AssetId>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
-                ensure!(Listings::<T>::contains_key(nft_item_with_share, seller.clone()), Error::<T>::NotListed);
-
-                let owned_nfts_with_share = OwnedNFTs::<T>::get(sender.clone()).unwrap_or_default();
-                for offered_nft_item_with_share in offered_nfts.clone().into_iter() {
-                    let offered_nft_item = (offered_nft_item_with_share.0, offered_nft_item_with_share.1);
-                    ensure!(NFTOwners::<T>::contains_key(offered_nft_item), Error::<T>::NFTNotFound);
-                    let owned_nft_item_with_share = owned_nfts_with_share.iter().find(|nft| nft.0 == offered_nft_item.0 && nft.1 == offered_nft_item.1);
-                    if let Some(nft_item_with_share) = owned_nft_item_with_share {
-                        ensure!(nft_item_with_share.2 >= offered_nft_item_with_share.2, Error::<T>::ShareNotEnough);
-                    } else {
-                        return Err(Error::<T>::NotOwner.into());
-                    }
-                }
-
-                let offer_item = Offer {
-                    offered_nfts,
-                    token_amount,
-                    buyer: sender.clone(),
-                };
 
-                Offers::<T>::mutate(nft_item_with_share, seller, |offer_items| {
-                    if let Some(offer_items_value) = offer_items {
-                        if let Some(index) = offer_items_value.iter().position(|x| *x == offer_item) {
-                            offer_items_value.remove(index);
-                        } else {
-                            return Err(Error::<T>::NotOffered);
-                        }
-                    }
-                    Ok(())
+                let offer_item = Offers::<T>::try_mutate(nft_item_with_share, &seller, |offer_items| -> Result<Offer<T>, DispatchError> {
+                    let offer_items_value = offer_items.as_mut().ok_or(Error::<T>::NotOffered)?;
+                    let index = offer_items_value.iter()
+                        .position(|x| x.buyer == sender && x.offered_nfts == offered_nfts && x.token_amount == token_amount && x.asset == asset)
+                        .ok_or(Error::<T>::NotOffered)?;
+                    Ok(offer_items_value.remove(index))
                 })?;
+                Self::unindex_offer(nft_item_with_share, &seller, &sender);
+                Self::release_offer(&offer_item)?;
 
                 Self::deposit_event(Event::OfferCanceled(nft_item_with_share, sender, offer_item));
                 Ok(())
             }
 
-            /// Accept an offer.
+            /// Accept an offer, the escrowed NFT shares and the reserved or escrowed token amount of the
+            /// offer go to the seller, the escrow of the other offers is returned.
+            ///
+            /// The whole swap is validated before anything is transferred and executed in a storage
+            /// layer, any failure fails the call with `OfferNotAcceptable` and changes nothing.
             ///
             /// The origin must be signed.
             ///
@@ -321,40 +863,59 @@ pub mod pallet {
             /// - `offered_nfts`: The NFTs that needs to be used as an offer.
             /// - `offered_token_amount`: The token amount that needs to be used as an offer.
             /// - `buyer`: Buyer of the NFT.
+            /// - `asset`: The asset of the token amount, the native currency if `None`.
             ///
             /// Emits `OfferAccepted` event when successful.
             #[pallet::call_index(5)]
-            #[pallet::weight({10_000})]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::accept_offer(offered_nfts.len() as u32, T::MaxOffersLength::get()))]
             pub fn accept_offer(origin: OriginFor<T>,
-                                nft_item_with_share: NftItemWithShare,
-                                offered_nfts: BoundedVec<NftItemWithShare, MaxOfferNftsLength>,
+                                nft_item_with_share: NftItemWithShare<T>,
+                                offered_nfts: BoundedVec<NftItemWithShare<T>, T::MaxOfferNftsLength>,
                                 offered_token_amount: BalanceOf<T>,
-                                buyer: T::AccountId) -> DispatchResult {
-                let sender = ensure_signed(origin.clone())?;
-                let offers = Offers::<T>::get(nft_item_with_share, sender.clone()).ok_or(Error::<T>::NotOffered)?;
-                let offer = offers.iter().find(|offer| {
-                    offer.buyer == buyer && offer.offered_nfts == offered_nfts && offer.token_amount == offered_token_amount
-                    }).ok_or(Error::<T>::NotOffered)?;
-
-                NftPallet::<T>::transfer_nft(origin.clone(), buyer.clone(), (nft_item_with_share.0, nft_item_with_share.1), nft_item_with_share.2)?;
-                for offered_nft_item in offered_nfts.clone().into_iter() {
-                    NftPallet::<T>::transfer_nft(OriginFor::<T>::signed(buyer.clone()), sender.clone(), (offered_nft_item.0, offered_nft_item.1), offered_nft_item.2)?;
-                }
+                                buyer: T::AccountId,
+                                asset: Option<T::AssetId>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let list_info = Listings::<T>::get(nft_item_with_share, sender.clone()).ok_or(Error::<T>::NotListed)?;
+                Self::ensure_not_expired(list_info.expiry, Error::<T>::ListingExpired)?;
+                let offer = Offers::<T>::try_mutate(nft_item_with_share, sender.clone(), |offer_items| -> Result<Offer<T>, DispatchError> {
+                    let offer_items_value = offer_items.as_mut().ok_or(Error::<T>::NotOffered)?;
+                    let index = offer_items_value.iter()
+                        .position(|x| x.buyer == buyer && x.offered_nfts == offered_nfts && x.token_amount == offered_token_amount && x.asset == asset)
+                        .ok_or(Error::<T>::NotOffered)?;
+                    Ok(offer_items_value.remove(index))
+                })?;
+                Self::ensure_not_expired(offer.expiry, Error::<T>::OfferExpired)?;
+                Self::unindex_offer(nft_item_with_share, &sender, &buyer);
+                Listings::<T>::remove(nft_item_with_share, sender.clone());
+                Self::remove_listed_share(nft_item_with_share, &sender);
 
-                if offered_token_amount > BalanceOf::<T>::zero() {
-                   let buyer_balance = T::Currency::free_balance(&buyer.clone());
-                   ensure!(buyer_balance >= offered_token_amount, Error::<T>::InsufficientBalance);
-                   T::Currency::transfer(&buyer.clone(), &sender.clone(), offered_token_amount, frame_support::traits::ExistenceRequirement::AllowDeath)?;
-                }
+                Self::ensure_offer_acceptable(&sender, nft_item_with_share, &offer).map_err(|_| Error::<T>::OfferNotAcceptable)?;
+                with_storage_layer(|| -> DispatchResult {
+                    T::Nfts::transfer_share(&sender, &buyer, (nft_item_with_share.0, nft_item_with_share.1), nft_item_with_share.2)?;
+                    for offered_nft_item in offer.offered_nfts.iter() {
+                        T::Nfts::transfer_share(&Self::account_id(), &sender, (offered_nft_item.0, offered_nft_item.1), offered_nft_item.2)?;
+                    }
 
-                Listings::<T>::remove(nft_item_with_share, sender.clone());
-                Offers::<T>::remove(nft_item_with_share, sender.clone());
+                    if offered_token_amount > BalanceOf::<T>::zero() {
+                        // the native token amount is held, the asset token amount is escrowed
+                        let payer = match asset {
+                            Some(_) => Self::account_id(),
+                            None => {
+                                T::OfferHolds::release(&HoldReason::Offer.into(), &buyer, offered_token_amount, Precision::Exact)?;
+                                buyer.clone()
+                            },
+                        };
+                        Self::pay_with_royalty(asset, &payer, &sender, nft_item_with_share, offered_token_amount)?;
+                    }
+                    Ok(())
+                }).map_err(|_| Error::<T>::OfferNotAcceptable)?;
+                Self::release_offers(nft_item_with_share, &sender);
 
-                Self::deposit_event(Event::OfferAccepted(sender, nft_item_with_share, buyer, offer.clone()));
+                Self::deposit_event(Event::OfferAccepted(sender, nft_item_with_share, buyer, offer));
                 Ok(())
             }
 
-            /// Reject an offer.
+            /// Reject an offer, the reserved token amount and the escrowed NFT shares are returned.
             ///
             /// The origin must be signed.
             ///
@@ -363,20 +924,22 @@ pub mod pallet {
             /// - `offered_nfts`: The NFTs that needs to be used as an offer.
             /// - `offered_token_amount`: The token amount that needs to be used as an offer.
             /// - `buyer`: Buyer of the NFT.
+            /// - `asset`: The asset of the token amount, the native currency if `None`.
             ///
             /// Emits `OfferRejected` event when successful.
             #[pallet::call_index(6)]
-            #[pallet::weight({10_000})]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::reject_offer(offered_nfts.len() as u32))]
             pub fn reject_offer(origin: OriginFor<T>,
-                                nft_item_with_share: NftItemWithShare,
-                                offered_nfts: BoundedVec<NftItemWithShare, MaxOfferNftsLength>,
+                                nft_item_with_share: NftItemWithShare<T>,
+                                offered_nfts: BoundedVec<NftItemWithShare<T>, T::MaxOfferNftsLength>,
                                 offered_token_amount: BalanceOf<T>,
-                                buyer: T::AccountId) -> DispatchResult {
+                                buyer: T::AccountId,
+                                asset: Option<T::AssetId>) -> DispatchResult {
                 let sender = ensure_signed(origin.clone())?;
                 let mut offers = Offers::<T>::get(nft_item_with_share, sender.clone()).ok_or(Error::<T>::NotOffered)?;
                 let mut remove_offer_wrap: Option<Offer<T>> = None;
                 for (i, offer) in offers.clone().into_iter().enumerate() {
-                    if offer.buyer == buyer && offer.offered_nfts == offered_nfts && offer.token_amount == offered_token_amount {
+                    if offer.buyer == buyer && offer.offered_nfts == offered_nfts && offer.token_amount == offered_token_amount && offer.asset == asset {
                         offers.remove(i);
                         remove_offer_wrap = Some(offer);
                         break;
@@ -384,6 +947,8 @@ pub mod pallet {
                 }
                 if let Some(remove_offer) = remove_offer_wrap {
                     Offers::<T>::insert(nft_item_with_share, sender.clone(), offers);
+                    Self::unindex_offer(nft_item_with_share, &sender, &buyer);
+                    Self::release_offer(&remove_offer)?;
                     Self::deposit_event(Event::OfferRejected(sender, nft_item_with_share, buyer, remove_offer));
                     Ok(())
                 } else {
@@ -398,24 +963,1067 @@ pub mod pallet {
             /// - `nft_item_with_share`: The NFT to be unlisted.
             /// - `price`: The new price of the NFT.
             ///
-            /// Emits `NftUnlisted` event when successful.
+            /// Emits `NftPriceUpdated` event when successful.
             #[pallet::call_index(7)]
-            #[pallet::weight({10_000})]
-            pub fn update_list_price(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare, price: BalanceOf<T>) -> DispatchResult {
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::update_list_price())]
+            pub fn update_list_price(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare<T>, price: BalanceOf<T>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
+                ensure!(T::Nfts::share_supply(nft_item) > 0, Error::<T>::NFTNotFound);
+
+                Listings::<T>::try_mutate(nft_item_with_share, &sender, |list_info| -> DispatchResult {
+                    let list_info = list_info.as_mut().ok_or(Error::<T>::NotListed)?;
+                    list_info.price = price;
+                    Ok(())
+                })?;
+
+                Self::deposit_event(Event::NftPriceUpdated(sender, nft_item_with_share));
+
+                Ok(())
+            }
+
+            /// Start an English auction, the NFT share is escrowed until the auction is settled.
+            ///
+            /// The origin must be signed.
+            ///
+            /// Parameters:
+            /// - `nft_item_with_share`: The NFT to auction.
+            /// - `reserve_price`: The lowest accepted bid.
+            /// - `min_bid_increment`: The amount a new bid must exceed the highest bid by.
+            /// - `duration`: The number of blocks until the auction is settled.
+            ///
+            /// Emits `AuctionCreated` event when successful.
+            #[pallet::call_index(8)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::create_auction().saturating_add(Self::expiry_spillover_weight()))]
+            pub fn create_auction(origin: OriginFor<T>,
+                                  nft_item_with_share: NftItemWithShare<T>,
+                                  reserve_price: BalanceOf<T>,
+                                  min_bid_increment: BalanceOf<T>,
+                                  duration: BlockNumberFor<T>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
+                ensure!(!duration.is_zero(), Error::<T>::InvalidAuctionDuration);
+                ensure!(!Auctions::<T>::contains_key(nft_item_with_share, &sender), Error::<T>::AuctionAlreadyExists);
+
+                T::Nfts::transfer_share(&sender, &Self::account_id(), nft_item, nft_item_with_share.2)?;
+
+                let end_block = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+                Self::queue_settlement(end_block, (nft_item_with_share, sender.clone()))?;
+                Auctions::<T>::insert(nft_item_with_share, &sender, AuctionInfo {
+                    reserve_price,
+                    min_bid_increment,
+                    end_block,
+                    highest_bid: None,
+                });
+
+                Self::deposit_event(Event::AuctionCreated(sender, nft_item_with_share, reserve_price, end_block));
+                Ok(())
+            }
+
+            /// Bid on an auction, the bid is reserved and the outbid bidder is refunded.
+            ///
+            /// The origin must be signed.
+            ///
+            /// Parameters:
+            /// - `nft_item_with_share`: The auctioned NFT.
+            /// - `seller`: Seller of the NFT.
+            /// - `amount`: The bid amount.
+            ///
+            /// Emits `BidPlaced` event when successful.
+            #[pallet::call_index(9)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::bid().saturating_add(Self::expiry_spillover_weight().saturating_mul(2)))]
+            pub fn bid(origin: OriginFor<T>,
+                       nft_item_with_share: NftItemWithShare<T>,
+                       seller: T::AccountId,
+                       amount: BalanceOf<T>) -> DispatchResult {
+                let bidder = ensure_signed(origin)?;
+                ensure!(bidder != seller, Error::<T>::CannotBidOwnAuction);
+
+                Auctions::<T>::try_mutate(nft_item_with_share, &seller, |auction_wrap| -> DispatchResult {
+                    let auction = auction_wrap.as_mut().ok_or(Error::<T>::AuctionNotFound)?;
+                    let now = frame_system::Pallet::<T>::block_number();
+                    ensure!(now < auction.end_block, Error::<T>::AuctionEnded);
+
+                    if let Some((_, highest_amount)) = &auction.highest_bid {
+                        ensure!(amount > *highest_amount, Error::<T>::BidTooLow);
+                        ensure!(amount >= highest_amount.saturating_add(auction.min_bid_increment), Error::<T>::BidTooLow);
+                    } else {
+                        ensure!(!amount.is_zero() && amount >= auction.reserve_price, Error::<T>::BidTooLow);
+                    }
+
+                    T::Currency::reserve(&bidder, amount).map_err(|_| Error::<T>::InsufficientBalance)?;
+                    if let Some((outbid_bidder, outbid_amount)) = auction.highest_bid.take() {
+                        T::Currency::unreserve(&outbid_bidder, outbid_amount);
+                    }
+                    auction.highest_bid = Some((bidder.clone(), amount));
+
+                    // a late bid extends the auction, so that other bidders can still answer it
+                    let extended_end_block = now.saturating_add(T::AuctionExtensionPeriod::get());
+                    if extended_end_block > auction.end_block {
+                        Self::unqueue_settlement(auction.end_block, (nft_item_with_share, seller.clone()));
+                        Self::queue_settlement(extended_end_block, (nft_item_with_share, seller.clone()))?;
+                        auction.end_block = extended_end_block;
+                        Self::deposit_event(Event::AuctionExtended(nft_item_with_share, seller.clone(), extended_end_block));
+                    }
+                    Ok(())
+                })?;
+
+                Self::deposit_event(Event::BidPlaced(bidder, nft_item_with_share, seller, amount));
+                Ok(())
+            }
+
+            /// Cancel an auction without bids, the NFT share is returned to the seller. Also reclaims
+            /// the NFT share of an ended auction which could not be settled.
+            ///
+            /// The origin must be signed by the seller.
+            ///
+            /// Parameters:
+            /// - `nft_item_with_share`: The auctioned NFT.
+            ///
+            /// Emits `AuctionCancelled` event when successful.
+            #[pallet::call_index(10)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_auction().saturating_add(Self::expiry_spillover_weight()))]
+            pub fn cancel_auction(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare<T>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
+                let auction = Auctions::<T>::get(nft_item_with_share, &sender).ok_or(Error::<T>::AuctionNotFound)?;
+                ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+                T::Nfts::transfer_share(&Self::account_id(), &sender, nft_item, nft_item_with_share.2)?;
+
+                Auctions::<T>::remove(nft_item_with_share, &sender);
+                Self::unqueue_settlement(auction.end_block, (nft_item_with_share, sender.clone()));
+
+                Self::deposit_event(Event::AuctionCancelled(sender, nft_item_with_share));
+                Ok(())
+            }
+
+            /// Sell an NFT in a Dutch auction, the price declines from the start price to the end
+            /// price over the duration.
+            ///
+            /// The origin must be signed.
+            ///
+            /// Parameters:
+            /// - `nft_item_with_share`: The NFT to sell.
+            /// - `start_price`: The price at the start block.
+            /// - `end_price`: The price after the duration, not higher than the start price.
+            /// - `start_block`: The block from which the NFT can be bought.
+            /// - `duration`: The number of blocks the price declines.
+            ///
+            /// Emits `DutchAuctionCreated` event when successful.
+            #[pallet::call_index(11)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::create_dutch_auction())]
+            pub fn create_dutch_auction(origin: OriginFor<T>,
+                                        nft_item_with_share: NftItemWithShare<T>,
+                                        start_price: BalanceOf<T>,
+                                        end_price: BalanceOf<T>,
+                                        start_block: BlockNumberFor<T>,
+                                        duration: BlockNumberFor<T>) -> DispatchResult {
                 let sender = ensure_signed(origin)?;
                 let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
-                ensure!(NFTOwners::<T>::contains_key(nft_item), Error::<T>::NFTNotFound);
+                ensure!(T::Nfts::share_supply(nft_item) > 0, Error::<T>::NFTNotFound);
+                let owned_share = T::Nfts::share_of(&sender, nft_item);
+                ensure!(owned_share > 0, Error::<T>::NotOwner);
+                ensure!(owned_share >= nft_item_with_share.2, Error::<T>::ShareNotEnough);
+                // a frozen or merged NFT can never be sold
+                T::Nfts::can_transfer_share(&sender, nft_item, nft_item_with_share.2)?;
+                ensure!(end_price <= start_price, Error::<T>::InvalidDutchAuctionPrice);
+                ensure!(!duration.is_zero(), Error::<T>::InvalidAuctionDuration);
+                ensure!(!Listings::<T>::contains_key(nft_item_with_share, &sender), Error::<T>::NftAlreadyListed);
+                ensure!(!DutchAuctions::<T>::contains_key(nft_item_with_share, &sender), Error::<T>::AuctionAlreadyExists);
+
+                DutchAuctions::<T>::insert(nft_item_with_share, &sender, DutchAuctionInfo {
+                    start_price,
+                    end_price,
+                    start_block,
+                    duration,
+                });
+                Self::add_listed_share(nft_item_with_share, &sender)?;
 
-                Listings::<T>::mutate(nft_item_with_share, &sender, |list_info| {
-                    *list_info = Some(ListInfo {
-                        owner: sender.clone(),
-                        price,
+                Self::deposit_event(Event::DutchAuctionCreated(sender, nft_item_with_share, start_price, end_price));
+                Ok(())
+            }
+
+            /// Cancel a Dutch auction.
+            ///
+            /// The origin must be signed by the seller.
+            ///
+            /// Parameters:
+            /// - `nft_item_with_share`: The NFT in the Dutch auction.
+            ///
+            /// Emits `DutchAuctionCancelled` event when successful.
+            #[pallet::call_index(12)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_dutch_auction())]
+            pub fn cancel_dutch_auction(origin: OriginFor<T>, nft_item_with_share: NftItemWithShare<T>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                ensure!(DutchAuctions::<T>::contains_key(nft_item_with_share, &sender), Error::<T>::AuctionNotFound);
+
+                DutchAuctions::<T>::remove(nft_item_with_share, &sender);
+                Self::remove_listed_share(nft_item_with_share, &sender);
+
+                Self::deposit_event(Event::DutchAuctionCancelled(sender, nft_item_with_share));
+                Ok(())
+            }
+
+            /// Propose a swap between several parties, each leg moves NFT shares and tokens from one
+            /// party to another. The swap is settled once every party giving NFTs or tokens approved
+            /// it.
+            ///
+            /// The origin must be signed, the creator approves the swap if it is a party. The swap
+            /// deposit is reserved from the creator until the swap is settled or cancelled.
+            ///
+            /// Parameters:
+            /// - `legs`: The transfers of the swap.
+            /// - `deadline`: The last block the swap can be approved at.
+            ///
+            /// Emits `SwapProposed` event when successful.
+            #[pallet::call_index(13)]
+            #[pallet::weight({
+                let (legs, nfts) = Pallet::<T>::swap_weight_components(legs);
+                <T as pallet::Config>::WeightInfo::propose_swap(legs, nfts)
+                    .saturating_add(<T as pallet::Config>::WeightInfo::approve_swap(legs, nfts))
+            })]
+            pub fn propose_swap(origin: OriginFor<T>,
+                                legs: BoundedVec<SwapLeg<T>, T::MaxSwapLegs>,
+                                deadline: BlockNumberFor<T>) -> DispatchResultWithPostInfo {
+                let sender = ensure_signed(origin)?;
+                ensure!(!legs.is_empty(), Error::<T>::EmptySwap);
+                let (leg_count, nft_count) = Self::swap_weight_components(&legs);
+                ensure!(deadline >= frame_system::Pallet::<T>::block_number(), Error::<T>::SwapExpired);
+
+                let swap_id = NextSwapId::<T>::get();
+                NextSwapId::<T>::put(swap_id.wrapping_add(1));
+                ProposedSwaps::<T>::try_mutate(&sender, |count| -> DispatchResult {
+                    let count = count.get_or_insert(0);
+                    ensure!(*count < T::MaxSwapsPerAccount::get(), Error::<T>::TooManySwaps);
+                    *count += 1;
+                    Ok(())
+                })?;
+                let deposit = T::SwapDeposit::get();
+                T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+                SwapDeposits::<T>::insert(swap_id, deposit);
+                let is_party = legs.iter().any(|leg| leg.from == sender);
+                Swaps::<T>::insert(swap_id, SwapInfo {
+                    creator: sender.clone(),
+                    legs,
+                    approvals: BoundedVec::default(),
+                    deadline,
+                });
+
+                Self::deposit_event(Event::SwapProposed(swap_id, sender.clone()));
+                let mut actual_weight = <T as pallet::Config>::WeightInfo::propose_swap(leg_count, nft_count);
+                if is_party {
+                    Self::do_approve_swap(swap_id, sender)?;
+                    actual_weight.saturating_accrue(<T as pallet::Config>::WeightInfo::approve_swap(leg_count, nft_count));
+                }
+                Ok(Some(actual_weight).into())
+            }
+
+            /// Approve a swap, the last approval settles it.
+            ///
+            /// The origin must be signed by a party giving NFTs or tokens.
+            ///
+            /// Parameters:
+            /// - `swap_id`: The swap to approve.
+            ///
+            /// Emits `SwapApproved` event when successful, and `SwapSettled` event with the last
+            /// approval.
+            #[pallet::call_index(14)]
+            #[pallet::weight({
+                let (legs, nfts) = Pallet::<T>::max_swap_weight_components();
+                <T as pallet::Config>::WeightInfo::approve_swap(legs, nfts)
+            })]
+            pub fn approve_swap(origin: OriginFor<T>, swap_id: u32) -> DispatchResultWithPostInfo {
+                let sender = ensure_signed(origin)?;
+                let (legs, nfts) = Self::do_approve_swap(swap_id, sender)?;
+                Ok(Some(<T as pallet::Config>::WeightInfo::approve_swap(legs, nfts)).into())
+            }
+
+            /// Approve a swap with a payload signed off-chain by a party, the last approval settles
+            /// it. The signed payload is `Pallet::swap_payload`, the swap prefixed by the pallet name and
+            /// the genesis hash.
+            ///
+            /// The origin must be signed, by any account.
+            ///
+            /// Parameters:
+            /// - `swap_id`: The swap to approve.
+            /// - `signer`: The public key of the party.
+            /// - `signature`: The signature of the swap payload.
+            ///
+            /// Emits `SwapApproved` event when successful, and `SwapSettled` event with the last
+            /// approval.
+            #[pallet::call_index(15)]
+            #[pallet::weight({
+                let (legs, nfts) = Pallet::<T>::max_swap_weight_components();
+                <T as pallet::Config>::WeightInfo::approve_swap_signed(legs, nfts)
+            })]
+            pub fn approve_swap_signed(origin: OriginFor<T>,
+                                       swap_id: u32,
+                                       signer: T::SigningPublicKey,
+                                       signature: T::OffchainSignature) -> DispatchResultWithPostInfo {
+                ensure_signed(origin)?;
+                let swap = Swaps::<T>::get(swap_id).ok_or(Error::<T>::SwapNotFound)?;
+                let party = signer.into_account();
+                ensure!(signature.verify(&Self::swap_payload(swap_id, &swap)[..], &party), Error::<T>::InvalidSignature);
+                let (legs, nfts) = Self::do_approve_swap(swap_id, party)?;
+                Ok(Some(<T as pallet::Config>::WeightInfo::approve_swap_signed(legs, nfts)).into())
+            }
+
+            /// Cancel a swap, the swap deposit is returned to the creator.
+            ///
+            /// The origin must be signed by the creator or a party, or by any account once the
+            /// deadline has passed.
+            ///
+            /// Parameters:
+            /// - `swap_id`: The swap to cancel.
+            ///
+            /// Emits `SwapCancelled` event when successful.
+            #[pallet::call_index(16)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_swap())]
+            pub fn cancel_swap(origin: OriginFor<T>, swap_id: u32) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let swap = Swaps::<T>::get(swap_id).ok_or(Error::<T>::SwapNotFound)?;
+                let expired = frame_system::Pallet::<T>::block_number() > swap.deadline;
+                let is_party = swap.creator == sender || swap.legs.iter().any(|leg| leg.from == sender || leg.to == sender);
+                ensure!(expired || is_party, Error::<T>::NotSwapParty);
+
+                Self::remove_swap(swap_id, &swap);
+
+                Self::deposit_event(Event::SwapCancelled(swap_id));
+                Ok(())
+            }
+
+            /// Bid on any NFT of a collection with the given attributes, the bid price is reserved until
+            /// the bid is filled, cancelled or expires.
+            ///
+            /// The origin must be signed.
+            ///
+            /// Parameters:
+            /// - `collection_id`: The collection to bid on.
+            /// - `share`: The share of the NFT to buy.
+            /// - `price`: The price paid for the share.
+            /// - `attributes`: The keys and values of the attributes set by the collection owner that
+            ///   the NFT must have.
+            /// - `expiry`: The last block the bid can be filled at, if any.
+            ///
+            /// Emits `CollectionBidPlaced` event when successful.
+            #[pallet::call_index(17)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::place_collection_bid().saturating_add(Self::expiry_spillover_weight()))]
+            pub fn place_collection_bid(origin: OriginFor<T>,
+                                        collection_id: T::CollectionId,
+                                        share: Share,
+                                        price: BalanceOf<T>,
+                                        attributes: BoundedVec<BidAttributeOf<T>, T::MaxBidAttributes>,
+                                        expiry: Option<BlockNumberFor<T>>) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                ensure!(T::Nfts::collection_owner(&collection_id).is_some(), Error::<T>::CollectionNotFound);
+                ensure!(!share.is_zero(), Error::<T>::ZeroShare);
+
+                T::Currency::reserve(&sender, price).map_err(|_| Error::<T>::InsufficientBalance)?;
+                let bid_id = NextCollectionBidId::<T>::get();
+                NextCollectionBidId::<T>::put(bid_id.wrapping_add(1));
+                Self::register_expiry(expiry, ExpiringEntry::CollectionBid(bid_id))?;
+                CollectionBids::<T>::insert(bid_id, CollectionBid {
+                    bidder: sender.clone(),
+                    collection_id,
+                    share,
+                    price,
+                    attributes,
+                    expiry,
+                });
+
+                Self::deposit_event(Event::CollectionBidPlaced(bid_id, sender, collection_id, share, price));
+                Ok(())
+            }
+
+            /// Cancel a collection-wide bid, the bid price is unreserved.
+            ///
+            /// The origin must be signed by the bidder.
+            ///
+            /// Parameters:
+            /// - `bid_id`: The bid to cancel.
+            ///
+            /// Emits `CollectionBidCancelled` event when successful.
+            #[pallet::call_index(18)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_collection_bid())]
+            pub fn cancel_collection_bid(origin: OriginFor<T>, bid_id: u32) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let bid = CollectionBids::<T>::get(bid_id).ok_or(Error::<T>::CollectionBidNotFound)?;
+                ensure!(bid.bidder == sender, Error::<T>::NotBidder);
+
+                T::Currency::unreserve(&bid.bidder, bid.price);
+                CollectionBids::<T>::remove(bid_id);
+
+                Self::deposit_event(Event::CollectionBidCancelled(bid_id));
+                Ok(())
+            }
+
+            /// Fill a collection-wide bid with an owned NFT having the attributes of the bid, the bidder
+            /// pays the bid price for the share.
+            ///
+            /// The origin must be signed by an owner of the NFT.
+            ///
+            /// Parameters:
+            /// - `bid_id`: The bid to fill.
+            /// - `item_id`: The NFT of the bid collection to sell.
+            ///
+            /// Emits `CollectionBidFilled` event when successful.
+            #[pallet::call_index(19)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::fill_collection_bid(T::MaxBidAttributes::get()))]
+            pub fn fill_collection_bid(origin: OriginFor<T>, bid_id: u32, item_id: T::ItemId) -> DispatchResult {
+                let sender = ensure_signed(origin)?;
+                let bid = CollectionBids::<T>::take(bid_id).ok_or(Error::<T>::CollectionBidNotFound)?;
+                Self::ensure_not_expired(bid.expiry, Error::<T>::CollectionBidExpired)?;
+                for (key, value) in bid.attributes.iter() {
+                    let attribute = T::Nfts::attribute(&bid.collection_id, &item_id, key);
+                    ensure!(attribute.as_deref() == Some(&value[..]), Error::<T>::AttributeMismatch);
+                }
+                let nft_item = (bid.collection_id, item_id);
+                let nft_item_with_share = (bid.collection_id, item_id, bid.share);
+                T::Nfts::can_transfer_share(&sender, nft_item, bid.share)?;
+
+                T::Currency::unreserve(&bid.bidder, bid.price);
+                Self::pay_with_royalty(None, &bid.bidder, &sender, nft_item_with_share, bid.price)?;
+                T::Nfts::transfer_share(&sender, &bid.bidder, nft_item, bid.share)?;
+
+                Self::deposit_event(Event::CollectionBidFilled(bid_id, sender, nft_item_with_share));
+                Ok(())
+            }
+
+            /// Set the market fee charged on the price of every sale.
+            ///
+            /// The origin must be root.
+            ///
+            /// Parameters:
+            /// - `fee_bps`: The fee in basis points.
+            ///
+            /// Emits `MarketFeeSet` event when successful.
+            #[pallet::call_index(20)]
+            #[pallet::weight(<T as pallet::Config>::WeightInfo::set_market_fee())]
+            pub fn set_market_fee(origin: OriginFor<T>, fee_bps: u16) -> DispatchResult {
+                ensure_root(origin)?;
+                ensure!(fee_bps <= T::MaxMarketFeeBps::get(), Error::<T>::FeeTooHigh);
+
+                MarketFeeBps::<T>::put(fee_bps);
+
+                Self::deposit_event(Event::MarketFeeSet(fee_bps));
+                Ok(())
+            }
+        }
+
+        impl<T: Config> Pallet<T> {
+            /// The account holding the escrowed NFTs.
+            pub fn account_id() -> T::AccountId {
+                T::PalletId::get().into_account_truncating()
+            }
+
+            /// Record the listed share of a listing or a Dutch auction.
+            pub(crate) fn add_listed_share(nft_item_with_share: NftItemWithShare<T>, seller: &T::AccountId) -> DispatchResult {
+                ListedShares::<T>::try_mutate((nft_item_with_share.0, nft_item_with_share.1), |shares| -> DispatchResult {
+                    let shares = shares.get_or_insert_with(BoundedVec::default);
+                    let listed_share = (seller.clone(), nft_item_with_share.2);
+                    if !shares.contains(&listed_share) {
+                        shares.try_push(listed_share).map_err(|_| Error::<T>::TooManyListings)?;
+                    }
+                    Ok(())
+                })?;
+                CollectionListings::<T>::insert(nft_item_with_share.0, (nft_item_with_share.1, nft_item_with_share.2, seller.clone()), ());
+                Ok(())
+            }
+
+            /// Forget the listed share of a removed listing or Dutch auction.
+            fn remove_listed_share(nft_item_with_share: NftItemWithShare<T>, seller: &T::AccountId) {
+                ListedShares::<T>::mutate_exists((nft_item_with_share.0, nft_item_with_share.1), |shares_wrap| {
+                    if let Some(shares) = shares_wrap {
+                        shares.retain(|(listed_seller, share)| listed_seller != seller || *share != nft_item_with_share.2);
+                        if shares.is_empty() {
+                            *shares_wrap = None;
+                        }
+                    }
+                });
+                CollectionListings::<T>::remove(nft_item_with_share.0, (nft_item_with_share.1, nft_item_with_share.2, seller.clone()));
+            }
+
+            /// Remove a listing or a Dutch auction which can no longer be sold, the escrow of its offers
+            /// is returned by the sweep of the next blocks.
+            fn invalidate_listing(nft_item_with_share: NftItemWithShare<T>, seller: T::AccountId) {
+                let listed = Listings::<T>::take(nft_item_with_share, &seller).is_some();
+                let auctioned = DutchAuctions::<T>::take(nft_item_with_share, &seller).is_some();
+                Self::remove_listed_share(nft_item_with_share, &seller);
+                if Offers::<T>::contains_key(nft_item_with_share, &seller) {
+                    // the offers stay until the buyers cancel them if the sweeps are full
+                    let now = frame_system::Pallet::<T>::block_number();
+                    let _ = Self::register_expiry(Some(now), ExpiringEntry::Offers(nft_item_with_share, seller.clone()));
+                }
+                if listed || auctioned {
+                    Self::deposit_event(Event::ListingInvalidated(seller, nft_item_with_share));
+                }
+            }
+
+            /// Drop the listings and Dutch auctions of `seller` of more than the share of the NFT they
+            /// still hold.
+            fn invalidate_unowned_listings(seller: &T::AccountId, nft_item: NftItemOf<T>) {
+                let Some(listed_shares) = ListedShares::<T>::get(nft_item) else { return };
+                let owned_share = T::Nfts::share_of(seller, nft_item);
+                for (_, share) in listed_shares.into_iter().filter(|(listed_seller, share)| listed_seller == seller && *share > owned_share) {
+                    Self::invalidate_listing((nft_item.0, nft_item.1, share), seller.clone());
+                }
+            }
+
+            /// Ensure the expiry block of a listing or an offer has not passed.
+            fn ensure_not_expired(expiry: Option<BlockNumberFor<T>>, error: Error<T>) -> DispatchResult {
+                if let Some(expiry) = expiry {
+                    ensure!(frame_system::Pallet::<T>::block_number() <= expiry, error);
+                }
+                Ok(())
+            }
+
+            /// Record a listing or an offer to sweep after its expiry block, or after one of the
+            /// next `MaxExpirySpillover` blocks if the sweep of the expiry block is full.
+            fn register_expiry(expiry: Option<BlockNumberFor<T>>, entry: ExpiringEntry<T>) -> DispatchResult {
+                let Some(expiry) = expiry else { return Ok(()) };
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(expiry >= now, Error::<T>::InvalidExpiry);
+                let sweep_block = Self::spillover_blocks(expiry)
+                    .find(|block| (ExpiringAt::<T>::decode_len(block).unwrap_or(0) as u32) < T::MaxExpiriesPerBlock::get())
+                    .ok_or(Error::<T>::TooManyExpiries)?;
+                ExpiringAt::<T>::try_append(sweep_block, entry).map_err(|_| Error::<T>::TooManyExpiries)?;
+                if !NextExpiryToSweep::<T>::exists() {
+                    NextExpiryToSweep::<T>::put(now);
+                }
+                Ok(())
+            }
+
+            /// The weight of releasing the offers of an expiring or dropped listing, on top of the sweep
+            /// weight of the entry.
+            fn release_weight(entry: &ExpiringEntry<T>) -> Weight {
+                match entry {
+                    ExpiringEntry::Listing(nft_item_with_share, seller) | ExpiringEntry::Offers(nft_item_with_share, seller) => {
+                        let offers_count = Offers::<T>::decode_len(nft_item_with_share, seller).unwrap_or(0) as u32;
+                        <T as pallet::Config>::WeightInfo::unlist_nft(offers_count).saturating_add(T::DbWeight::get().reads(1))
+                    },
+                    _ => Weight::zero(),
+                }
+            }
+
+            /// The weight of looking for a sweep block after a full expiry block, or for a settlement
+            /// block after a full end block.
+            fn expiry_spillover_weight() -> Weight {
+                T::DbWeight::get().reads(T::MaxExpirySpillover::get().into())
+            }
+
+            /// A block and the next `MaxExpirySpillover` blocks, in which the entries of the block are
+            /// recorded once the blocks before are full.
+            fn spillover_blocks(block: BlockNumberFor<T>) -> impl Iterator<Item = BlockNumberFor<T>> {
+                (0..=T::MaxExpirySpillover::get()).map(move |spillover| block.saturating_add(spillover.into()))
+            }
+
+            /// Queue an auction to settle at its end block, or at one of the next `MaxExpirySpillover`
+            /// blocks if the settlements of the end block are full.
+            fn queue_settlement(end_block: BlockNumberFor<T>, auction: (NftItemWithShare<T>, T::AccountId)) -> DispatchResult {
+                let settlement_block = Self::spillover_blocks(end_block)
+                    .find(|block| (AuctionsEndingAt::<T>::decode_len(block).unwrap_or(0) as u32) < T::MaxAuctionsPerBlock::get())
+                    .ok_or(Error::<T>::TooManyAuctionsEnding)?;
+                AuctionsEndingAt::<T>::try_append(settlement_block, auction).map_err(|_| Error::<T>::TooManyAuctionsEnding)?;
+                Ok(())
+            }
+
+            /// Remove the queued settlement of an auction, from its end block or the block it spilled
+            /// over to.
+            fn unqueue_settlement(end_block: BlockNumberFor<T>, auction: (NftItemWithShare<T>, T::AccountId)) {
+                let queued_block = Self::spillover_blocks(end_block)
+                    .find(|block| AuctionsEndingAt::<T>::get(block).contains(&auction));
+                if let Some(block) = queued_block {
+                    AuctionsEndingAt::<T>::mutate(block, |ending_auctions| {
+                        ending_auctions.retain(|ending_auction| *ending_auction != auction);
                     });
+                }
+            }
+
+            /// Remove an expired listing, an expired offer returning its reserved token amount and
+            /// escrowed NFT shares, or an expired collection-wide bid returning its bid price, and
+            /// release the offers of a dropped listing. Entries removed or replaced by ones expiring
+            /// later since they were recorded are skipped.
+            fn expire(entry: ExpiringEntry<T>, block: BlockNumberFor<T>) {
+                match entry {
+                    ExpiringEntry::Listing(nft_item_with_share, seller) => {
+                        let expired = Listings::<T>::get(nft_item_with_share, &seller)
+                            .map_or(false, |list_info| list_info.expiry.map_or(false, |expiry| expiry <= block));
+                        if expired {
+                            Listings::<T>::remove(nft_item_with_share, &seller);
+                            Self::remove_listed_share(nft_item_with_share, &seller);
+                            Self::release_offers(nft_item_with_share, &seller);
+                            Self::deposit_event(Event::ListingExpired(seller, nft_item_with_share));
+                        }
+                    },
+                    ExpiringEntry::Offers(nft_item_with_share, seller) => {
+                        // the offers follow the share listed again by the seller
+                        let relisted = Listings::<T>::contains_key(nft_item_with_share, &seller)
+                            || DutchAuctions::<T>::contains_key(nft_item_with_share, &seller);
+                        if !relisted {
+                            Self::release_offers(nft_item_with_share, &seller);
+                        }
+                    },
+                    ExpiringEntry::Offer(nft_item_with_share, seller, offer) => {
+                        // the offer stays until the buyer cancels it if it can not be released
+                        let result = with_storage_layer(|| -> DispatchResult {
+                            Offers::<T>::try_mutate(nft_item_with_share, &seller, |offer_items| -> DispatchResult {
+                                let offer_items_value = offer_items.as_mut().ok_or(Error::<T>::NotOffered)?;
+                                let index = offer_items_value.iter().position(|x| *x == offer).ok_or(Error::<T>::NotOffered)?;
+                                offer_items_value.remove(index);
+                                Ok(())
+                            })?;
+                            Self::unindex_offer(nft_item_with_share, &seller, &offer.buyer);
+                            Self::release_offer(&offer)
+                        });
+                        if result.is_ok() {
+                            Self::deposit_event(Event::OfferExpired(nft_item_with_share, seller, offer));
+                        }
+                    },
+                    ExpiringEntry::CollectionBid(bid_id) => {
+                        let expired_bid = CollectionBids::<T>::get(bid_id).filter(|bid| bid.expiry.map_or(false, |expiry| expiry <= block));
+                        if let Some(bid) = expired_bid {
+                            T::Currency::unreserve(&bid.bidder, bid.price);
+                            CollectionBids::<T>::remove(bid_id);
+                            Self::deposit_event(Event::CollectionBidExpired(bid_id));
+                        }
+                    },
+                }
+            }
+
+            /// Count an offer of `buyer` in the buyer and seller indexes.
+            pub(crate) fn index_offer(nft_item_with_share: NftItemWithShare<T>, seller: &T::AccountId, buyer: &T::AccountId) {
+                BuyerOffers::<T>::mutate(buyer, (nft_item_with_share, seller.clone()), |count| {
+                    *count = Some(count.unwrap_or_default() + 1);
+                });
+                SellerOffers::<T>::mutate(seller, nft_item_with_share, |count| {
+                    *count = Some(count.unwrap_or_default() + 1);
                 });
+            }
 
-                Self::deposit_event(Event::NftPriceUpdated(sender, nft_item_with_share));
+            /// Remove an offer of `buyer` from the buyer and seller indexes.
+            fn unindex_offer(nft_item_with_share: NftItemWithShare<T>, seller: &T::AccountId, buyer: &T::AccountId) {
+                BuyerOffers::<T>::mutate_exists(buyer, (nft_item_with_share, seller.clone()), |count| {
+                    *count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+                });
+                SellerOffers::<T>::mutate_exists(seller, nft_item_with_share, |count| {
+                    *count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+                });
+            }
 
+            /// Hold the native token amount or escrow the asset token amount, and escrow the NFT
+            /// shares of an offer.
+            pub(crate) fn escrow_offer(offer: &Offer<T>) -> DispatchResult {
+                match offer.asset {
+                    Some(asset) if !offer.token_amount.is_zero() => {
+                        T::Assets::transfer(asset, &offer.buyer, &Self::account_id(), offer.token_amount, Preservation::Expendable)
+                            .map_err(|_| Error::<T>::InsufficientBalance)?;
+                    },
+                    Some(_) => {},
+                    None => T::OfferHolds::hold(&HoldReason::Offer.into(), &offer.buyer, offer.token_amount)
+                        .map_err(|_| Error::<T>::InsufficientBalance)?,
+                }
+                for offered_nft_item in offer.offered_nfts.iter() {
+                    T::Nfts::transfer_share(&offer.buyer, &Self::account_id(), (offered_nft_item.0, offered_nft_item.1), offered_nft_item.2)?;
+                }
                 Ok(())
             }
+
+            /// Ensure every share and the token amount of an offer can be swapped, without changing the
+            /// storage.
+            fn ensure_offer_acceptable(seller: &T::AccountId,
+                                       nft_item_with_share: NftItemWithShare<T>,
+                                       offer: &Offer<T>) -> DispatchResult {
+                T::Nfts::can_transfer_share(seller, (nft_item_with_share.0, nft_item_with_share.1), nft_item_with_share.2)?;
+                for offered_nft_item in offer.offered_nfts.iter() {
+                    // the same NFT may be offered more than once
+                    let offered_share = offer.offered_nfts.iter()
+                        .filter(|nft| (nft.0, nft.1) == (offered_nft_item.0, offered_nft_item.1))
+                        .fold(Share::zero(), |total, nft| total.saturating_add(nft.2));
+                    T::Nfts::can_transfer_share(&Self::account_id(), (offered_nft_item.0, offered_nft_item.1), offered_share)?;
+                }
+                let escrowed_amount = match offer.asset {
+                    Some(asset) => T::Assets::balance(asset, &Self::account_id()),
+                    // only the offers of the buyer hold funds for this reason
+                    None => T::OfferHolds::balance_on_hold(&HoldReason::Offer.into(), &offer.buyer),
+                };
+                ensure!(escrowed_amount >= offer.token_amount, Error::<T>::InsufficientBalance);
+                Ok(())
+            }
+
+            /// Return the held or escrowed token amount and the escrowed NFT shares of an offer to the
+            /// buyer.
+            fn release_offer(offer: &Offer<T>) -> DispatchResult {
+                match offer.asset {
+                    Some(asset) if !offer.token_amount.is_zero() => {
+                        T::Assets::transfer(asset, &Self::account_id(), &offer.buyer, offer.token_amount, Preservation::Expendable)?;
+                    },
+                    Some(_) => {},
+                    None => {
+                        T::OfferHolds::release(&HoldReason::Offer.into(), &offer.buyer, offer.token_amount, Precision::BestEffort)?;
+                    },
+                }
+                for offered_nft_item in offer.offered_nfts.iter() {
+                    T::Nfts::transfer_share(&Self::account_id(), &offer.buyer, (offered_nft_item.0, offered_nft_item.1), offered_nft_item.2)?;
+                }
+                Ok(())
+            }
+
+            /// Return the escrow of every offer on a removed listing, the offers which can not be released
+            /// stay until the buyers cancel them.
+            fn release_offers(nft_item_with_share: NftItemWithShare<T>, seller: &T::AccountId) {
+                let Some(offers) = Offers::<T>::take(nft_item_with_share, seller) else { return };
+                let mut kept_offers = Vec::new();
+                for offer in offers {
+                    if with_storage_layer(|| Self::release_offer(&offer)).is_ok() {
+                        Self::unindex_offer(nft_item_with_share, seller, &offer.buyer);
+                        Self::deposit_event(Event::OfferReleased(nft_item_with_share, seller.clone(), offer));
+                    } else {
+                        kept_offers.push(offer);
+                    }
+                }
+                if !kept_offers.is_empty() {
+                    Offers::<T>::insert(nft_item_with_share, seller, BoundedVec::truncate_from(kept_offers));
+                }
+            }
+
+            /// Settle an ended auction, the highest bidder pays for the NFT share, the NFT share
+            /// goes back to the seller without bids.
+            fn settle_auction(nft_item_with_share: NftItemWithShare<T>, seller: T::AccountId) {
+                let Some(auction) = Auctions::<T>::get(nft_item_with_share, &seller) else { return };
+                let nft_item = (nft_item_with_share.0, nft_item_with_share.1);
+
+                let result = with_storage_layer(|| -> DispatchResult {
+                    let receiver = match &auction.highest_bid {
+                        Some((bidder, amount)) => {
+                            T::Currency::unreserve(bidder, *amount);
+                            Self::pay_with_royalty(None, bidder, &seller, nft_item_with_share, *amount)?;
+                            bidder.clone()
+                        },
+                        None => seller.clone(),
+                    };
+                    T::Nfts::transfer_share(&Self::account_id(), &receiver, nft_item, nft_item_with_share.2)
+                });
+
+                if result.is_ok() {
+                    Auctions::<T>::remove(nft_item_with_share, &seller);
+                    Self::deposit_event(Event::AuctionSettled(nft_item_with_share, seller, auction.highest_bid));
+                } else {
+                    if let Some((bidder, amount)) = &auction.highest_bid {
+                        T::Currency::unreserve(bidder, *amount);
+                    }
+                    // the NFT share stays in the ended auction if it can not be returned, e.g. while the
+                    // collection is frozen, and the seller reclaims it with `cancel_auction`
+                    if T::Nfts::transfer_share(&Self::account_id(), &seller, nft_item, nft_item_with_share.2).is_ok() {
+                        Auctions::<T>::remove(nft_item_with_share, &seller);
+                    } else {
+                        Auctions::<T>::insert(nft_item_with_share, &seller, AuctionInfo { highest_bid: None, ..auction });
+                    }
+                    Self::deposit_event(Event::AuctionSettlementFailed(nft_item_with_share, seller));
+                }
+            }
+
+            /// The unexpired listings and the started Dutch auctions of a collection priced in an
+            /// asset, or in the native currency without an asset, sorted by price. Looked up in the
+            /// collection index.
+            pub fn collection_listings(collection_id: T::CollectionId, asset: Option<T::AssetId>) -> Vec<MarketListingOf<T>> {
+                let mut listings: Vec<_> = CollectionListings::<T>::iter_key_prefix(collection_id)
+                    .filter_map(|(item_id, share, seller)| {
+                        let nft_item_with_share = (collection_id, item_id, share);
+                        let (price, listing_asset, expiry, dutch_auction) = match Listings::<T>::get(nft_item_with_share, &seller) {
+                            Some(list_info) => {
+                                Self::ensure_not_expired(list_info.expiry, Error::<T>::ListingExpired).ok()?;
+                                (list_info.price, list_info.asset, list_info.expiry, false)
+                            },
+                            None => {
+                                let dutch_auction = DutchAuctions::<T>::get(nft_item_with_share, &seller)?;
+                                (Self::dutch_auction_price(&dutch_auction).ok()?, None, None, true)
+                            },
+                        };
+                        (listing_asset == asset).then_some(api::MarketListing {
+                            collection_id,
+                            item_id,
+                            share,
+                            seller,
+                            price,
+                            asset: listing_asset,
+                            expiry,
+                            dutch_auction,
+                        })
+                    })
+                    .collect();
+                listings.sort_by(|a, b| a.price.cmp(&b.price));
+                listings
+            }
+
+            /// The offers received by a seller, looked up in the seller index.
+            pub fn seller_offers(seller: &T::AccountId) -> Vec<MarketOfferOf<T>> {
+                SellerOffers::<T>::iter_key_prefix(seller)
+                    .flat_map(|nft_item_with_share| {
+                        Offers::<T>::get(nft_item_with_share, seller).unwrap_or_default()
+                            .into_iter()
+                            .map(|offer| Self::market_offer(nft_item_with_share, seller, offer))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            }
+
+            /// The offers made by a buyer, looked up in the buyer index.
+            pub fn buyer_offers(buyer: &T::AccountId) -> Vec<MarketOfferOf<T>> {
+                BuyerOffers::<T>::iter_key_prefix(buyer)
+                    .flat_map(|(nft_item_with_share, seller)| {
+                        Offers::<T>::get(nft_item_with_share, &seller).unwrap_or_default()
+                            .into_iter()
+                            .filter(|offer| offer.buyer == *buyer)
+                            .map(|offer| Self::market_offer(nft_item_with_share, &seller, offer))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            }
+
+            /// The price of buying a listing or a Dutch auction now, with the market fee, the
+            /// creator royalty and the amount the seller receives.
+            pub fn buy_quote(nft_item_with_share: NftItemWithShare<T>, seller: &T::AccountId) -> Option<BuyQuoteOf<T>> {
+                let (price, asset) = match Listings::<T>::get(nft_item_with_share, seller) {
+                    Some(list_info) => {
+                        Self::ensure_not_expired(list_info.expiry, Error::<T>::ListingExpired).ok()?;
+                        (list_info.price, list_info.asset)
+                    },
+                    None => {
+                        let dutch_auction = DutchAuctions::<T>::get(nft_item_with_share, seller)?;
+                        (Self::dutch_auction_price(&dutch_auction).ok()?, None)
+                    },
+                };
+                let (market_fee, royalty, seller_amount) = Self::split_payment(asset, seller, nft_item_with_share, price);
+                Some(api::BuyQuote { price, asset, market_fee, royalty, seller_amount })
+            }
+
+            /// An offer as returned by the runtime API.
+            fn market_offer(nft_item_with_share: NftItemWithShare<T>, seller: &T::AccountId, offer: Offer<T>) -> MarketOfferOf<T> {
+                api::MarketOffer {
+                    collection_id: nft_item_with_share.0,
+                    item_id: nft_item_with_share.1,
+                    share: nft_item_with_share.2,
+                    seller: seller.clone(),
+                    buyer: offer.buyer,
+                    offered_nfts: offer.offered_nfts.into_inner(),
+                    token_amount: offer.token_amount,
+                    asset: offer.asset,
+                    expiry: offer.expiry,
+                }
+            }
+
+            /// The current price of a Dutch auction.
+            pub fn dutch_auction_price(dutch_auction: &DutchAuctionInfo<T>) -> Result<BalanceOf<T>, DispatchError> {
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(now >= dutch_auction.start_block, Error::<T>::DutchAuctionNotStarted);
+                Ok(T::DutchAuctionPrice::price(
+                    dutch_auction.start_price,
+                    dutch_auction.end_price,
+                    now.saturating_sub(dutch_auction.start_block),
+                    dutch_auction.duration,
+                ))
+            }
+
+            /// The payload a party signs off-chain to approve a swap.
+            pub fn swap_payload(swap_id: u32, swap: &SwapInfo<T>) -> Vec<u8> {
+                // the pallet name and the genesis hash keep a signature from being replayed in another
+                // pallet or on another chain
+                let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+                (<Self as PalletInfoAccess>::name().as_bytes(), genesis_hash, swap_id, &swap.legs, swap.deadline).encode()
+            }
+
+            /// Remove a settled or cancelled swap, and return the swap deposit to the creator.
+            fn remove_swap(swap_id: u32, swap: &SwapInfo<T>) {
+                Swaps::<T>::remove(swap_id);
+                if let Some(deposit) = SwapDeposits::<T>::take(swap_id) {
+                    T::Currency::unreserve(&swap.creator, deposit);
+                    ProposedSwaps::<T>::mutate_exists(&swap.creator, |count| {
+                        *count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+                    });
+                }
+            }
+
+            /// The number of legs and the number of NFTs of a swap, the components of the swap weights.
+            fn swap_weight_components(legs: &[SwapLeg<T>]) -> (u32, u32) {
+                let nfts = legs.iter().fold(0u32, |total, leg| total.saturating_add(leg.nfts.len() as u32));
+                (legs.len() as u32, nfts)
+            }
+
+            /// The components of the swap weights for the largest swap, the weight charged before the
+            /// approved swap is read.
+            fn max_swap_weight_components() -> (u32, u32) {
+                (T::MaxSwapLegs::get(), T::MaxSwapLegs::get().saturating_mul(T::MaxOfferNftsLength::get()))
+            }
+
+            /// Record the approval of a party, and settle the swap once every party approved it.
+            /// Returns the components of the swap weights of the approved swap.
+            fn do_approve_swap(swap_id: u32, party: T::AccountId) -> Result<(u32, u32), DispatchError> {
+                let mut swap = Swaps::<T>::get(swap_id).ok_or(Error::<T>::SwapNotFound)?;
+                let weight_components = Self::swap_weight_components(&swap.legs);
+                ensure!(frame_system::Pallet::<T>::block_number() <= swap.deadline, Error::<T>::SwapExpired);
+                ensure!(swap.legs.iter().any(|leg| leg.from == party), Error::<T>::NotSwapParty);
+                ensure!(!swap.approvals.contains(&party), Error::<T>::SwapAlreadyApproved);
+                // there are at most as many parties as legs
+                swap.approvals.try_push(party.clone()).map_err(|_| Error::<T>::NotSwapParty)?;
+                Self::deposit_event(Event::SwapApproved(swap_id, party));
+
+                if swap.legs.iter().all(|leg| swap.approvals.contains(&leg.from)) {
+                    Self::remove_swap(swap_id, &swap);
+                    Self::ensure_swap_settleable(&swap).map_err(|_| Error::<T>::SwapNotSettleable)?;
+                    with_storage_layer(|| Self::settle_swap(&swap)).map_err(|_| Error::<T>::SwapNotSettleable)?;
+                    Self::deposit_event(Event::SwapSettled(swap_id));
+                } else {
+                    Swaps::<T>::insert(swap_id, swap);
+                }
+                Ok(weight_components)
+            }
+
+            /// Ensure every party owns the NFT shares and the free balance it gives in a swap, without
+            /// changing the storage.
+            fn ensure_swap_settleable(swap: &SwapInfo<T>) -> DispatchResult {
+                for leg in swap.legs.iter() {
+                    for nft in leg.nfts.iter() {
+                        // a party may give the same NFT in several legs
+                        let given_share = swap.legs.iter()
+                            .filter(|other| other.from == leg.from)
+                            .flat_map(|other| other.nfts.iter())
+                            .filter(|other_nft| (other_nft.0, other_nft.1) == (nft.0, nft.1))
+                            .fold(Share::zero(), |total, other_nft| total.saturating_add(other_nft.2));
+                        T::Nfts::can_transfer_share(&leg.from, (nft.0, nft.1), given_share)?;
+                    }
+                    let given_amount = swap.legs.iter()
+                        .filter(|other| other.from == leg.from)
+                        .fold(BalanceOf::<T>::zero(), |total, other| total.saturating_add(other.token_amount));
+                    ensure!(T::Currency::free_balance(&leg.from) >= given_amount, Error::<T>::InsufficientBalance);
+                }
+                Ok(())
+            }
+
+            /// Move the NFT shares and tokens of every leg of a swap.
+            fn settle_swap(swap: &SwapInfo<T>) -> DispatchResult {
+                for leg in swap.legs.iter() {
+                    for nft in leg.nfts.iter() {
+                        T::Nfts::transfer_share(&leg.from, &leg.to, (nft.0, nft.1), nft.2)?;
+                    }
+                    if !leg.token_amount.is_zero() {
+                        T::Currency::transfer(&leg.from, &leg.to, leg.token_amount, frame_support::traits::ExistenceRequirement::AllowDeath)?;
+                    }
+                }
+                Ok(())
+            }
+
+            /// Ensure the asset exists, the native currency always does.
+            fn ensure_asset_exists(asset: Option<T::AssetId>) -> DispatchResult {
+                if let Some(asset) = asset {
+                    ensure!(T::Assets::asset_exists(asset), Error::<T>::UnknownAsset);
+                }
+                Ok(())
+            }
+
+            /// The free balance of an asset, or of the native currency without an asset.
+            fn token_balance(asset: Option<T::AssetId>, who: &T::AccountId) -> BalanceOf<T> {
+                match asset {
+                    Some(asset) => T::Assets::balance(asset, who),
+                    None => T::Currency::free_balance(who),
+                }
+            }
+
+            /// Transfer tokens of an asset, or of the native currency without an asset.
+            fn transfer_tokens(asset: Option<T::AssetId>,
+                               from: &T::AccountId,
+                               to: &T::AccountId,
+                               amount: BalanceOf<T>) -> DispatchResult {
+                match asset {
+                    Some(asset) => T::Assets::transfer(asset, from, to, amount, Preservation::Expendable).map(|_| ()),
+                    None => T::Currency::transfer(from, to, amount, ExistenceRequirement::AllowDeath),
+                }
+            }
+
+            /// Whether `who` holds at least the minimum balance of an asset, or of the native currency
+            /// without an asset, after receiving `amount`.
+            fn can_receive(asset: Option<T::AssetId>, who: &T::AccountId, amount: BalanceOf<T>) -> bool {
+                let (balance, minimum_balance) = match asset {
+                    Some(asset) => (T::Assets::balance(asset, who), T::Assets::minimum_balance(asset)),
+                    None => (T::Currency::total_balance(who), T::Currency::minimum_balance()),
+                };
+                balance.saturating_add(amount) >= minimum_balance
+            }
+
+            /// Pay for a sold NFT in an asset or the native currency, the market fee goes to the fee
+            /// destination, the creator royalty of the NFT goes to its recipient and the rest to the
+            /// seller.
+            fn pay_with_royalty(asset: Option<T::AssetId>,
+                                buyer: &T::AccountId,
+                                seller: &T::AccountId,
+                                nft_item_with_share: NftItemWithShare<T>,
+                                amount: BalanceOf<T>) -> DispatchResult {
+                let (fee_amount, royalty, seller_amount) = Self::split_payment(asset, seller, nft_item_with_share, amount);
+                if fee_amount > BalanceOf::<T>::zero() {
+                    Self::transfer_tokens(asset, buyer, &T::FeeDestination::get(), fee_amount)?;
+                    Self::deposit_event(Event::MarketFeePaid(nft_item_with_share, fee_amount));
+                }
+                if let Some((recipient, royalty_amount)) = royalty {
+                    Self::transfer_tokens(asset, buyer, &recipient, royalty_amount)?;
+                    Self::deposit_event(Event::RoyaltyPaid(nft_item_with_share, recipient, royalty_amount));
+                }
+
+                Self::transfer_tokens(asset, buyer, seller, seller_amount)
+            }
+
+            /// Split the price of a sold NFT into the market fee, the creator royalty and the amount
+            /// the seller receives. No fee is charged when it would leave the fee destination below
+            /// the minimum balance, and the royalty goes to the seller when it would leave the royalty
+            /// recipient below the minimum balance.
+            fn split_payment(asset: Option<T::AssetId>,
+                             seller: &T::AccountId,
+                             nft_item_with_share: NftItemWithShare<T>,
+                             amount: BalanceOf<T>) -> (BalanceOf<T>, Option<(T::AccountId, BalanceOf<T>)>, BalanceOf<T>) {
+                let mut fee_amount = Permill::from_parts(MarketFeeBps::<T>::get() as u32 * 100) * amount;
+                if !Self::can_receive(asset, &T::FeeDestination::get(), fee_amount) {
+                    fee_amount = BalanceOf::<T>::zero();
+                }
+                let mut seller_amount = amount.saturating_sub(fee_amount);
+
+                let royalty = T::Nfts::royalty((nft_item_with_share.0, nft_item_with_share.1)).and_then(|(recipient, bps)| {
+                    // the fee and the royalty together can not exceed the price
+                    let royalty_amount = (Permill::from_parts(bps as u32 * 100) * amount).min(seller_amount);
+                    let payable = royalty_amount > BalanceOf::<T>::zero()
+                        && recipient != *seller
+                        && Self::can_receive(asset, &recipient, royalty_amount);
+                    payable.then_some((recipient, royalty_amount))
+                });
+                if let Some((_, royalty_amount)) = &royalty {
+                    seller_amount -= *royalty_amount;
+                }
+
+                (fee_amount, royalty, seller_amount)
+            }
+        }
+
+        /// Drops the listings of the moved and changed NFTs when the market trades `pallet_nft` NFTs.
+        impl<T: Config<CollectionId = sp_core::H256, ItemId = u32>> pallet_nft::OnNftTransfer<T::AccountId> for Pallet<T> {
+            fn on_transfer(from: &T::AccountId, _to: &T::AccountId, nft_item: NftItemOf<T>, _share: Share) {
+                Self::invalidate_unowned_listings(from, nft_item);
+            }
+
+            fn on_burn(owner: &T::AccountId, nft_item: NftItemOf<T>, _share: Share) {
+                Self::invalidate_unowned_listings(owner, nft_item);
+            }
+
+            fn on_mutate(nft_item: NftItemOf<T>) {
+                let Some(listed_shares) = ListedShares::<T>::take(nft_item) else { return };
+                for (seller, share) in listed_shares {
+                    Self::invalidate_listing((nft_item.0, nft_item.1, share), seller);
+                }
+            }
+
+            fn max_weight() -> Weight {
+                // every listing and Dutch auction of the NFT is dropped, its offers queued for release
+                let invalidate_weight = T::DbWeight::get().reads_writes(8, 6).saturating_add(Self::expiry_spillover_weight());
+                T::DbWeight::get()
+                    .reads_writes(2, 1)
+                    .saturating_add(invalidate_weight.saturating_mul(T::MaxListingsPerNft::get().into()))
+            }
         }
 }
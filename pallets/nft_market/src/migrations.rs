@@ -0,0 +1,175 @@
+/// Migration to the listings and offers of fungible shares, with an expiry block.
+pub mod v1 {
+    use crate::*;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        storage::with_storage_layer,
+        traits::OnRuntimeUpgrade,
+    };
+    use sp_core::H256;
+    use sp_std::{marker::PhantomData, vec::Vec};
+    #[cfg(feature = "try-runtime")]
+    use frame_support::sp_runtime::TryRuntimeError;
+
+    /// The storage layout before the fungible shares, the listings and offers had no expiry block
+    /// and the offers were not escrowed.
+    pub mod v0 {
+        use super::*;
+        use frame_support::storage_alias;
+
+        /// An NFT with a share as a percentage.
+        pub type NftItemWithPercentage = (H256, u32, u8);
+
+        /// An offer without an expiry block.
+        #[derive(Encode, Decode)]
+        pub struct Offer<T: Config> {
+            pub offered_nfts: BoundedVec<NftItemWithPercentage, T::MaxOfferNftsLength>,
+            pub token_amount: BalanceOf<T>,
+            pub buyer: T::AccountId,
+        }
+
+        /// A listing without an expiry block.
+        #[derive(Encode, Decode)]
+        pub struct ListInfo<T: Config> {
+            pub owner: T::AccountId,
+            pub price: BalanceOf<T>,
+        }
+
+        /// The listings, keyed by the NFT and its percentage share.
+        #[storage_alias]
+        pub type Listings<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Twox64Concat,
+            NftItemWithPercentage,
+            Twox64Concat,
+            <T as frame_system::Config>::AccountId,
+            ListInfo<T>,
+        >;
+
+        /// The offers, keyed by the NFT and its percentage share.
+        #[storage_alias]
+        pub type Offers<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Twox64Concat,
+            NftItemWithPercentage,
+            Twox64Concat,
+            <T as frame_system::Config>::AccountId,
+            BoundedVec<Offer<T>, <T as Config>::MaxOffersLength>,
+        >;
+    }
+
+    /// The fungible share of a percentage share, the NFTs minted before the fungible shares have a
+    /// share supply of 100, see the `pallet_nft` v2 migration.
+    fn share_of_percentage((collection_id, item_id, percentage): v0::NftItemWithPercentage) -> (H256, u32, Share) {
+        (collection_id, item_id, percentage as Share)
+    }
+
+    /// Re-key the listings and offers by the fungible shares, add an empty expiry block and asset to
+    /// every listing and offer, index the listed shares and the listings by collection, index the
+    /// offers by buyer and seller, and escrow the offers. The listings whose share can not be indexed are dropped together with their offers,
+    /// and so are the offers which can not be escrowed any more.
+    ///
+    /// Must run after the `pallet_nft` migrations, which turn the percentage shares into fungible
+    /// shares. The old and the new storage share the same prefix, so the old entries are drained
+    /// before the new ones are written. The old storage only held `pallet_nft` NFTs.
+    pub struct InnerMigrateV0ToV1<T: Config>(PhantomData<T>);
+
+    impl<T: Config<CollectionId = H256, ItemId = u32>> OnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+
+            let listings: Vec<_> = v0::Listings::<T>::drain().collect();
+            for (nft_item_with_percentage, seller, list_info) in listings {
+                reads += 2;
+                writes += 3;
+                let nft_item_with_share = share_of_percentage(nft_item_with_percentage);
+                // index the listed shares, so that the listing is removed when the seller moves the NFT,
+                // a listing which can not be indexed would outlive the NFT moving and is dropped
+                if Pallet::<T>::add_listed_share(nft_item_with_share, &seller).is_err() {
+                    continue;
+                }
+                Listings::<T>::insert(nft_item_with_share, &seller, ListInfo {
+                    owner: list_info.owner,
+                    price: list_info.price,
+                    expiry: None,
+                    asset: None,
+                });
+            }
+
+            let offers: Vec<_> = v0::Offers::<T>::drain().collect();
+            for (nft_item_with_percentage, seller, offers) in offers {
+                reads += 1;
+                writes += 1;
+                let nft_item_with_share = share_of_percentage(nft_item_with_percentage);
+                // the offers were not escrowed yet, the ones on a dropped listing are dropped as well
+                if !Listings::<T>::contains_key(nft_item_with_share, &seller) {
+                    continue;
+                }
+                let offers: Vec<_> = offers.into_iter()
+                    .map(|offer| Offer {
+                        offered_nfts: BoundedVec::truncate_from(
+                            offer.offered_nfts.into_iter().map(share_of_percentage).collect::<Vec<_>>(),
+                        ),
+                        token_amount: offer.token_amount,
+                        buyer: offer.buyer,
+                        expiry: None,
+                        asset: None,
+                    })
+                    .filter(|offer| {
+                        reads += 2 + offer.offered_nfts.len() as u64;
+                        writes += 2 + 2 * offer.offered_nfts.len() as u64;
+                        with_storage_layer(|| Pallet::<T>::escrow_offer(offer)).is_ok()
+                    })
+                    .collect();
+                for offer in offers.iter() {
+                    // index the offers by buyer and seller
+                    reads += 2;
+                    writes += 2;
+                    Pallet::<T>::index_offer(nft_item_with_share, &seller, &offer.buyer);
+                }
+                if !offers.is_empty() {
+                    Offers::<T>::insert(nft_item_with_share, &seller, BoundedVec::truncate_from(offers));
+                }
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let listings_count = v0::Listings::<T>::iter_keys().count() as u32;
+            let offers_count = v0::Offers::<T>::iter_keys().count() as u32;
+            Ok((listings_count, offers_count).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (listings_count, offers_count): (u32, u32) =
+                Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre-upgrade state")?;
+            // the listings which can not be indexed are dropped
+            ensure!(Listings::<T>::iter().count() as u32 <= listings_count, "Listings count mismatch");
+            // the offers which can not be escrowed are dropped
+            ensure!(Offers::<T>::iter().count() as u32 <= offers_count, "Offers count mismatch");
+            ensure!(
+                CollectionListings::<T>::iter_keys().count() == Listings::<T>::iter_keys().count(),
+                "Collection listings count mismatch"
+            );
+            for (seller, nft_item_with_share, count) in SellerOffers::<T>::iter() {
+                let offers_count = Offers::<T>::decode_len(nft_item_with_share, &seller).unwrap_or(0) as u32;
+                ensure!(count == offers_count, "Seller offers count mismatch");
+            }
+            Ok(())
+        }
+    }
+
+    /// Migrate the listing and offer storage from version 0 to version 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,9 +1,13 @@
 use crate as pallet_nft_market;
 use frame_support::{
     derive_impl,
-    traits::ConstU32,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64},
+    PalletId,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
 use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
@@ -16,6 +20,7 @@ frame_support::construct_runtime!(
     pub enum Test {
         System: frame_system,
         Balances: pallet_balances,
+        Assets: pallet_assets,
         NftModule: pallet_nft,
         NftMarketModule: pallet_nft_market,
     }
@@ -42,7 +47,9 @@ impl frame_system::Config for Test {
 }
 
 frame_support::parameter_types! {
-	pub const ExistentialDeposit: Balance = 1;
+	pub static ExistentialDeposit: Balance = 1;
+	pub const NftMarketPalletId: PalletId = PalletId(*b"py/nftmk");
+	pub const MarketFeeDestination: u64 = 99;
 }
 
 impl pallet_balances::Config for Test {
@@ -57,19 +64,82 @@ impl pallet_balances::Config for Test {
     type WeightInfo = ();
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
 }
 
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type ForceOrigin = EnsureRoot<u64>;
+    type AssetDeposit = ConstU128<1>;
+    type AssetAccountDeposit = ConstU128<1>;
+    type MetadataDepositBase = ConstU128<1>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type ApprovalDeposit = ConstU128<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 impl pallet_nft::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type DefaultShareSupply = ConstU128<100>;
+    type OnNftTransfer = NftMarketModule;
+    type MaxSubNftsLength = ConstU32<4>;
+    type MaxMetadataLength = ConstU32<8>;
+    type MaxCollectionsLength = ConstU32<3>;
+    type Currency = Balances;
+    type CollectionDeposit = ConstU128<0>;
+    type ItemDeposit = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type AttributeDepositBase = ConstU128<1>;
+    type DepositPerByte = ConstU128<1>;
+    type MaxAttributeKeyLength = ConstU32<8>;
+    type MaxAttributeValueLength = ConstU32<8>;
     type WeightInfo = ();
 }
 
 impl pallet_nft_market::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
+    type OfferHolds = Balances;
+    type CollectionId = H256;
+    type ItemId = u32;
+    type Nfts = NftModule;
+    type AssetId = u32;
+    type Assets = Assets;
+    type PalletId = NftMarketPalletId;
+    type FeeDestination = MarketFeeDestination;
+    type MaxMarketFeeBps = ConstU16<1_000>;
+    type MaxOfferNftsLength = ConstU32<2>;
+    type MaxOffersLength = ConstU32<2>;
+    type AuctionExtensionPeriod = ConstU64<3>;
+    type MaxAuctionsPerBlock = ConstU32<2>;
+    type MaxExpiriesPerBlock = ConstU32<2>;
+    type MaxExpirySpillover = ConstU32<2>;
+    type MaxListingsPerNft = ConstU32<4>;
+    type DutchAuctionPrice = pallet_nft_market::LinearDecline;
+    type MaxBidAttributes = ConstU32<2>;
+    type MaxBidAttributeLength = ConstU32<8>;
+    type MaxSwapLegs = ConstU32<3>;
+    type SwapDeposit = ConstU128<10>;
+    type MaxSwapsPerAccount = ConstU32<2>;
+    type OffchainSignature = TestSignature;
+    type SigningPublicKey = UintAuthorityId;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = NftModule;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,12 +1,38 @@
 use super::*;
 use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, traits::{Get, Hooks}, weights::Weight, BoundedVec};
 use sp_core::H256;
-use sp_core::hashing::blake2_256;
-use pallet_nft::{NFTOwners, OwnedNFTs};
+use pallet_nft::{NFTCollectionIds, NFTOwners, OwnedNFTs, RoyaltyInfo};
 
 type AccountId = <Test as frame_system::Config>::AccountId;
 
+fn owned_nfts(account_id: AccountId) -> Vec<(H256, u32, Share)> {
+    let mut nfts: Vec<_> = OwnedNFTs::<Test>::iter_prefix(account_id)
+        .map(|(nft_item, share)| (nft_item.0, nft_item.1, share))
+        .collect();
+    nfts.sort();
+    nfts
+}
+
+fn last_collection_id() -> H256 {
+    *NFTCollectionIds::<Test>::get().unwrap().last().unwrap()
+}
+
+fn nft_owners(nft_item: (H256, u32)) -> Vec<AccountId> {
+    let mut owners: Vec<_> = NFTOwners::<Test>::iter_key_prefix(nft_item).collect();
+    owners.sort();
+    owners
+}
+
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let block_number = System::block_number() + 1;
+        System::set_block_number(block_number);
+        NftMarketModule::on_initialize(block_number);
+    }
+}
+
 #[test]
 fn list_nft() {
     new_test_ext().execute_with(|| {
@@ -15,7 +41,7 @@ fn list_nft() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
 
@@ -23,9 +49,11 @@ fn list_nft() {
         let list_info = ListInfo {
             price,
             owner: account_id.clone(),
+            expiry: None,
+            asset: None,
         };
         let share = 40;
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id), (collection_id, 0, share), price));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id), (collection_id, 0, share), price, None, None));
         assert_eq!(Listings::<Test>::get((collection_id, 0, share), account_id), Some(list_info));
     })
 }
@@ -39,18 +67,18 @@ fn list_nft_fail_when_not_owner() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
 
         let share = 100;
         assert_noop!(
-            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), 0),
+            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), 0, None, None),
             Error::<Test>::NotOwner
         );
 
         assert_noop!(
-            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 1, share), 0),
+            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 1, share), 0, None, None),
             Error::<Test>::NFTNotFound
         );
     })
@@ -64,7 +92,7 @@ fn unlist_nft() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
 
@@ -74,9 +102,11 @@ fn unlist_nft() {
         let list_info = ListInfo {
             price,
             owner: account_id.clone(),
+            expiry: None,
+            asset: None,
         };
         let nft_item_with_share = (nft_item.0, nft_item.1, share);
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id), nft_item_with_share, price));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id), nft_item_with_share, price, None, None));
         assert_eq!(Listings::<Test>::get(nft_item_with_share, account_id.clone()), Some(list_info));
         assert_ok!(NftMarketModule::unlist_nft(RuntimeOrigin::signed(account_id), nft_item_with_share));
         assert_eq!(Listings::<Test>::get(nft_item_with_share, account_id.clone()), None);
@@ -92,23 +122,26 @@ fn place_offer() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         let share = 100;
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
 
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
         let token_amount: u128 = 10;
         let placed_share = 40;
         let offer_nfts = BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
-        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts, token_amount, account_id0));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts, token_amount, account_id0, None, None));
 
         let offered_nfts_boundedvec = BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
         let offer = Offer {
             offered_nfts: offered_nfts_boundedvec,
             token_amount,
             buyer: account_id1.clone(),
+            expiry: None,
+            asset: None,
         };
         let offered_boundedvec = BoundedVec::try_from(vec![offer]).unwrap();
         assert_eq!(Offers::<Test>::get((collection_id, 0, share), account_id0), Some(offered_boundedvec));
@@ -124,36 +157,37 @@ fn place_offer_fail_with_wrong_nft() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
         let share = 10;
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
 
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 2), 100));
 
         // account0: nft0,1
         // account1: nft2
         let token_amount: u128 = 0;
         let placed_share = 40;
         let offer_nfts_id2 = BoundedVec::try_from(vec![(collection_id, 2, placed_share)]).unwrap();
-        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id2.clone(), token_amount, account_id0));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id2.clone(), token_amount, account_id0, None, None));
 
         assert_noop!(
-            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 1, share), offer_nfts_id2.clone(), token_amount, account_id0),
+            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 1, share), offer_nfts_id2.clone(), token_amount, account_id0, None, None),
             Error::<Test>::NotListed
         );
 
         let offer_nfts_id3 = BoundedVec::try_from(vec![(collection_id, 3, placed_share)]).unwrap();
         assert_noop!(
-            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id3, token_amount, account_id0),
+            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id3, token_amount, account_id0, None, None),
             Error::<Test>::NFTNotFound
         );
 
         let offer_nfts_id1 = BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
         assert_noop!(
-            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id1, token_amount, account_id0),
+            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id1, token_amount, account_id0, None, None),
             Error::<Test>::NotOwner
         );
     })
@@ -168,13 +202,14 @@ fn place_offer_fail_when_not_enough_share() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
         let share = 10;
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
 
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
 
         // account0: nft0
         // account1: nft1
@@ -185,7 +220,7 @@ fn place_offer_fail_when_not_enough_share() {
         let token_amount: u128 = 0;
         let offer_nfts_id1 = BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
         assert_noop!(
-            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id1, token_amount, account_id0),
+            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id1, token_amount, account_id0, None, None),
             Error::<Test>::ShareNotEnough
         );
     })
@@ -200,18 +235,19 @@ fn cancel_offer() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
         let share = 100;
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
 
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
         let placed_share = 40;
         let token_amount: u128 = 0;
         let offer_nfts= BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
-        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0));
-        assert_ok!(NftMarketModule::cancel_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0, None, None));
+        assert_ok!(NftMarketModule::cancel_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0, None));
 
         let offered_boundedvec = BoundedVec::default();
         assert_eq!(Offers::<Test>::get((collection_id, 0, share), account_id0), Some(offered_boundedvec));
@@ -227,33 +263,34 @@ fn accept_offer() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         let share = 100;
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
 
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
         let token_amount: u128 = 20;
         let placed_share = 80;
         let offer_nfts= BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
-        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0));
-        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id1));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0, None, None));
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id1, None));
 
-        let nft0_owners = BoundedVec::try_from(vec![account_id1]).unwrap();
-        let nft1_owners = BoundedVec::try_from(vec![account_id1, account_id0]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(nft0_owners));
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 1)), Some(nft1_owners));
+        let nft0_owners = vec![account_id1];
+        let nft1_owners = vec![account_id0, account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), nft0_owners);
+        assert_eq!(nft_owners((collection_id, 1)), nft1_owners);
 
-        let account0_owned_nfts = BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
-        let account1_owned_nfts = BoundedVec::try_from(vec![(collection_id, 1, 100 - placed_share), (collection_id, 0, share)]).unwrap();
-        assert_eq!(OwnedNFTs::<Test>::get(account_id0), Some(account0_owned_nfts));
-        assert_eq!(OwnedNFTs::<Test>::get(account_id1), Some(account1_owned_nfts));
+        let account0_owned_nfts = vec![(collection_id, 1, placed_share)];
+        let account1_owned_nfts = vec![(collection_id, 0, share), (collection_id, 1, 100 - placed_share)];
+        assert_eq!(owned_nfts(account_id0), account0_owned_nfts);
+        assert_eq!(owned_nfts(account_id1), account1_owned_nfts);
     })
 }
 
 #[test]
-fn accept_offer_fail_with_insufficient_balance() {
+fn place_offer_fail_with_insufficient_balance() {
     new_test_ext().execute_with(|| {
         let account_id0: AccountId = 1;
         let account_id1: AccountId = 2;
@@ -261,26 +298,26 @@ fn accept_offer_fail_with_insufficient_balance() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         let share = 100;
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
 
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
         let token_amount: u128 = 200000000;
         let placed_share = 100;
         let offer_nfts= BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
-        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0));
         assert_noop!(
-            NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id1),
+            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0, None, None),
             Error::<Test>::InsufficientBalance
         );
 
-        let nft0_owners = BoundedVec::try_from(vec![account_id0]).unwrap();
-        let nft1_owners = BoundedVec::try_from(vec![account_id1]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(nft0_owners));
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 1)), Some(nft1_owners));
+        let nft0_owners = vec![account_id0];
+        let nft1_owners = vec![account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), nft0_owners);
+        assert_eq!(nft_owners((collection_id, 1)), nft1_owners);
     })
 }
 
@@ -293,36 +330,41 @@ fn reject_offer() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         let share = 100;
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
 
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone())); // id1
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone())); // id2
-        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone())); // id3
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone())); // id1
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone())); // id2
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 2), 100));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone())); // id3
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 3), 100));
         let token_amount: u128 = 0;
         let placed_share = 100;
         let offer_nfts_id1 = BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
-        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id1.clone(), token_amount, account_id0));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id1.clone(), token_amount, account_id0, None, None));
         let offer_nfts_id23 = BoundedVec::try_from(vec![(collection_id, 2, placed_share), (collection_id, 3, placed_share)]).unwrap();
-        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id23, token_amount, account_id0));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts_id23, token_amount, account_id0, None, None));
 
-        assert_ok!(NftMarketModule::reject_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts_id1.clone(), token_amount, account_id1));
+        assert_ok!(NftMarketModule::reject_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts_id1.clone(), token_amount, account_id1, None));
 
         let offered_nfts_boundedvec = BoundedVec::try_from(vec![(collection_id, 2, placed_share), (collection_id, 3, placed_share)]).unwrap();
         let offer = Offer {
             offered_nfts: offered_nfts_boundedvec,
             token_amount,
-            buyer: account_id1
+            buyer: account_id1,
+            expiry: None,
+            asset: None,
         };
         let offered_boundedvec = BoundedVec::try_from(vec![offer]).unwrap();
         assert_eq!(Offers::<Test>::get((collection_id, 0, share), account_id0), Some(offered_boundedvec));
-        let nft0_owners = BoundedVec::try_from(vec![account_id0]).unwrap();
-        let nft1_owners = BoundedVec::try_from(vec![account_id1]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(nft0_owners));
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 1)), Some(nft1_owners));
+        let nft0_owners = vec![account_id0];
+        let nft1_owners = vec![account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), nft0_owners);
+        assert_eq!(nft_owners((collection_id, 1)), nft1_owners);
     })
 }
 
@@ -335,19 +377,18 @@ fn buy_nft() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
         let share = 100;
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 200000));
-        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), account_id0));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 200000, None, None));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), account_id0, 200000));
 
-        let nft0_owners = BoundedVec::try_from(vec![account_id1]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(nft0_owners));
-        let account0_owned_nfts = BoundedVec::default();
-        let account1_owned_nfts = BoundedVec::try_from(vec![(collection_id, 0, share)]).unwrap();
-        assert_eq!(OwnedNFTs::<Test>::get(account_id0), Some(account0_owned_nfts));
-        assert_eq!(OwnedNFTs::<Test>::get(account_id1), Some(account1_owned_nfts));
+        let nft0_owners = vec![account_id1];
+        assert_eq!(nft_owners((collection_id, 0)), nft0_owners);
+        let account1_owned_nfts = vec![(collection_id, 0, share)];
+        assert!(owned_nfts(account_id0).is_empty());
+        assert_eq!(owned_nfts(account_id1), account1_owned_nfts);
     })
 }
 
@@ -360,19 +401,19 @@ fn buy_nft_fail_when_insufficient_balance() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
 
         let share = 10;
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 2000000));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 2000000, None, None));
         assert_noop!(
-            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), account_id0),
+            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), account_id0, 2000000),
             Error::<Test>::InsufficientBalance
         );
 
-        let nft0_owners = BoundedVec::try_from(vec![account_id0]).unwrap();
-        assert_eq!(NFTOwners::<Test>::get((collection_id, 0)), Some(nft0_owners));
+        let nft0_owners = vec![account_id0];
+        assert_eq!(nft_owners((collection_id, 0)), nft0_owners);
     })
 }
 
@@ -384,7 +425,7 @@ fn update_list() {
         let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
         assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id), max_items, metainfo.clone()));
         
-        let collection_id = H256::from_slice(&blake2_256(&metainfo.clone()));
+        let collection_id = last_collection_id();
         let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
         assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id), collection_id, metainfo1.clone()));
 
@@ -393,12 +434,1505 @@ fn update_list() {
         let updated_list_info = ListInfo {
             price: new_price,
             owner: account_id.clone(),
+            expiry: None,
+            asset: None,
         };
         let share = 40;
-        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id), (collection_id, 0, share), price));
+        assert_noop!(
+            NftMarketModule::update_list_price(RuntimeOrigin::signed(account_id), (collection_id, 0, share), new_price),
+            Error::<Test>::NotListed
+        );
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id), (collection_id, 0, share), price, None, None));
         assert_ok!(NftMarketModule::update_list_price(RuntimeOrigin::signed(account_id), (collection_id, 0, share), new_price));
         assert_eq!(Listings::<Test>::get((collection_id, 0, share), account_id), Some(updated_list_info));
     })
 }
 
 
+
+#[test]
+fn place_offer_up_to_max_offers_length() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let share = 100;
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+
+        let max_offer_nfts = <Test as Config>::MaxOfferNftsLength::get();
+        for item_id in 1..=max_offer_nfts {
+            assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+            assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, item_id), 100));
+        }
+        let placed_share = 10;
+        let offered_nfts: Vec<(H256, u32, Share)> = (1..=max_offer_nfts).map(|item_id| (collection_id, item_id, placed_share)).collect();
+        assert!(BoundedVec::<(H256, u32, Share), <Test as Config>::MaxOfferNftsLength>::try_from(
+            [offered_nfts.clone(), vec![(collection_id, 0, placed_share)]].concat()
+        ).is_err());
+
+        let offer_nfts: BoundedVec<(H256, u32, Share), <Test as Config>::MaxOfferNftsLength> = BoundedVec::try_from(offered_nfts).unwrap();
+        let max_offers = <Test as Config>::MaxOffersLength::get();
+        for token_amount in 0..max_offers {
+            assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount as u128, account_id0, None, None));
+        }
+        assert_eq!(Offers::<Test>::get((collection_id, 0, share), account_id0).unwrap().len() as u32, max_offers);
+    })
+}
+
+#[test]
+fn buy_nft_pays_royalty() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let creator: AccountId = 0;
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(creator), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(creator), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::set_collection_royalty(RuntimeOrigin::signed(creator), collection_id, Some(RoyaltyInfo { recipient: creator, bps: 500 })));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(creator), account_id0, (collection_id, 0), 100));
+
+        let share = 100;
+        let price = 200000;
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), price, None, None));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), account_id0, price));
+        System::assert_has_event(Event::RoyaltyPaid((collection_id, 0, share), creator, 10000).into());
+
+        assert_eq!(Balances::free_balance(creator), 1000000 + 10000);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 + price - 10000);
+        assert_eq!(Balances::free_balance(account_id1), 1000000 - price);
+    })
+}
+
+#[test]
+fn accept_offer_pays_nft_royalty() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let creator: AccountId = 0;
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(creator), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(creator), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::set_collection_royalty(RuntimeOrigin::signed(creator), collection_id, Some(RoyaltyInfo { recipient: creator, bps: 500 })));
+        assert_ok!(NftModule::set_nft_royalty(RuntimeOrigin::signed(creator), (collection_id, 0), Some(RoyaltyInfo { recipient: creator, bps: 1_000 })));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(creator), account_id0, (collection_id, 0), 100));
+
+        let share = 40;
+        let token_amount: u128 = 50000;
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+        let offer_nfts = BoundedVec::default();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0, None, None));
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts, token_amount, account_id1, None));
+        System::assert_has_event(Event::RoyaltyPaid((collection_id, 0, share), creator, 5000).into());
+
+        assert_eq!(Balances::free_balance(creator), 1000000 + 5000);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 + token_amount - 5000);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, share)]);
+    })
+}
+
+#[test]
+fn english_auction_settles_to_highest_bidder() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 0;
+        let account_id1: AccountId = 1;
+        let account_id2: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id1), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        let nft_item_with_share = (collection_id, 0, 100);
+        assert_ok!(NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share, 1000, 100, 10));
+        System::assert_last_event(Event::AuctionCreated(account_id1, nft_item_with_share, 1000, 11).into());
+        assert_eq!(nft_owners((collection_id, 0)), vec![NftMarketModule::account_id()]);
+
+        assert_noop!(
+            NftMarketModule::bid(RuntimeOrigin::signed(account_id1), nft_item_with_share, account_id1, 1000),
+            Error::<Test>::CannotBidOwnAuction
+        );
+        assert_noop!(
+            NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 900),
+            Error::<Test>::BidTooLow
+        );
+        assert_ok!(NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 1000));
+        assert_eq!(Balances::reserved_balance(account_id2), 1000);
+        assert_noop!(
+            NftMarketModule::bid(RuntimeOrigin::signed(account_id0), nft_item_with_share, account_id1, 1050),
+            Error::<Test>::BidTooLow
+        );
+        assert_noop!(
+            NftMarketModule::cancel_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share),
+            Error::<Test>::AuctionHasBids
+        );
+
+        // the outbid bidder is refunded immediately
+        assert_ok!(NftMarketModule::bid(RuntimeOrigin::signed(account_id0), nft_item_with_share, account_id1, 1100));
+        assert_eq!(Balances::reserved_balance(account_id0), 1100);
+        assert_eq!(Balances::reserved_balance(account_id2), 0);
+
+        run_to_block(10);
+        assert!(Auctions::<Test>::contains_key(nft_item_with_share, account_id1));
+        run_to_block(11);
+        System::assert_has_event(Event::AuctionSettled(nft_item_with_share, account_id1, Some((account_id0, 1100))).into());
+        assert!(!Auctions::<Test>::contains_key(nft_item_with_share, account_id1));
+        assert_eq!(nft_owners((collection_id, 0)), vec![account_id0]);
+        assert_eq!(owned_nfts(account_id0), vec![(collection_id, 0, 100)]);
+        assert_eq!(Balances::reserved_balance(account_id0), 0);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 - 1100);
+        assert_eq!(Balances::free_balance(account_id1), 1000000 + 1100);
+        assert_noop!(
+            NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 2000),
+            Error::<Test>::AuctionNotFound
+        );
+    })
+}
+
+#[test]
+fn english_auction_extended_by_late_bid() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id1: AccountId = 1;
+        let account_id2: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id1), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        let nft_item_with_share = (collection_id, 0, 40);
+        assert_ok!(NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share, 1000, 100, 5));
+
+        // a bid within the extension period moves the end of the auction
+        run_to_block(4);
+        let extension_period: u64 = <Test as Config>::AuctionExtensionPeriod::get();
+        assert_ok!(NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 1000));
+        System::assert_has_event(Event::AuctionExtended(nft_item_with_share, account_id1, 4 + extension_period).into());
+        assert!(AuctionsEndingAt::<Test>::get(6).is_empty());
+
+        run_to_block(6);
+        assert!(Auctions::<Test>::contains_key(nft_item_with_share, account_id1));
+        run_to_block(4 + extension_period);
+        assert!(!Auctions::<Test>::contains_key(nft_item_with_share, account_id1));
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 60)]);
+        assert_eq!(owned_nfts(account_id2), vec![(collection_id, 0, 40)]);
+    })
+}
+
+#[test]
+fn late_bid_spills_over_into_the_next_block_when_the_end_block_is_full() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id1: AccountId = 1;
+        let account_id2: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id1), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let max_auctions: u32 = <Test as Config>::MaxAuctionsPerBlock::get();
+        for _ in 0..=max_auctions {
+            assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo.clone()));
+        }
+        // fill the block a late bid extends the auction to
+        let extension_period: u64 = <Test as Config>::AuctionExtensionPeriod::get();
+        let extended_end_block = 4 + extension_period;
+        for item_id in 1..=max_auctions {
+            assert_ok!(NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), (collection_id, item_id, 100), 1000, 100, extended_end_block - 1));
+        }
+        assert_eq!(AuctionsEndingAt::<Test>::get(extended_end_block).len() as u32, max_auctions);
+        let nft_item_with_share = (collection_id, 0, 100);
+        assert_ok!(NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share, 1000, 100, 5));
+
+        // the late bid is accepted and the settlement spills over into the next block
+        run_to_block(4);
+        assert_ok!(NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 1000));
+        System::assert_has_event(Event::AuctionExtended(nft_item_with_share, account_id1, extended_end_block).into());
+        assert!(AuctionsEndingAt::<Test>::get(6).is_empty());
+        assert_eq!(AuctionsEndingAt::<Test>::get(extended_end_block + 1).into_inner(), vec![(nft_item_with_share, account_id1)]);
+
+        // no bid is accepted after the end block, the auction is settled in the spillover block
+        run_to_block(extended_end_block);
+        assert_noop!(
+            NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 2000),
+            Error::<Test>::AuctionEnded
+        );
+        assert!(Auctions::<Test>::contains_key(nft_item_with_share, account_id1));
+        run_to_block(extended_end_block + 1);
+        assert!(!Auctions::<Test>::contains_key(nft_item_with_share, account_id1));
+        assert_eq!(owned_nfts(account_id2), vec![(collection_id, 0, 100)]);
+    })
+}
+
+#[test]
+fn english_auction_without_bids_returns_nft() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id1: AccountId = 1;
+        let account_id2: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id1), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        let nft_item_with_share = (collection_id, 0, 100);
+        assert_noop!(
+            NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share, 1000, 100, 0),
+            Error::<Test>::InvalidAuctionDuration
+        );
+        assert_noop!(
+            NftMarketModule::create_auction(RuntimeOrigin::signed(account_id2), nft_item_with_share, 1000, 100, 5),
+            pallet_nft::Error::<Test>::NotOwner
+        );
+
+        assert_ok!(NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share, 1000, 100, 5));
+        assert_noop!(
+            NftMarketModule::cancel_auction(RuntimeOrigin::signed(account_id2), nft_item_with_share),
+            Error::<Test>::AuctionNotFound
+        );
+        assert_ok!(NftMarketModule::cancel_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share));
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100)]);
+        assert!(AuctionsEndingAt::<Test>::get(6).is_empty());
+
+        assert_ok!(NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share, 1000, 100, 5));
+        run_to_block(6);
+        System::assert_has_event(Event::AuctionSettled(nft_item_with_share, account_id1, None).into());
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100)]);
+    })
+}
+
+#[test]
+fn english_auction_in_frozen_collection_is_kept_until_cancelled() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id1: AccountId = 1;
+        let account_id2: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id1), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        let nft_item_with_share = (collection_id, 0, 100);
+        assert_ok!(NftMarketModule::create_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share, 1000, 100, 5));
+        assert_ok!(NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 1000));
+        assert_ok!(NftModule::freeze_collection(RuntimeOrigin::signed(account_id1), collection_id));
+
+        // the bid is refunded and the NFT share stays in the auction
+        run_to_block(6);
+        System::assert_has_event(Event::AuctionSettlementFailed(nft_item_with_share, account_id1).into());
+        assert_eq!(Balances::reserved_balance(account_id2), 0);
+        assert_eq!(Balances::free_balance(account_id2), 1000000);
+        assert_eq!(Auctions::<Test>::get(nft_item_with_share, account_id1).unwrap().highest_bid, None);
+        assert_eq!(nft_owners((collection_id, 0)), vec![NftMarketModule::account_id()]);
+        assert_noop!(
+            NftMarketModule::bid(RuntimeOrigin::signed(account_id2), nft_item_with_share, account_id1, 2000),
+            Error::<Test>::AuctionEnded
+        );
+        assert_noop!(
+            NftMarketModule::cancel_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share),
+            pallet_nft::Error::<Test>::CollectionIsFrozen
+        );
+
+        // the seller reclaims the NFT share once the collection is thawed
+        assert_ok!(NftModule::thaw_collection(RuntimeOrigin::signed(account_id1), collection_id));
+        assert_ok!(NftMarketModule::cancel_auction(RuntimeOrigin::signed(account_id1), nft_item_with_share));
+        assert!(!Auctions::<Test>::contains_key(nft_item_with_share, account_id1));
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100)]);
+    })
+}
+
+#[test]
+fn buy_nft_fail_when_price_exceeds_max_price() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        let share = 100;
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 200000, None, None));
+        assert_noop!(
+            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), account_id0, 199999),
+            Error::<Test>::PriceExceedsMaxPrice
+        );
+    })
+}
+
+#[test]
+fn buy_nft_in_dutch_auction_at_declining_price() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        let nft_item_with_share = (collection_id, 0, 100);
+        assert_noop!(
+            NftMarketModule::create_dutch_auction(RuntimeOrigin::signed(account_id0), nft_item_with_share, 1000, 2000, 2, 10),
+            Error::<Test>::InvalidDutchAuctionPrice
+        );
+        assert_ok!(NftMarketModule::create_dutch_auction(RuntimeOrigin::signed(account_id0), nft_item_with_share, 2000, 1000, 2, 10));
+        assert_noop!(
+            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), nft_item_with_share, 1500, None, None),
+            Error::<Test>::NftAlreadyListed
+        );
+        assert_noop!(
+            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), nft_item_with_share, account_id0, 2000),
+            Error::<Test>::DutchAuctionNotStarted
+        );
+
+        // the price declines linearly from 2000 at block 2 to 1000 at block 12
+        System::set_block_number(2);
+        assert_eq!(NftMarketModule::dutch_auction_price(&DutchAuctions::<Test>::get(nft_item_with_share, account_id0).unwrap()), Ok(2000));
+        System::set_block_number(7);
+        assert_noop!(
+            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), nft_item_with_share, account_id0, 1499),
+            Error::<Test>::PriceExceedsMaxPrice
+        );
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), nft_item_with_share, account_id0, 1500));
+        System::assert_last_event(Event::BuySuccess(nft_item_with_share, account_id0, 1500).into());
+        assert!(!DutchAuctions::<Test>::contains_key(nft_item_with_share, account_id0));
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100)]);
+        assert_eq!(Balances::free_balance(account_id1), 1000000 - 1500);
+    })
+}
+
+#[test]
+fn dutch_auction_price_stops_at_end_price() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        let nft_item_with_share = (collection_id, 0, 100);
+        assert_ok!(NftMarketModule::create_dutch_auction(RuntimeOrigin::signed(account_id0), nft_item_with_share, 2000, 1000, 1, 10));
+
+        System::set_block_number(100);
+        assert_eq!(NftMarketModule::dutch_auction_price(&DutchAuctions::<Test>::get(nft_item_with_share, account_id0).unwrap()), Ok(1000));
+
+        assert_ok!(NftMarketModule::cancel_dutch_auction(RuntimeOrigin::signed(account_id0), nft_item_with_share));
+        assert!(!DutchAuctions::<Test>::contains_key(nft_item_with_share, account_id0));
+    })
+}
+
+#[test]
+fn place_offer_escrows_tokens_and_nfts() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let share = 100;
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
+        let token_amount: u128 = 1000;
+        let placed_share = 60;
+        let offer_nfts = BoundedVec::try_from(vec![(collection_id, 1, placed_share)]).unwrap();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), token_amount, account_id0, None, None));
+        assert_eq!(Balances::reserved_balance(account_id1), token_amount);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 100 - placed_share)]);
+        assert_eq!(owned_nfts(NftMarketModule::account_id()), vec![(collection_id, 1, placed_share)]);
+
+        // the escrowed share can not be transferred away
+        assert_noop!(
+            NftModule::transfer_nft(RuntimeOrigin::signed(account_id1), account_id0, (collection_id, 1), 100),
+            pallet_nft::Error::<Test>::NFTNotEnoughShare
+        );
+
+        // the offer is released when the NFT is unlisted
+        assert_ok!(NftMarketModule::unlist_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share)));
+        System::assert_has_event(Event::OfferReleased((collection_id, 0, share), account_id0, Offer {
+            offered_nfts: offer_nfts.clone(),
+            token_amount,
+            buyer: account_id1,
+            expiry: None,
+            asset: None,
+        }).into());
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 100)]);
+        assert!(owned_nfts(NftMarketModule::account_id()).is_empty());
+        assert_noop!(
+            NftMarketModule::cancel_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts, token_amount, account_id0, None),
+            Error::<Test>::NotOffered
+        );
+    })
+}
+
+#[test]
+fn reject_and_accept_offer_release_and_consume_escrow() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let share = 100;
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
+        let offer_nfts = BoundedVec::try_from(vec![(collection_id, 1, 30)]).unwrap();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id0, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), 2000, account_id0, None, None));
+        assert_eq!(Balances::reserved_balance(account_id1), 3000);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 40)]);
+
+        assert_ok!(NftMarketModule::reject_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id1, None));
+        assert_eq!(Balances::reserved_balance(account_id1), 2000);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 70)]);
+
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts, 2000, account_id1, None));
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+        assert_eq!(Balances::free_balance(account_id1), 1000000 - 2000);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 + 2000);
+        assert_eq!(owned_nfts(account_id0), vec![(collection_id, 1, 30)]);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100), (collection_id, 1, 70)]);
+        assert!(owned_nfts(NftMarketModule::account_id()).is_empty());
+    })
+}
+
+#[test]
+fn selling_an_nft_releases_the_other_offers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 0;
+        let account_id1: AccountId = 1;
+        let account_id2: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id1), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id2), collection_id, metainfo1.clone()));
+        let offer_nfts: BoundedVec<_, <Test as Config>::MaxOfferNftsLength> = BoundedVec::try_from(vec![(collection_id, 2, 30)]).unwrap();
+
+        // buying the NFT releases its offers
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 100), 500, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id2), (collection_id, 0, 100), offer_nfts.clone(), 1000, account_id1, None, None));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 100), account_id1, 500));
+        assert!(Offers::<Test>::get((collection_id, 0, 100), account_id1).is_none());
+        assert_eq!(BuyerOffers::<Test>::iter_prefix(account_id2).count(), 0);
+        assert_eq!(Balances::reserved_balance(account_id2), 0);
+        assert_eq!(owned_nfts(account_id2), vec![(collection_id, 2, 100)]);
+
+        // accepting an offer releases the other offers
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id1), (collection_id, 1, 100), 500, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id2), (collection_id, 1, 100), offer_nfts.clone(), 1000, account_id1, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id0), (collection_id, 1, 100), BoundedVec::default(), 2000, account_id1, None, None));
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id1), (collection_id, 1, 100), BoundedVec::default(), 2000, account_id0, None));
+        System::assert_has_event(Event::OfferReleased((collection_id, 1, 100), account_id1, Offer {
+            offered_nfts: offer_nfts,
+            token_amount: 1000,
+            buyer: account_id2,
+            expiry: None,
+            asset: None,
+        }).into());
+        assert!(Offers::<Test>::get((collection_id, 1, 100), account_id1).is_none());
+        assert_eq!(Balances::reserved_balance(account_id2), 0);
+        assert_eq!(owned_nfts(account_id2), vec![(collection_id, 2, 100)]);
+        assert!(owned_nfts(NftMarketModule::account_id()).is_empty());
+    })
+}
+
+#[test]
+fn accept_offer_fail_without_changes_when_not_acceptable() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let share = 60;
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
+        let offer_nfts = BoundedVec::try_from(vec![(collection_id, 1, 50)]).unwrap();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id0, None, None));
+
+        // the listing is removed once the seller no longer holds the listed share
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 50));
+        assert_noop!(
+            NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id1, None),
+            Error::<Test>::NotListed
+        );
+
+        // the collection is frozen
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id1), account_id0, (collection_id, 0), 50));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+        assert_ok!(NftModule::freeze_collection(RuntimeOrigin::signed(account_id0), collection_id));
+        assert_noop!(
+            NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id1, None),
+            Error::<Test>::OfferNotAcceptable
+        );
+
+        assert_ok!(NftModule::thaw_collection(RuntimeOrigin::signed(account_id0), collection_id));
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts, 1000, account_id1, None));
+        assert_eq!(owned_nfts(account_id0), vec![(collection_id, 0, 40), (collection_id, 1, 50)]);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 60), (collection_id, 1, 50)]);
+    })
+}
+
+#[test]
+fn accept_offer_fail_when_the_held_token_amount_is_gone() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{fungible::MutateHold, tokens::Precision};
+
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let share = 60;
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+        let offer_nfts = BoundedVec::default();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id0, None, None));
+
+        // another reserve of the buyer covers the token amount, but the offer no longer holds it
+        assert_ok!(NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), collection_id, 10, 1000, BoundedVec::default(), None));
+        assert_ok!(<Balances as MutateHold<_>>::release(&HoldReason::Offer.into(), &account_id1, 1000, Precision::Exact));
+        assert_eq!(Balances::reserved_balance(account_id1), 1000);
+        assert_noop!(
+            NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts, 1000, account_id1, None),
+            Error::<Test>::OfferNotAcceptable
+        );
+    })
+}
+
+fn three_way_swap_legs(collection_id: H256) -> BoundedVec<SwapLeg<Test>, <Test as Config>::MaxSwapLegs> {
+    BoundedVec::try_from(vec![
+        SwapLeg { from: 0, to: 1, nfts: BoundedVec::try_from(vec![(collection_id, 0, 100)]).unwrap(), token_amount: 0 },
+        SwapLeg { from: 1, to: 2, nfts: BoundedVec::try_from(vec![(collection_id, 1, 40)]).unwrap(), token_amount: 0 },
+        SwapLeg { from: 2, to: 0, nfts: BoundedVec::try_from(vec![(collection_id, 2, 100)]).unwrap(), token_amount: 500 },
+    ]).unwrap()
+}
+
+fn mint_three_way_swap_nfts() -> H256 {
+    let max_items: u32 = 100;
+    let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+    assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(0), max_items, metainfo.clone()));
+
+    let collection_id = last_collection_id();
+    let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+    for item_id in 0..3 {
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(0), collection_id, metainfo1.clone()));
+        if item_id > 0 {
+            assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(0), item_id as AccountId, (collection_id, item_id), 100));
+        }
+    }
+    collection_id
+}
+
+#[test]
+fn three_way_swap_settles_after_every_approval() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let collection_id = mint_three_way_swap_nfts();
+        let legs = three_way_swap_legs(collection_id);
+
+        // the creator is a party and approves the swap
+        assert_ok!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), legs.clone(), 10));
+        assert_eq!(Swaps::<Test>::get(0).unwrap().approvals.into_inner(), vec![0]);
+        assert_eq!(Balances::reserved_balance(0), 10);
+        assert_noop!(NftMarketModule::approve_swap(RuntimeOrigin::signed(0), 0), Error::<Test>::SwapAlreadyApproved);
+        assert_noop!(NftMarketModule::approve_swap(RuntimeOrigin::signed(3), 0), Error::<Test>::NotSwapParty);
+
+        assert_ok!(NftMarketModule::approve_swap(RuntimeOrigin::signed(1), 0));
+        assert_eq!(owned_nfts(1), vec![(collection_id, 1, 100)]);
+
+        // the last party signs off-chain and anyone submits the approval
+        let swap = Swaps::<Test>::get(0).unwrap();
+        let signature = sp_runtime::testing::TestSignature(2, NftMarketModule::swap_payload(0, &swap));
+        // a signature of the swap without the pallet name and the genesis hash is rejected
+        let undomained_signature = sp_runtime::testing::TestSignature(2, (0u32, &swap.legs, swap.deadline).encode());
+        assert_noop!(
+            NftMarketModule::approve_swap_signed(RuntimeOrigin::signed(1), 0, sp_runtime::testing::UintAuthorityId(2), undomained_signature),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            NftMarketModule::approve_swap_signed(RuntimeOrigin::signed(1), 0, sp_runtime::testing::UintAuthorityId(1), signature.clone()),
+            Error::<Test>::InvalidSignature
+        );
+        assert_ok!(NftMarketModule::approve_swap_signed(RuntimeOrigin::signed(1), 0, sp_runtime::testing::UintAuthorityId(2), signature));
+        System::assert_last_event(Event::SwapSettled(0).into());
+
+        assert!(Swaps::<Test>::get(0).is_none());
+        assert_eq!(Balances::reserved_balance(0), 0);
+        assert_eq!(owned_nfts(0), vec![(collection_id, 2, 100)]);
+        assert_eq!(owned_nfts(1), vec![(collection_id, 0, 100), (collection_id, 1, 60)]);
+        assert_eq!(owned_nfts(2), vec![(collection_id, 1, 40)]);
+        assert_eq!(nft_owners((collection_id, 1)), vec![1, 2]);
+        assert_eq!(Balances::free_balance(0), 1000000 + 500);
+        assert_eq!(Balances::free_balance(2), 1000000 - 500);
+    })
+}
+
+#[test]
+fn approve_swap_charges_the_weight_of_the_approved_swap() {
+    new_test_ext().execute_with(|| {
+        use crate::weights::WeightInfo;
+
+        System::set_block_number(1);
+        let collection_id = mint_three_way_swap_nfts();
+        assert_ok!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), three_way_swap_legs(collection_id), 10));
+
+        // the largest swap is charged before the swap is read, the rest is refunded
+        let max_legs: u32 = <Test as Config>::MaxSwapLegs::get();
+        let max_nfts = max_legs * <Test as Config>::MaxOfferNftsLength::get();
+        let post_info = NftMarketModule::approve_swap(RuntimeOrigin::signed(1), 0).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::approve_swap(3, 3)));
+        assert!(post_info.actual_weight.unwrap().all_lt(<() as WeightInfo>::approve_swap(max_legs, max_nfts)));
+    })
+}
+
+#[test]
+fn swap_fail_without_changes_when_not_settleable() {
+    new_test_ext().execute_with(|| {
+        let collection_id = mint_three_way_swap_nfts();
+        assert_ok!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), three_way_swap_legs(collection_id), 10));
+        assert_ok!(NftMarketModule::approve_swap(RuntimeOrigin::signed(1), 0));
+
+        // a party no longer owns the committed share
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(2), 1, (collection_id, 2), 50));
+        assert_noop!(NftMarketModule::approve_swap(RuntimeOrigin::signed(2), 0), Error::<Test>::SwapNotSettleable);
+
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(1), 2, (collection_id, 2), 50));
+        assert_ok!(NftMarketModule::approve_swap(RuntimeOrigin::signed(2), 0));
+        assert_eq!(owned_nfts(0), vec![(collection_id, 2, 100)]);
+    })
+}
+
+#[test]
+fn cancel_swap_and_swap_expiry() {
+    new_test_ext().execute_with(|| {
+        let collection_id = mint_three_way_swap_nfts();
+        let legs = three_way_swap_legs(collection_id);
+        assert_noop!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), BoundedVec::default(), 10), Error::<Test>::EmptySwap);
+
+        // the account without free balance can not pay the swap deposit
+        assert_noop!(NftMarketModule::propose_swap(RuntimeOrigin::signed(3), legs.clone(), 10), Error::<Test>::InsufficientBalance);
+
+        assert_ok!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), legs.clone(), 10));
+        assert_noop!(NftMarketModule::cancel_swap(RuntimeOrigin::signed(3), 0), Error::<Test>::NotSwapParty);
+        assert_ok!(NftMarketModule::cancel_swap(RuntimeOrigin::signed(2), 0));
+        assert_noop!(NftMarketModule::approve_swap(RuntimeOrigin::signed(1), 0), Error::<Test>::SwapNotFound);
+        assert_eq!(Balances::reserved_balance(0), 0);
+
+        // an account can only have a few open swaps
+        assert_ok!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), legs.clone(), 10));
+        assert_ok!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), legs.clone(), 10));
+        assert_noop!(NftMarketModule::propose_swap(RuntimeOrigin::signed(0), legs.clone(), 10), Error::<Test>::TooManySwaps);
+        assert_eq!(Balances::reserved_balance(0), 20);
+        assert_ok!(NftMarketModule::cancel_swap(RuntimeOrigin::signed(0), 2));
+        assert_eq!(ProposedSwaps::<Test>::get(0), Some(1));
+
+        // an expired swap can not be approved, and anyone can cancel it
+        run_to_block(11);
+        assert_noop!(NftMarketModule::approve_swap(RuntimeOrigin::signed(1), 1), Error::<Test>::SwapExpired);
+        assert_ok!(NftMarketModule::cancel_swap(RuntimeOrigin::signed(3), 1));
+        assert!(Swaps::<Test>::get(1).is_none());
+        assert_eq!(Balances::reserved_balance(0), 0);
+        assert_eq!(ProposedSwaps::<Test>::get(0), None);
+        assert_eq!(owned_nfts(0), vec![(collection_id, 0, 100)]);
+    })
+}
+
+#[test]
+fn collection_bid_filled_by_any_owner() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+
+        assert_noop!(
+            NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), H256::zero(), 100, 1000, BoundedVec::default(), None),
+            Error::<Test>::CollectionNotFound
+        );
+        assert_noop!(
+            NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), collection_id, 100, 2000000, BoundedVec::default(), None),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_ok!(NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), collection_id, 100, 1000, BoundedVec::default(), None));
+        assert_ok!(NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), collection_id, 50, 400, BoundedVec::default(), None));
+        assert_eq!(Balances::reserved_balance(account_id1), 1400);
+
+        // any NFT of the collection fills the bid
+        assert_ok!(NftMarketModule::fill_collection_bid(RuntimeOrigin::signed(account_id0), 0, 1));
+        System::assert_last_event(Event::CollectionBidFilled(0, account_id0, (collection_id, 1, 100)).into());
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 100)]);
+        assert_eq!(Balances::reserved_balance(account_id1), 400);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 + 1000);
+        assert_noop!(
+            NftMarketModule::fill_collection_bid(RuntimeOrigin::signed(account_id0), 0, 0),
+            Error::<Test>::CollectionBidNotFound
+        );
+
+        assert_noop!(
+            NftMarketModule::cancel_collection_bid(RuntimeOrigin::signed(account_id0), 1),
+            Error::<Test>::NotBidder
+        );
+        assert_ok!(NftMarketModule::cancel_collection_bid(RuntimeOrigin::signed(account_id1), 1));
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+        assert_eq!(Balances::free_balance(account_id1), 1000000 - 1000);
+    })
+}
+
+#[test]
+fn collection_bid_filtered_by_attributes_and_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        let key: BoundedVec<u8, _> = BoundedVec::try_from(b"color".to_vec()).unwrap();
+        let value: BoundedVec<u8, _> = BoundedVec::try_from(b"red".to_vec()).unwrap();
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id0), collection_id, Some(1), pallet_nft::AttributeNamespace::CollectionOwner, key.clone(), value.clone()));
+        // an attribute set by the NFT owner in its own namespace does not match
+        assert_ok!(NftModule::set_attribute(RuntimeOrigin::signed(account_id0), collection_id, Some(0), pallet_nft::AttributeNamespace::ItemOwner, key.clone(), value.clone()));
+
+        let attributes = BoundedVec::try_from(vec![(BoundedVec::try_from(b"color".to_vec()).unwrap(), BoundedVec::try_from(b"red".to_vec()).unwrap())]).unwrap();
+        assert_ok!(NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), collection_id, 100, 1000, attributes, Some(5)));
+        assert_noop!(
+            NftMarketModule::fill_collection_bid(RuntimeOrigin::signed(account_id0), 0, 0),
+            Error::<Test>::AttributeMismatch
+        );
+        assert_ok!(NftMarketModule::fill_collection_bid(RuntimeOrigin::signed(account_id0), 0, 1));
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 100)]);
+
+        // an expired bid can not be filled, and is unreserved by the sweeper
+        assert_ok!(NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), collection_id, 100, 500, BoundedVec::default(), Some(5)));
+        assert_eq!(Balances::reserved_balance(account_id1), 500);
+        run_to_block(6);
+        assert_noop!(
+            NftMarketModule::fill_collection_bid(RuntimeOrigin::signed(account_id0), 1, 0),
+            Error::<Test>::CollectionBidExpired
+        );
+        NftMarketModule::on_idle(6, Weight::MAX);
+        System::assert_has_event(Event::CollectionBidExpired(1).into());
+        assert!(CollectionBids::<Test>::get(1).is_none());
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+    })
+}
+
+#[test]
+fn fill_collection_bid_fail_when_share_not_enough() {
+    new_test_ext().execute_with(|| {
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 60));
+        assert_ok!(NftMarketModule::place_collection_bid(RuntimeOrigin::signed(account_id1), collection_id, 50, 1000, BoundedVec::default(), None));
+
+        assert_noop!(
+            NftMarketModule::fill_collection_bid(RuntimeOrigin::signed(account_id0), 0, 0),
+            pallet_nft::Error::<Test>::NFTNotEnoughShare
+        );
+        assert_noop!(
+            NftMarketModule::fill_collection_bid(RuntimeOrigin::signed(account_id0), 0, 1),
+            pallet_nft::Error::<Test>::NFTNotFound
+        );
+    })
+}
+
+#[test]
+fn expired_listing_can_not_be_bought() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        let nft_item_with_share = (collection_id, 0, 100);
+
+        assert_noop!(
+            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), nft_item_with_share, 1000, Some(0), None),
+            Error::<Test>::InvalidExpiry
+        );
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), nft_item_with_share, 1000, Some(3), None));
+
+        run_to_block(4);
+        assert_noop!(
+            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), nft_item_with_share, account_id0, 1000),
+            Error::<Test>::ListingExpired
+        );
+        assert_noop!(
+            NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), nft_item_with_share, BoundedVec::default(), 1000, account_id0, None, None),
+            Error::<Test>::ListingExpired
+        );
+
+        NftMarketModule::on_idle(4, Weight::MAX);
+        System::assert_last_event(Event::ListingExpired(account_id0, nft_item_with_share).into());
+        assert_eq!(Listings::<Test>::get(nft_item_with_share, account_id0), None);
+        assert_eq!(NextExpiryToSweep::<Test>::get(), Some(4));
+    })
+}
+
+#[test]
+fn expired_listing_releases_its_offers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let nft_item_with_share = (collection_id, 0, 100);
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), nft_item_with_share, 1000, Some(3), None));
+
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
+        let offer_nfts = BoundedVec::try_from(vec![(collection_id, 1, 30)]).unwrap();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), nft_item_with_share, offer_nfts, 500, account_id0, None, None));
+        assert_eq!(Balances::reserved_balance(account_id1), 500);
+
+        // the offer without an expiry block is released with the listing
+        run_to_block(4);
+        NftMarketModule::on_idle(4, Weight::MAX);
+        System::assert_last_event(Event::ListingExpired(account_id0, nft_item_with_share).into());
+        assert_eq!(Offers::<Test>::get(nft_item_with_share, account_id0), None);
+        assert_eq!(BuyerOffers::<Test>::get(account_id1, (nft_item_with_share, account_id0)), None);
+        assert_eq!(SellerOffers::<Test>::get(account_id0, nft_item_with_share), None);
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 100)]);
+    })
+}
+
+#[test]
+fn expired_offer_is_released_by_sweeper() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        let share = 100;
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), 0, None, None));
+
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 1), 100));
+        let offer_nfts = BoundedVec::try_from(vec![(collection_id, 1, 30)]).unwrap();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id0, Some(2), None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts.clone(), 2000, account_id0, Some(5), None));
+        assert_eq!(Balances::reserved_balance(account_id1), 3000);
+
+        run_to_block(3);
+        assert_noop!(
+            NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, share), offer_nfts.clone(), 1000, account_id1, None),
+            Error::<Test>::OfferExpired
+        );
+
+        // the sweeper stops when the remaining weight is not enough
+        NftMarketModule::on_idle(3, Weight::zero());
+        assert_eq!(Balances::reserved_balance(account_id1), 3000);
+
+        NftMarketModule::on_idle(3, Weight::MAX);
+        assert_eq!(Balances::reserved_balance(account_id1), 2000);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 70)]);
+        assert_eq!(Offers::<Test>::get((collection_id, 0, share), account_id0).unwrap().len(), 1);
+
+        // the offer cancelled before its expiry is skipped
+        assert_ok!(NftMarketModule::cancel_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, share), offer_nfts, 2000, account_id0, None));
+        run_to_block(6);
+        NftMarketModule::on_idle(6, Weight::MAX);
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 100)]);
+        assert!(ExpiringAt::<Test>::get(5).is_empty());
+    })
+}
+
+#[test]
+fn expiries_spill_over_into_the_next_blocks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let share = 100;
+        for item_id in 0..7 {
+            assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+            if item_id < 6 {
+                assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, item_id, share), 1000, Some(3), None));
+            }
+        }
+
+        // the sweeps of the expiry block and of the next `MaxExpirySpillover` blocks are full
+        assert_eq!(ExpiringAt::<Test>::get(3).len(), 2);
+        assert_eq!(ExpiringAt::<Test>::get(4).len(), 2);
+        assert_eq!(ExpiringAt::<Test>::get(5).len(), 2);
+        assert_noop!(
+            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 6, share), 1000, Some(3), None),
+            Error::<Test>::TooManyExpiries
+        );
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 6, share), 1000, Some(4), None));
+        assert_eq!(ExpiringAt::<Test>::get(6).len(), 1);
+
+        // a spilled over listing expires at its expiry block and is swept later
+        run_to_block(4);
+        assert_noop!(
+            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 5, share), account_id0, 1000),
+            Error::<Test>::ListingExpired
+        );
+        NftMarketModule::on_idle(4, Weight::MAX);
+        assert_eq!(Listings::<Test>::get((collection_id, 0, share), account_id0), None);
+        assert!(Listings::<Test>::get((collection_id, 5, share), account_id0).is_some());
+
+        run_to_block(6);
+        NftMarketModule::on_idle(6, Weight::MAX);
+        System::assert_last_event(Event::ListingExpired(account_id0, (collection_id, 5, share)).into());
+        assert!(Listings::<Test>::get((collection_id, 6, share), account_id0).is_some());
+
+        run_to_block(7);
+        NftMarketModule::on_idle(7, Weight::MAX);
+        System::assert_last_event(Event::ListingExpired(account_id0, (collection_id, 6, share)).into());
+        assert_eq!(NextExpiryToSweep::<Test>::get(), Some(7));
+    })
+}
+
+#[test]
+fn migrate_listings_and_offers_to_fungible_shares() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+        use crate::migrations::v1::{v0, MigrateV0ToV1};
+
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let account_id2: AccountId = 3;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id1), collection_id, metainfo.clone()));
+
+        StorageVersion::new(0).put::<NftMarketModule>();
+        v0::Listings::<Test>::insert((collection_id, 0, 100u8), account_id0, v0::ListInfo::<Test> { owner: account_id0, price: 1000 });
+        let offers: BoundedVec<v0::Offer<Test>, <Test as Config>::MaxOffersLength> = BoundedVec::try_from(vec![
+            v0::Offer { offered_nfts: BoundedVec::try_from(vec![(collection_id, 1, 50u8)]).unwrap(), token_amount: 500, buyer: account_id1 },
+            // the buyer can not pay the token amount, the offer is dropped
+            v0::Offer { offered_nfts: BoundedVec::default(), token_amount: 500, buyer: account_id2 },
+        ]).unwrap();
+        v0::Offers::<Test>::insert((collection_id, 0, 100u8), account_id0, offers);
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(NftMarketModule::on_chain_storage_version(), 1);
+        assert_eq!(v0::Listings::<Test>::iter().count(), 0);
+        assert_eq!(
+            Listings::<Test>::get((collection_id, 0, 100), account_id0),
+            Some(ListInfo { owner: account_id0, price: 1000, expiry: None, asset: None })
+        );
+        assert_eq!(ListedShares::<Test>::get((collection_id, 0)).unwrap().into_inner(), vec![(account_id0, 100)]);
+        assert_eq!(
+            Offers::<Test>::get((collection_id, 0, 100), account_id0).unwrap().into_inner(),
+            vec![Offer {
+                offered_nfts: BoundedVec::try_from(vec![(collection_id, 1, 50)]).unwrap(),
+                token_amount: 500,
+                buyer: account_id1,
+                expiry: None,
+                asset: None,
+            }]
+        );
+        assert_eq!(BuyerOffers::<Test>::get(account_id1, ((collection_id, 0, 100), account_id0)), Some(1));
+        assert_eq!(BuyerOffers::<Test>::get(account_id2, ((collection_id, 0, 100), account_id0)), None);
+        assert_eq!(SellerOffers::<Test>::iter().collect::<Vec<_>>(), vec![(account_id0, (collection_id, 0, 100), 1)]);
+        assert_eq!(CollectionListings::<Test>::iter_key_prefix(collection_id).collect::<Vec<_>>(), vec![(0, 100, account_id0)]);
+
+        // the offer is escrowed
+        assert_eq!(Balances::reserved_balance(account_id1), 500);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 1, 50)]);
+        assert_eq!(owned_nfts(NftMarketModule::account_id()), vec![(collection_id, 1, 50)]);
+    })
+}
+
+#[test]
+fn migrate_drops_listings_whose_share_can_not_be_indexed() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+        use crate::migrations::v1::{v0, MigrateV0ToV1};
+
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+
+        // one listing more than the listed shares of an NFT by a seller
+        StorageVersion::new(0).put::<NftMarketModule>();
+        let max_listings = <Test as Config>::MaxListingsPerNft::get() as u8;
+        for percentage in 1..=max_listings + 1 {
+            v0::Listings::<Test>::insert((collection_id, 0, percentage), account_id0, v0::ListInfo::<Test> { owner: account_id0, price: 1000 });
+            let offers: BoundedVec<v0::Offer<Test>, <Test as Config>::MaxOffersLength> = BoundedVec::try_from(vec![
+                v0::Offer { offered_nfts: BoundedVec::default(), token_amount: 100, buyer: account_id1 },
+            ]).unwrap();
+            v0::Offers::<Test>::insert((collection_id, 0, percentage), account_id0, offers);
+        }
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        // every migrated listing is indexed, the dropped listing takes its offer along
+        assert_eq!(Listings::<Test>::iter().count(), max_listings as usize);
+        let listed_shares = ListedShares::<Test>::get((collection_id, 0)).unwrap();
+        assert_eq!(listed_shares.len(), max_listings as usize);
+        for (nft_item_with_share, seller, _) in Listings::<Test>::iter() {
+            assert!(listed_shares.contains(&(seller, nft_item_with_share.2)));
+            assert!(Offers::<Test>::contains_key(nft_item_with_share, seller));
+        }
+        assert_eq!(Offers::<Test>::iter().count(), max_listings as usize);
+        assert_eq!(Balances::reserved_balance(account_id1), 100 * max_listings as u128);
+    })
+}
+
+#[test]
+fn listings_are_invalidated_when_the_nft_moves() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let buyer: AccountId = 0;
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 30), 1000, None, None));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 80), 2000, None, None));
+        assert_ok!(NftMarketModule::create_dutch_auction(RuntimeOrigin::signed(account_id0), (collection_id, 1, 100), 1000, 100, 1, 10));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(buyer), (collection_id, 0, 30), BoundedVec::default(), 300, account_id0, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(buyer), (collection_id, 0, 80), BoundedVec::default(), 800, account_id0, None, None));
+        assert_eq!(Balances::reserved_balance(buyer), 1100);
+
+        // the listing of more than the remaining share is removed, its offers are released by the
+        // sweep of the next block
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 50));
+        System::assert_has_event(Event::ListingInvalidated(account_id0, (collection_id, 0, 80)).into());
+        assert_eq!(Balances::reserved_balance(buyer), 1100);
+        run_to_block(2);
+        NftMarketModule::on_idle(2, Weight::MAX);
+        let released_offer = Offer { offered_nfts: BoundedVec::default(), token_amount: 800, buyer, expiry: None, asset: None };
+        System::assert_has_event(Event::OfferReleased((collection_id, 0, 80), account_id0, released_offer).into());
+        assert_eq!(Offers::<Test>::get((collection_id, 0, 80), account_id0), None);
+        assert_eq!(BuyerOffers::<Test>::get(buyer, ((collection_id, 0, 80), account_id0)), None);
+        assert_eq!(Balances::reserved_balance(buyer), 300);
+        assert!(Listings::<Test>::get((collection_id, 0, 30), account_id0).is_some());
+        assert!(Listings::<Test>::get((collection_id, 0, 80), account_id0).is_none());
+        assert_eq!(ListedShares::<Test>::get((collection_id, 0)).unwrap().into_inner(), vec![(account_id0, 30)]);
+        assert_noop!(
+            NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 80), account_id0, 2000),
+            Error::<Test>::NotListed
+        );
+
+        // merging the NFTs removes every listing and Dutch auction of them
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id1), account_id0, (collection_id, 0), 50));
+        assert_ok!(NftModule::merge_nfts(RuntimeOrigin::signed(account_id0), BoundedVec::try_from(vec![(collection_id, 0), (collection_id, 1)]).unwrap()));
+        assert!(Listings::<Test>::get((collection_id, 0, 30), account_id0).is_none());
+        assert!(DutchAuctions::<Test>::get((collection_id, 1, 100), account_id0).is_none());
+        assert_eq!(ListedShares::<Test>::iter().count(), 0);
+        run_to_block(3);
+        NftMarketModule::on_idle(3, Weight::MAX);
+        assert_eq!(Offers::<Test>::iter().count(), 0);
+        assert_eq!(BuyerOffers::<Test>::iter().count(), 0);
+        assert_eq!(Balances::reserved_balance(buyer), 0);
+
+        // a sub NFT can not be sold on its own and is not listed
+        assert_noop!(
+            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 1, 100), 1000, None, None),
+            pallet_nft::Error::<Test>::NFTIsFrozen
+        );
+        assert_noop!(
+            NftMarketModule::create_dutch_auction(RuntimeOrigin::signed(account_id0), (collection_id, 1, 100), 1000, 100, 1, 10),
+            pallet_nft::Error::<Test>::NFTIsFrozen
+        );
+
+        // selling a listing keeps the index up to date
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 100), 1000, None, None));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 100), account_id0, 1000));
+        assert_eq!(ListedShares::<Test>::iter().count(), 0);
+    })
+}
+
+#[test]
+fn partial_burn_invalidates_only_the_listings_of_the_burner() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let buyer: AccountId = 0;
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo.clone()));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(account_id0), account_id1, (collection_id, 0), 50));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 50), 1000, None, None));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 50), 1000, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(buyer), (collection_id, 0, 50), BoundedVec::default(), 500, account_id0, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(buyer), (collection_id, 0, 50), BoundedVec::default(), 500, account_id1, None, None));
+
+        // burning one share unit drops the listing of the burner only
+        assert_ok!(NftModule::burn_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0), 1));
+        System::assert_has_event(Event::ListingInvalidated(account_id0, (collection_id, 0, 50)).into());
+        assert!(Listings::<Test>::get((collection_id, 0, 50), account_id0).is_none());
+        assert!(Listings::<Test>::get((collection_id, 0, 50), account_id1).is_some());
+        assert_eq!(ListedShares::<Test>::get((collection_id, 0)).unwrap().into_inner(), vec![(account_id1, 50)]);
+
+        run_to_block(2);
+        NftMarketModule::on_idle(2, Weight::MAX);
+        assert_eq!(Offers::<Test>::get((collection_id, 0, 50), account_id0), None);
+        assert_eq!(Offers::<Test>::get((collection_id, 0, 50), account_id1).unwrap().len(), 1);
+        assert_eq!(Balances::reserved_balance(buyer), 500);
+    })
+}
+
+#[test]
+fn market_fee_paid_to_fee_destination() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let creator: AccountId = 0;
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let fee_destination: AccountId = MarketFeeDestination::get();
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(creator), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(creator), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::set_collection_royalty(RuntimeOrigin::signed(creator), collection_id, Some(RoyaltyInfo { recipient: creator, bps: 1_000 })));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(creator), account_id0, (collection_id, 0), 100));
+
+        assert_noop!(NftMarketModule::set_market_fee(RuntimeOrigin::signed(account_id0), 250), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(NftMarketModule::set_market_fee(RuntimeOrigin::root(), 1_001), Error::<Test>::FeeTooHigh);
+        assert_ok!(NftMarketModule::set_market_fee(RuntimeOrigin::root(), 250));
+        System::assert_last_event(Event::MarketFeeSet(250).into());
+
+        // the fee is charged on a bought listing
+        let price: u128 = 10000;
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40), price, None, None));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 40), account_id0, price));
+        System::assert_has_event(Event::MarketFeePaid((collection_id, 0, 40), 250).into());
+        assert_eq!(Balances::free_balance(fee_destination), 250);
+        assert_eq!(Balances::free_balance(creator), 1000000 + 1000);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 + price - 250 - 1000);
+
+        // and on the token amount of an accepted offer
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 60), 0, None, None));
+        let offer_nfts = BoundedVec::default();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 60), offer_nfts.clone(), 20000, account_id0, None, None));
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, 60), offer_nfts, 20000, account_id1, None));
+        assert_eq!(Balances::free_balance(fee_destination), 250 + 500);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100)]);
+    })
+}
+
+#[test]
+fn market_fee_below_existential_deposit_is_not_charged() {
+    ExistentialDeposit::set(500);
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let fee_destination: AccountId = MarketFeeDestination::get();
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftMarketModule::set_market_fee(RuntimeOrigin::root(), 250));
+
+        // the fee below the existential deposit of the empty fee destination is not charged
+        let price: u128 = 10000;
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40), price, None, None));
+        assert_eq!(NftMarketModule::buy_quote((collection_id, 0, 40), &account_id0).unwrap().market_fee, 0);
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 40), account_id0, price));
+        assert_eq!(Balances::free_balance(fee_destination), 0);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 + price);
+
+        // the fee reaching the existential deposit is charged
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 60), 2 * price, None, None));
+        assert_eq!(NftMarketModule::buy_quote((collection_id, 0, 60), &account_id0).unwrap().market_fee, 500);
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 60), account_id0, 2 * price));
+        System::assert_has_event(Event::MarketFeePaid((collection_id, 0, 60), 500).into());
+        assert_eq!(Balances::free_balance(fee_destination), 500);
+
+        // and so are the smaller fees once the fee destination exists
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 100), price, None, None));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 100), account_id1, price));
+        assert_eq!(Balances::free_balance(fee_destination), 750);
+    })
+}
+
+#[test]
+fn royalty_below_existential_deposit_goes_to_the_seller() {
+    ExistentialDeposit::set(500);
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let recipient: AccountId = 42;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::set_collection_royalty(RuntimeOrigin::signed(account_id0), collection_id, Some(RoyaltyInfo { recipient, bps: 500 })));
+
+        // the royalty below the existential deposit of the empty recipient is paid to the seller
+        let price: u128 = 2000;
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40), price, None, None));
+        let quote = NftMarketModule::buy_quote((collection_id, 0, 40), &account_id0).unwrap();
+        assert_eq!((quote.royalty, quote.seller_amount), (None, price));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 40), account_id0, price));
+        assert_eq!(Balances::free_balance(recipient), 0);
+        assert_eq!(Balances::free_balance(account_id0), 1000000 + price);
+
+        // the royalty reaching the existential deposit is paid to the recipient
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 60), 5 * price, None, None));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 60), account_id0, 5 * price));
+        System::assert_has_event(Event::RoyaltyPaid((collection_id, 0, 60), recipient, 500).into());
+        assert_eq!(Balances::free_balance(recipient), 500);
+    })
+}
+
+#[test]
+fn listing_and_offer_in_an_asset() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let account_id0: AccountId = 0;
+        let account_id1: AccountId = 1;
+        let asset_id: u32 = 7;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(account_id0), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(account_id0), collection_id, metainfo1.clone()));
+
+        assert_noop!(
+            NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40), 300, None, Some(asset_id)),
+            Error::<Test>::UnknownAsset
+        );
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id, account_id0, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(account_id0), asset_id, account_id1, 1000));
+
+        // the listing is paid in the asset, the native balances do not change
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40), 300, None, Some(asset_id)));
+        assert_ok!(NftMarketModule::buy_nft(RuntimeOrigin::signed(account_id1), (collection_id, 0, 40), account_id0, 300));
+        assert_eq!(Assets::balance(asset_id, account_id0), 300);
+        assert_eq!(Assets::balance(asset_id, account_id1), 700);
+        assert_eq!(Balances::free_balance(account_id1), 1000000);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 40)]);
+
+        // the token amount of an offer in the asset is escrowed in the market account
+        let market_account = NftMarketModule::account_id();
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 60), 0, None, None));
+        let offer_nfts = BoundedVec::default();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 60), offer_nfts.clone(), 200, account_id0, None, Some(asset_id)));
+        assert_eq!(Assets::balance(asset_id, market_account), 200);
+        assert_eq!(Assets::balance(asset_id, account_id1), 500);
+        assert_eq!(Balances::reserved_balance(account_id1), 0);
+
+        // the asset is part of the offer
+        assert_noop!(
+            NftMarketModule::cancel_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 60), offer_nfts.clone(), 200, account_id0, None),
+            Error::<Test>::NotOffered
+        );
+        assert_ok!(NftMarketModule::cancel_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 60), offer_nfts.clone(), 200, account_id0, Some(asset_id)));
+        assert_eq!(Assets::balance(asset_id, market_account), 0);
+        assert_eq!(Assets::balance(asset_id, account_id1), 700);
+
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 60), offer_nfts.clone(), 200, account_id0, None, Some(asset_id)));
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, 60), offer_nfts, 200, account_id1, Some(asset_id)));
+        assert_eq!(Assets::balance(asset_id, market_account), 0);
+        assert_eq!(Assets::balance(asset_id, account_id0), 500);
+        assert_eq!(owned_nfts(account_id1), vec![(collection_id, 0, 100)]);
+    })
+}
+
+#[test]
+fn query_listings_offers_and_quotes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let creator: AccountId = 0;
+        let account_id0: AccountId = 1;
+        let account_id1: AccountId = 2;
+        let max_items: u32 = 100;
+        let metainfo = BoundedVec::try_from(vec![0, 1]).unwrap();
+        assert_ok!(NftModule::create_collection(RuntimeOrigin::signed(creator), max_items, metainfo.clone()));
+
+        let collection_id = last_collection_id();
+        let metainfo1 = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(creator), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::mint_nft(RuntimeOrigin::signed(creator), collection_id, metainfo1.clone()));
+        assert_ok!(NftModule::set_collection_royalty(RuntimeOrigin::signed(creator), collection_id, Some(RoyaltyInfo { recipient: creator, bps: 1_000 })));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(creator), account_id0, (collection_id, 0), 100));
+        assert_ok!(NftModule::transfer_nft(RuntimeOrigin::signed(creator), account_id0, (collection_id, 1), 100));
+        assert_ok!(NftMarketModule::set_market_fee(RuntimeOrigin::root(), 250));
+
+        // the listings of a collection in an asset are sorted by price
+        let asset_id = 7;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id, account_id0, true, 1));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40), 1000, None, None));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 1, 100), 200, Some(5), None));
+        assert_ok!(NftMarketModule::list_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 50), 10, None, Some(asset_id)));
+        let listings: Vec<_> = NftMarketModule::collection_listings(collection_id, None).into_iter()
+            .map(|listing| (listing.item_id, listing.share, listing.price, listing.expiry))
+            .collect();
+        assert_eq!(listings, vec![(1, 100, 200, Some(5)), (0, 40, 1000, None)]);
+        let listings: Vec<_> = NftMarketModule::collection_listings(collection_id, Some(asset_id)).into_iter()
+            .map(|listing| (listing.item_id, listing.share, listing.price, listing.asset))
+            .collect();
+        assert_eq!(listings, vec![(0, 50, 10, Some(asset_id))]);
+        assert!(NftMarketModule::collection_listings(H256::zero(), None).is_empty());
+
+        // the quote splits the price into the market fee, the royalty and the seller amount
+        assert_eq!(
+            NftMarketModule::buy_quote((collection_id, 0, 40), &account_id0),
+            Some(api::BuyQuote { price: 1000, asset: None, market_fee: 25, royalty: Some((creator, 100)), seller_amount: 875 })
+        );
+        assert_eq!(NftMarketModule::buy_quote((collection_id, 0, 60), &account_id0), None);
+
+        // the offers are found by the seller and by the buyer
+        let offer_nfts = BoundedVec::default();
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 40), offer_nfts.clone(), 300, account_id0, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 40), offer_nfts.clone(), 400, account_id0, None, None));
+        assert_ok!(NftMarketModule::place_offer(RuntimeOrigin::signed(creator), (collection_id, 1, 100), offer_nfts.clone(), 100, account_id0, None, None));
+        let mut seller_offers: Vec<_> = NftMarketModule::seller_offers(&account_id0).into_iter()
+            .map(|offer| (offer.item_id, offer.buyer, offer.token_amount))
+            .collect();
+        seller_offers.sort();
+        assert_eq!(seller_offers, vec![(0, account_id1, 300), (0, account_id1, 400), (1, creator, 100)]);
+        let buyer_offers: Vec<_> = NftMarketModule::buyer_offers(&account_id1).into_iter()
+            .map(|offer| (offer.item_id, offer.seller, offer.token_amount))
+            .collect();
+        assert_eq!(buyer_offers, vec![(0, account_id0, 300), (0, account_id0, 400)]);
+        assert_eq!(BuyerOffers::<Test>::get(account_id1, ((collection_id, 0, 40), account_id0)), Some(2));
+        assert_eq!(SellerOffers::<Test>::get(account_id0, (collection_id, 0, 40)), Some(2));
+
+        // the buyer and seller indexes follow the removed offers
+        assert_ok!(NftMarketModule::cancel_offer(RuntimeOrigin::signed(account_id1), (collection_id, 0, 40), offer_nfts.clone(), 300, account_id0, None));
+        assert_eq!(BuyerOffers::<Test>::get(account_id1, ((collection_id, 0, 40), account_id0)), Some(1));
+        assert_ok!(NftMarketModule::reject_offer(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40), offer_nfts.clone(), 400, account_id1, None));
+        assert!(NftMarketModule::buyer_offers(&account_id1).is_empty());
+        assert_eq!(BuyerOffers::<Test>::get(account_id1, ((collection_id, 0, 40), account_id0)), None);
+        assert_ok!(NftMarketModule::accept_offer(RuntimeOrigin::signed(account_id0), (collection_id, 1, 100), offer_nfts, 100, creator, None));
+        assert!(NftMarketModule::buyer_offers(&creator).is_empty());
+        assert!(NftMarketModule::seller_offers(&account_id0).is_empty());
+        assert_eq!(SellerOffers::<Test>::iter().count(), 0);
+
+        // the collection index follows the removed listings
+        assert!(!CollectionListings::<Test>::contains_key(collection_id, (1, 100, account_id0)));
+        assert_ok!(NftMarketModule::unlist_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 40)));
+        assert_ok!(NftMarketModule::unlist_nft(RuntimeOrigin::signed(account_id0), (collection_id, 0, 50)));
+        assert_eq!(CollectionListings::<Test>::iter().count(), 0);
+    })
+}
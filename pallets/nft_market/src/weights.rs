@@ -1,13 +1,11 @@
 
-//! Autogenerated weights for `pallet_nft_market`
+//! Weights for `pallet_nft_market`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-10-19, STEPS: `20`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `MacBook-Air.local`, CPU: `<UNKNOWN>`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//! No benchmark run produced these weights, every number is a placeholder estimated from the
+//! storage accessed by the extrinsic. Regenerate this file with the benchmarks before relying on
+//! the weights.
 
-// Executed Command:
+// Regenerate with:
 // ./target/production/solochain-template-node
 // benchmark
 // pallet
@@ -39,43 +37,713 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_nft_market`.
 pub trait WeightInfo {
 	fn list_nft() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn settle_auction() -> Weight;
+	fn create_dutch_auction() -> Weight;
+	fn cancel_dutch_auction() -> Weight;
+	fn propose_swap(l: u32, n: u32) -> Weight;
+	fn approve_swap(l: u32, n: u32) -> Weight;
+	fn approve_swap_signed(l: u32, n: u32) -> Weight;
+	fn cancel_swap() -> Weight;
+	fn place_collection_bid() -> Weight;
+	fn cancel_collection_bid() -> Weight;
+	fn fill_collection_bid(a: u32) -> Weight;
+	fn sweep_expired(n: u32) -> Weight;
+	fn set_market_fee() -> Weight;
+	fn unlist_nft(o: u32) -> Weight;
+	fn buy_nft(o: u32) -> Weight;
+	fn place_offer(n: u32) -> Weight;
+	fn cancel_offer(n: u32) -> Weight;
+	fn reject_offer(n: u32) -> Weight;
+	fn update_list_price() -> Weight;
+	fn accept_offer(n: u32, o: u32) -> Weight;
 }
 
 /// Weights for `pallet_nft_market` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `NftModule::NFTOwners` (r:1 w:0)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
 	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
-	/// Proof: `NftModule::OwnedNFTs` (`max_values`: None, `max_size`: Some(370050), added: 372525, mode: `MaxEncodedLen`)
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::NextExpiryToSweep` (r:1 w:1)
 	/// Storage: `NftMarketModule::Listings` (r:0 w:1)
-	/// Proof: `NftMarketModule::Listings` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
 	fn list_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `223`
-		//  Estimated: `373515`
-		// Minimum execution time: 14_000_000 picoseconds.
 		Weight::from_parts(16_000_000, 373515)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	/// Storage: `NftMarketModule::AuctionsEndingAt` (r:1 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_parts(31_000_000, 11879)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `NftMarketModule::AuctionsEndingAt` (r:2 w:2)
+	fn bid() -> Weight {
+		Weight::from_parts(38_000_000, 22768)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	/// Storage: `NftMarketModule::AuctionsEndingAt` (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		Weight::from_parts(29_000_000, 11879)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	fn settle_auction() -> Weight {
+		Weight::from_parts(52_000_000, 6172)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftMarketModule::Listings` (r:1 w:0)
+	/// Storage: `NftMarketModule::DutchAuctions` (r:1 w:1)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	fn create_dutch_auction() -> Weight {
+		Weight::from_parts(17_000_000, 4173)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftMarketModule::DutchAuctions` (r:1 w:1)
+	fn cancel_dutch_auction() -> Weight {
+		Weight::from_parts(10_000_000, 3605)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftMarketModule::NextSwapId` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:0 w:1)
+	/// Storage: `NftMarketModule::Swaps` (r:0 w:1)
+	/// The range of component `l` is `[1, 8]`.
+	/// The range of component `n` is `[0, 10]`.
+	fn propose_swap(l: u32, n: u32) -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(200_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::Swaps` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// Storage: `NftMarketModule::ListedShares` (r:10 w:0)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	/// The range of component `l` is `[1, 8]`.
+	/// The range of component `n` is `[0, 10]`.
+	fn approve_swap(l: u32, n: u32) -> Weight {
+		Weight::from_parts(40_000_000, 5250)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 3150).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Swaps` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// Storage: `NftMarketModule::ListedShares` (r:10 w:0)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	/// The range of component `l` is `[1, 8]`.
+	/// The range of component `n` is `[0, 10]`.
+	fn approve_swap_signed(l: u32, n: u32) -> Weight {
+		Weight::from_parts(96_000_000, 5250)
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 3150).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Swaps` (r:1 w:1)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		Weight::from_parts(22_000_000, 5250)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollections` (r:1 w:0)
+	/// Storage: `NftMarketModule::NextCollectionBidId` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionBids` (r:0 w:1)
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::NextExpiryToSweep` (r:1 w:1)
+	fn place_collection_bid() -> Weight {
+		Weight::from_parts(38_000_000, 23878)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::CollectionBids` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn cancel_collection_bid() -> Weight {
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftMarketModule::CollectionBids` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	/// Storage: `NftModule::Attributes` (r:4 w:0)
+	/// The range of component `a` is `[0, 4]`.
+	fn fill_collection_bid(a: u32) -> Weight {
+		Weight::from_parts(96_000_000, 8799)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2981).saturating_mul(a.into()))
+	}
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::Offers` (r:100 w:100)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn sweep_expired(n: u32) -> Weight {
+		Weight::from_parts(9_000_000, 23878)
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:0 w:1)
+	fn set_market_fee() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1001 w:0)
+	/// Storage: `NftMarketModule::Listings` (r:0 w:1)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:100 w:100)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Storage: `NftModule::FrozenCollections` (r:1000 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2000 w:2000)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2000)
+	/// The range of component `o` is `[0, 100]`.
+	fn unlist_nft(o: u32) -> Weight {
+		Weight::from_parts(21_000_000, 64058)
+			.saturating_add(Weight::from_parts(214_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((42_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((42_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 25910).saturating_mul(o.into()))
+	}
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	/// Storage: `NftMarketModule::Listings` (r:1 w:1)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	/// Storage: `System::Account` (r:102 w:102)
+	/// Storage: `NftModule::NFTDetails` (r:1001 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1000 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2002 w:2002)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2002)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:100 w:100)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// The range of component `o` is `[0, 100]`.
+	fn buy_nft(o: u32) -> Weight {
+		Weight::from_parts(52_000_000, 64058)
+			.saturating_add(Weight::from_parts(214_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((42_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes((42_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 25910).saturating_mul(o.into()))
+	}
+	/// Storage: `NftMarketModule::Listings` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:11 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::NextExpiryToSweep` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// The range of component `n` is `[0, 10]`.
+	fn place_offer(n: u32) -> Weight {
+		Weight::from_parts(34_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// The range of component `n` is `[0, 10]`.
+	fn cancel_offer(n: u32) -> Weight {
+		Weight::from_parts(27_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// The range of component `n` is `[0, 10]`.
+	fn reject_offer(n: u32) -> Weight {
+		Weight::from_parts(28_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftMarketModule::Listings` (r:1 w:1)
+	fn update_list_price() -> Weight {
+		Weight::from_parts(12_000_000, 4173)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `NftMarketModule::Listings` (r:1 w:1)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:100 w:100)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1002 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1001 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2022 w:2022)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2022)
+	/// Storage: `System::Account` (r:102 w:102)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	/// The range of component `n` is `[0, 10]`.
+	/// The range of component `o` is `[0, 99]`.
+	fn accept_offer(n: u32, o: u32) -> Weight {
+		Weight::from_parts(61_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(214_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((42_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((42_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 25910).saturating_mul(o.into()))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: `NftModule::NFTOwners` (r:1 w:0)
-	/// Proof: `NftModule::NFTOwners` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
 	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
-	/// Proof: `NftModule::OwnedNFTs` (`max_values`: None, `max_size`: Some(370050), added: 372525, mode: `MaxEncodedLen`)
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::NextExpiryToSweep` (r:1 w:1)
 	/// Storage: `NftMarketModule::Listings` (r:0 w:1)
-	/// Proof: `NftMarketModule::Listings` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
 	fn list_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `223`
-		//  Estimated: `373515`
-		// Minimum execution time: 14_000_000 picoseconds.
 		Weight::from_parts(16_000_000, 373515)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	/// Storage: `NftMarketModule::AuctionsEndingAt` (r:1 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_parts(31_000_000, 11879)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `NftMarketModule::AuctionsEndingAt` (r:2 w:2)
+	fn bid() -> Weight {
+		Weight::from_parts(38_000_000, 22768)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	/// Storage: `NftMarketModule::AuctionsEndingAt` (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		Weight::from_parts(29_000_000, 11879)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftMarketModule::Auctions` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftModule::NFTApprovals` (r:0 w:1)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	fn settle_auction() -> Weight {
+		Weight::from_parts(52_000_000, 6172)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:1 w:0)
+	/// Storage: `NftMarketModule::Listings` (r:1 w:0)
+	/// Storage: `NftMarketModule::DutchAuctions` (r:1 w:1)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	fn create_dutch_auction() -> Weight {
+		Weight::from_parts(17_000_000, 4173)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftMarketModule::DutchAuctions` (r:1 w:1)
+	fn cancel_dutch_auction() -> Weight {
+		Weight::from_parts(10_000_000, 3605)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftMarketModule::NextSwapId` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:0 w:1)
+	/// Storage: `NftMarketModule::Swaps` (r:0 w:1)
+	/// The range of component `l` is `[1, 8]`.
+	/// The range of component `n` is `[0, 10]`.
+	fn propose_swap(l: u32, n: u32) -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(200_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::Swaps` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// Storage: `NftMarketModule::ListedShares` (r:10 w:0)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	/// The range of component `l` is `[1, 8]`.
+	/// The range of component `n` is `[0, 10]`.
+	fn approve_swap(l: u32, n: u32) -> Weight {
+		Weight::from_parts(40_000_000, 5250)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 3150).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Swaps` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// Storage: `NftMarketModule::ListedShares` (r:10 w:0)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	/// The range of component `l` is `[1, 8]`.
+	/// The range of component `n` is `[0, 10]`.
+	fn approve_swap_signed(l: u32, n: u32) -> Weight {
+		Weight::from_parts(96_000_000, 5250)
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 3150).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Swaps` (r:1 w:1)
+	/// Storage: `NftMarketModule::SwapDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftMarketModule::ProposedSwaps` (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		Weight::from_parts(22_000_000, 5250)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftModule::NFTCollections` (r:1 w:0)
+	/// Storage: `NftMarketModule::NextCollectionBidId` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionBids` (r:0 w:1)
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::NextExpiryToSweep` (r:1 w:1)
+	fn place_collection_bid() -> Weight {
+		Weight::from_parts(38_000_000, 23878)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftMarketModule::CollectionBids` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn cancel_collection_bid() -> Weight {
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftMarketModule::CollectionBids` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2 w:2)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	/// Storage: `NftModule::Attributes` (r:4 w:0)
+	/// The range of component `a` is `[0, 4]`.
+	fn fill_collection_bid(a: u32) -> Weight {
+		Weight::from_parts(96_000_000, 8799)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2981).saturating_mul(a.into()))
+	}
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::Offers` (r:100 w:100)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn sweep_expired(n: u32) -> Weight {
+		Weight::from_parts(9_000_000, 23878)
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:0 w:1)
+	fn set_market_fee() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1001 w:0)
+	/// Storage: `NftMarketModule::Listings` (r:0 w:1)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:100 w:100)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Storage: `NftModule::FrozenCollections` (r:1000 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2000 w:2000)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2000)
+	/// The range of component `o` is `[0, 100]`.
+	fn unlist_nft(o: u32) -> Weight {
+		Weight::from_parts(21_000_000, 64058)
+			.saturating_add(Weight::from_parts(214_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((42_u64).saturating_mul(o.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((42_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 25910).saturating_mul(o.into()))
+	}
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	/// Storage: `NftMarketModule::Listings` (r:1 w:1)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	/// Storage: `System::Account` (r:102 w:102)
+	/// Storage: `NftModule::NFTDetails` (r:1001 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1000 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2002 w:2002)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2002)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:100 w:100)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// The range of component `o` is `[0, 100]`.
+	fn buy_nft(o: u32) -> Weight {
+		Weight::from_parts(52_000_000, 64058)
+			.saturating_add(Weight::from_parts(214_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((42_u64).saturating_mul(o.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes((42_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 25910).saturating_mul(o.into()))
+	}
+	/// Storage: `NftMarketModule::Listings` (r:1 w:0)
+	/// Storage: `NftModule::NFTDetails` (r:11 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::ExpiringAt` (r:1 w:1)
+	/// Storage: `NftMarketModule::NextExpiryToSweep` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// The range of component `n` is `[0, 10]`.
+	fn place_offer(n: u32) -> Weight {
+		Weight::from_parts(34_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// The range of component `n` is `[0, 10]`.
+	fn cancel_offer(n: u32) -> Weight {
+		Weight::from_parts(27_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:10 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:10 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:20 w:20)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:20)
+	/// The range of component `n` is `[0, 10]`.
+	fn reject_offer(n: u32) -> Weight {
+		Weight::from_parts(28_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `NftModule::NFTDetails` (r:1 w:0)
+	/// Storage: `NftMarketModule::Listings` (r:1 w:1)
+	fn update_list_price() -> Weight {
+		Weight::from_parts(12_000_000, 4173)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `NftMarketModule::Listings` (r:1 w:1)
+	/// Storage: `NftMarketModule::Offers` (r:1 w:1)
+	/// Storage: `NftMarketModule::BuyerOffers` (r:100 w:100)
+	/// Storage: `NftMarketModule::SellerOffers` (r:1 w:1)
+	/// Storage: `NftMarketModule::ListedShares` (r:1 w:1)
+	/// Storage: `NftMarketModule::CollectionListings` (r:0 w:1)
+	/// Storage: `NftModule::NFTDetails` (r:1002 w:0)
+	/// Storage: `NftModule::FrozenCollections` (r:1001 w:0)
+	/// Storage: `NftModule::OwnedNFTs` (r:2022 w:2022)
+	/// Storage: `NftModule::NFTOwners` (r:0 w:2022)
+	/// Storage: `System::Account` (r:102 w:102)
+	/// Storage: `NftModule::NFTRoyalties` (r:1 w:0)
+	/// Storage: `NftModule::CollectionRoyalties` (r:1 w:0)
+	/// Storage: `NftMarketModule::MarketFeeBps` (r:1 w:0)
+	/// The range of component `n` is `[0, 10]`.
+	/// The range of component `o` is `[0, 99]`.
+	fn accept_offer(n: u32, o: u32) -> Weight {
+		Weight::from_parts(61_000_000, 64058)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(214_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((42_u64).saturating_mul(o.into())))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((42_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 25910).saturating_mul(o.into()))
+	}
 }
\ No newline at end of file
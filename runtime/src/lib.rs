@@ -6,10 +6,10 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H256};
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
-    traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
+    traits::{AccountIdConversion, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
     transaction_validity::{TransactionSource, TransactionValidity},
     ApplyExtrinsicResult, MultiSignature,
 };
@@ -22,7 +22,7 @@ use frame_support::genesis_builder_helper::{build_config, create_default_config}
 pub use frame_support::{
     construct_runtime, derive_impl, parameter_types,
     traits::{
-        ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
+        ConstBool, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
         StorageInfo,
     },
     weights::{
@@ -31,7 +31,7 @@ pub use frame_support::{
         },
         IdentityFee, Weight,
     },
-    StorageValue,
+    PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -99,7 +99,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 113,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -223,10 +223,33 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
 }
 
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = u32;
+    type AssetIdParameter = codec::Compact<u32>;
+    type Currency = Balances;
+    type CreateOrigin = frame_support::traits::AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type AssetAccountDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type MetadataDepositBase = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type MetadataDepositPerByte = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type ApprovalDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 parameter_types! {
     pub FeeMultiplier: Multiplier = Multiplier::one();
 }
@@ -260,13 +283,58 @@ impl pallet_poe::Config for Runtime {
 
 impl pallet_nft::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type DefaultShareSupply = ConstU128<100>;
+    type OnNftTransfer = NftMarketModule;
+    type MaxSubNftsLength = ConstU32<10>;
+    type MaxMetadataLength = ConstU32<256>;
+    type MaxCollectionsLength = ConstU32<10_000>;
+    type Currency = Balances;
+    type CollectionDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type ItemDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type MetadataDepositPerByte = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type AttributeDepositBase = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type DepositPerByte = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type MaxAttributeKeyLength = ConstU32<64>;
+    type MaxAttributeValueLength = ConstU32<256>;
     type WeightInfo = pallet_nft::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const NftMarketPalletId: PalletId = PalletId(*b"py/nftmk");
+    pub MarketFeeDestination: AccountId = PalletId(*b"py/trsry").into_account_truncating();
+}
+
 impl pallet_nft_market::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
+    type OfferHolds = Balances;
+    type CollectionId = H256;
+    type ItemId = u32;
+    type Nfts = NftModule;
+    type AssetId = u32;
+    type Assets = Assets;
+    type PalletId = NftMarketPalletId;
+    type FeeDestination = MarketFeeDestination;
+    type MaxMarketFeeBps = ConstU16<1_000>;
+    type MaxOfferNftsLength = ConstU32<10>;
+    type MaxOffersLength = ConstU32<100>;
+    type AuctionExtensionPeriod = ConstU32<{ 10 * MINUTES }>;
+    type MaxAuctionsPerBlock = ConstU32<100>;
+    type MaxExpiriesPerBlock = ConstU32<100>;
+    type MaxExpirySpillover = ConstU32<16>;
+    type MaxListingsPerNft = ConstU32<10>;
+    type DutchAuctionPrice = pallet_nft_market::LinearDecline;
+    type MaxBidAttributes = ConstU32<4>;
+    type MaxBidAttributeLength = ConstU32<64>;
+    type MaxSwapLegs = ConstU32<8>;
+    type SwapDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type MaxSwapsPerAccount = ConstU32<16>;
+    type OffchainSignature = Signature;
+    type SigningPublicKey = <Signature as Verify>::Signer;
     type WeightInfo = pallet_nft_market::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = NftModule;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -319,6 +387,9 @@ mod runtime {
 
     #[runtime::pallet_index(10)]
     pub type NftMarketModule = pallet_nft_market;
+
+    #[runtime::pallet_index(11)]
+    pub type Assets = pallet_assets;
 }
 
 /// The address format for describing accounts.
@@ -343,7 +414,12 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_nft::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_nft::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_nft::migrations::v3::MigrateV2ToV3<Runtime>,
+    pallet_nft_market::migrations::v1::MigrateV0ToV1<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -366,6 +442,7 @@ mod benches {
         [frame_benchmarking, BaselineBench::<Runtime>]
         [frame_system, SystemBench::<Runtime>]
         [pallet_balances, Balances]
+        [pallet_assets, Assets]
         [pallet_timestamp, Timestamp]
         [pallet_sudo, Sudo]
         [pallet_template, TemplateModule]
@@ -543,6 +620,67 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_nft_runtime_api::NftApi<Block, AccountId> for Runtime {
+        fn holdings(
+            account: AccountId,
+            start_after: Option<(H256, u32)>,
+            limit: u32,
+        ) -> Vec<pallet_nft_runtime_api::NftHolding> {
+            NftModule::holdings(&account, start_after, limit)
+        }
+        fn item_details(
+            collection_id: H256,
+            item_id: u32,
+        ) -> Option<pallet_nft_runtime_api::NftItemDetails<AccountId>> {
+            NftModule::item_details((collection_id, item_id))
+        }
+        fn collection_details(collection_id: H256) -> Option<pallet_nft_runtime_api::CollectionDetails<AccountId>> {
+            NftModule::collection_details(collection_id)
+        }
+        fn owners(
+            collection_id: H256,
+            item_id: u32,
+            start_after: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<pallet_nft_runtime_api::NftOwner<AccountId>> {
+            NftModule::owners((collection_id, item_id), start_after, limit)
+        }
+        fn attributes(
+            collection_id: H256,
+            item_id: Option<u32>,
+            namespace: Option<pallet_nft_runtime_api::AttributeNamespace<AccountId>>,
+        ) -> Vec<pallet_nft_runtime_api::NftAttribute<AccountId>> {
+            NftModule::attributes(collection_id, item_id, namespace)
+        }
+    }
+
+    impl pallet_nft_market_runtime_api::MarketApi<Block, H256, u32, AccountId, Balance, u32, BlockNumber> for Runtime {
+        fn collection_listings(
+            collection_id: H256,
+            asset: Option<u32>,
+        ) -> Vec<pallet_nft_market_runtime_api::MarketListing<H256, u32, AccountId, Balance, u32, BlockNumber>> {
+            NftMarketModule::collection_listings(collection_id, asset)
+        }
+        fn seller_offers(
+            seller: AccountId,
+        ) -> Vec<pallet_nft_market_runtime_api::MarketOffer<H256, u32, AccountId, Balance, u32, BlockNumber>> {
+            NftMarketModule::seller_offers(&seller)
+        }
+        fn buyer_offers(
+            buyer: AccountId,
+        ) -> Vec<pallet_nft_market_runtime_api::MarketOffer<H256, u32, AccountId, Balance, u32, BlockNumber>> {
+            NftMarketModule::buyer_offers(&buyer)
+        }
+        fn buy_quote(
+            collection_id: H256,
+            item_id: u32,
+            share: pallet_nft_market_runtime_api::Share,
+            seller: AccountId,
+        ) -> Option<pallet_nft_market_runtime_api::BuyQuote<AccountId, Balance, u32>> {
+            NftMarketModule::buy_quote((collection_id, item_id, share), &seller)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (